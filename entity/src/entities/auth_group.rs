@@ -1,6 +1,7 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.15
 
 use super::sea_orm_active_enums::GroupFilterType;
+use super::sea_orm_active_enums::GroupMembershipInheritance;
 use super::sea_orm_active_enums::GroupOwnerType;
 use super::sea_orm_active_enums::GroupType;
 use sea_orm::entity::prelude::*;
@@ -20,18 +21,46 @@ pub struct Model {
     pub owner_id: Option<i32>,
     pub group_type: GroupType,
     pub filter_type: GroupFilterType,
+    pub parent_id: Option<i32>,
+    pub membership_inheritance: GroupMembershipInheritance,
+    pub category_id: Option<i32>,
+    pub archived: Option<DateTime>,
+    pub application_expiry_days: Option<i32>,
+    pub application_cooldown_days: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    SelfRef,
     #[sea_orm(has_many = "super::auth_group_application::Entity")]
     AuthGroupApplication,
+    #[sea_orm(
+        belongs_to = "super::auth_group_category::Entity",
+        from = "Column::CategoryId",
+        to = "super::auth_group_category::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    AuthGroupCategory,
     #[sea_orm(has_many = "super::auth_group_filter_group::Entity")]
     AuthGroupFilterGroup,
     #[sea_orm(has_many = "super::auth_group_filter_rule::Entity")]
     AuthGroupFilterRule,
+    #[sea_orm(has_many = "super::auth_group_invitation::Entity")]
+    AuthGroupInvitation,
+    #[sea_orm(has_many = "super::auth_group_question::Entity")]
+    AuthGroupQuestion,
     #[sea_orm(has_many = "super::auth_group_user::Entity")]
     AuthGroupUser,
+    #[sea_orm(has_many = "super::auth_recruitment_pipeline::Entity")]
+    AuthRecruitmentPipeline,
 }
 
 impl Related<super::auth_group_application::Entity> for Entity {
@@ -40,6 +69,12 @@ impl Related<super::auth_group_application::Entity> for Entity {
     }
 }
 
+impl Related<super::auth_group_category::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthGroupCategory.def()
+    }
+}
+
 impl Related<super::auth_group_filter_group::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::AuthGroupFilterGroup.def()
@@ -52,10 +87,28 @@ impl Related<super::auth_group_filter_rule::Entity> for Entity {
     }
 }
 
+impl Related<super::auth_group_invitation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthGroupInvitation.def()
+    }
+}
+
+impl Related<super::auth_group_question::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthGroupQuestion.def()
+    }
+}
+
 impl Related<super::auth_group_user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::AuthGroupUser.def()
     }
 }
 
+impl Related<super::auth_recruitment_pipeline::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthRecruitmentPipeline.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
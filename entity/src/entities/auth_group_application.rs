@@ -21,6 +21,7 @@ pub struct Model {
     pub responder: Option<i32>,
     pub created: DateTime,
     pub last_updated: DateTime,
+    pub assignee_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -49,6 +50,22 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     AuthUser1,
+    #[sea_orm(
+        belongs_to = "super::auth_user::Entity",
+        from = "Column::AssigneeId",
+        to = "super::auth_user::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    AuthUser3,
+    #[sea_orm(has_many = "super::auth_group_application_answer::Entity")]
+    AuthGroupApplicationAnswer,
+    #[sea_orm(has_many = "super::auth_group_application_comment::Entity")]
+    AuthGroupApplicationComment,
+    #[sea_orm(has_many = "super::auth_group_application_history::Entity")]
+    AuthGroupApplicationHistory,
+    #[sea_orm(has_many = "super::auth_recruitment::Entity")]
+    AuthRecruitment,
 }
 
 impl Related<super::auth_group::Entity> for Entity {
@@ -57,4 +74,28 @@ impl Related<super::auth_group::Entity> for Entity {
     }
 }
 
+impl Related<super::auth_group_application_answer::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthGroupApplicationAnswer.def()
+    }
+}
+
+impl Related<super::auth_group_application_comment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthGroupApplicationComment.def()
+    }
+}
+
+impl Related<super::auth_group_application_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthGroupApplicationHistory.def()
+    }
+}
+
+impl Related<super::auth_recruitment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthRecruitment.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -11,6 +11,7 @@ pub struct Model {
     pub id: i32,
     pub group_id: i32,
     pub filter_type: GroupFilterType,
+    pub parent_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -23,6 +24,14 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     AuthGroup,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    SelfRef,
     #[sea_orm(has_many = "super::auth_group_filter_rule::Entity")]
     AuthGroupFilterRule,
 }
@@ -2,6 +2,7 @@
 
 use super::sea_orm_active_enums::GroupFilterCriteria;
 use super::sea_orm_active_enums::GroupFilterCriteriaType;
+use super::sea_orm_active_enums::GroupFilterScope;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +16,7 @@ pub struct Model {
     pub criteria: GroupFilterCriteria,
     pub criteria_type: GroupFilterCriteriaType,
     pub criteria_value: String,
+    pub scope: GroupFilterScope,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
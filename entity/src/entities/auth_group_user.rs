@@ -10,6 +10,9 @@ pub struct Model {
     pub id: i32,
     pub group_id: i32,
     pub user_id: i32,
+    pub created: DateTime,
+    pub expires: Option<DateTime>,
+    pub added_by: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -29,7 +32,15 @@ pub enum Relation {
         on_update = "NoAction",
         on_delete = "NoAction"
     )]
-    AuthUser,
+    AuthUser2,
+    #[sea_orm(
+        belongs_to = "super::auth_user::Entity",
+        from = "Column::AddedBy",
+        to = "super::auth_user::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    AuthUser1,
 }
 
 impl Related<super::auth_group::Entity> for Entity {
@@ -38,10 +49,4 @@ impl Related<super::auth_group::Entity> for Entity {
     }
 }
 
-impl Related<super::auth_user::Entity> for Entity {
-    fn to() -> RelationDef {
-        Relation::AuthUser.def()
-    }
-}
-
 impl ActiveModelBehavior for ActiveModel {}
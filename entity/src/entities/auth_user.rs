@@ -14,15 +14,23 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
-    #[sea_orm(has_many = "super::auth_group_user::Entity")]
-    AuthGroupUser,
+    #[sea_orm(has_many = "super::auth_group_application_comment::Entity")]
+    AuthGroupApplicationComment,
+    #[sea_orm(has_many = "super::auth_group_owner_delegate::Entity")]
+    AuthGroupOwnerDelegate,
     #[sea_orm(has_many = "super::auth_user_character_ownership::Entity")]
     AuthUserCharacterOwnership,
 }
 
-impl Related<super::auth_group_user::Entity> for Entity {
+impl Related<super::auth_group_application_comment::Entity> for Entity {
     fn to() -> RelationDef {
-        Relation::AuthGroupUser.def()
+        Relation::AuthGroupApplicationComment.def()
+    }
+}
+
+impl Related<super::auth_group_owner_delegate::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthGroupOwnerDelegate.def()
     }
 }
 
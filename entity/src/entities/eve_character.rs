@@ -19,6 +19,12 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_one = "super::auth_user_character_ownership::Entity")]
     AuthUserCharacterOwnership,
+    #[sea_orm(has_many = "super::eve_character_role::Entity")]
+    EveCharacterRole,
+    #[sea_orm(has_many = "super::eve_character_title::Entity")]
+    EveCharacterTitle,
+    #[sea_orm(has_one = "super::eve_character_token::Entity")]
+    EveCharacterToken,
     #[sea_orm(
         belongs_to = "super::eve_corporation::Entity",
         from = "Column::CorporationId",
@@ -35,6 +41,24 @@ impl Related<super::auth_user_character_ownership::Entity> for Entity {
     }
 }
 
+impl Related<super::eve_character_role::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EveCharacterRole.def()
+    }
+}
+
+impl Related<super::eve_character_title::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EveCharacterTitle.def()
+    }
+}
+
+impl Related<super::eve_character_token::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EveCharacterToken.def()
+    }
+}
+
 impl Related<super::eve_corporation::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::EveCorporation.def()
@@ -4,12 +4,28 @@ pub mod prelude;
 
 pub mod auth_group;
 pub mod auth_group_application;
+pub mod auth_group_application_answer;
+pub mod auth_group_application_comment;
+pub mod auth_group_application_history;
+pub mod auth_group_category;
 pub mod auth_group_filter_group;
 pub mod auth_group_filter_rule;
+pub mod auth_group_invitation;
+pub mod auth_group_membership_history;
+pub mod auth_group_owner_delegate;
+pub mod auth_group_question;
+pub mod auth_group_template;
 pub mod auth_group_user;
+pub mod auth_organization;
+pub mod auth_organization_member;
+pub mod auth_recruitment;
+pub mod auth_recruitment_pipeline;
 pub mod auth_user;
 pub mod auth_user_character_ownership;
 pub mod eve_alliance;
 pub mod eve_character;
+pub mod eve_character_role;
+pub mod eve_character_title;
+pub mod eve_character_token;
 pub mod eve_corporation;
 pub mod sea_orm_active_enums;
@@ -2,11 +2,27 @@
 
 pub use super::auth_group::Entity as AuthGroup;
 pub use super::auth_group_application::Entity as AuthGroupApplication;
+pub use super::auth_group_application_answer::Entity as AuthGroupApplicationAnswer;
+pub use super::auth_group_application_comment::Entity as AuthGroupApplicationComment;
+pub use super::auth_group_application_history::Entity as AuthGroupApplicationHistory;
+pub use super::auth_group_category::Entity as AuthGroupCategory;
 pub use super::auth_group_filter_group::Entity as AuthGroupFilterGroup;
 pub use super::auth_group_filter_rule::Entity as AuthGroupFilterRule;
+pub use super::auth_group_invitation::Entity as AuthGroupInvitation;
+pub use super::auth_group_membership_history::Entity as AuthGroupMembershipHistory;
+pub use super::auth_group_owner_delegate::Entity as AuthGroupOwnerDelegate;
+pub use super::auth_group_question::Entity as AuthGroupQuestion;
+pub use super::auth_group_template::Entity as AuthGroupTemplate;
 pub use super::auth_group_user::Entity as AuthGroupUser;
+pub use super::auth_organization::Entity as AuthOrganization;
+pub use super::auth_organization_member::Entity as AuthOrganizationMember;
+pub use super::auth_recruitment::Entity as AuthRecruitment;
+pub use super::auth_recruitment_pipeline::Entity as AuthRecruitmentPipeline;
 pub use super::auth_user::Entity as AuthUser;
 pub use super::auth_user_character_ownership::Entity as AuthUserCharacterOwnership;
 pub use super::eve_alliance::Entity as EveAlliance;
 pub use super::eve_character::Entity as EveCharacter;
+pub use super::eve_character_role::Entity as EveCharacterRole;
+pub use super::eve_character_title::Entity as EveCharacterTitle;
+pub use super::eve_character_token::Entity as EveCharacterToken;
 pub use super::eve_corporation::Entity as EveCorporation;
@@ -12,10 +12,14 @@ use serde::{Deserialize, Serialize};
 pub enum GroupApplicationStatus {
     #[sea_orm(string_value = "Accepted")]
     Accepted,
+    #[sea_orm(string_value = "Expired")]
+    Expired,
     #[sea_orm(string_value = "Outstanding")]
     Outstanding,
     #[sea_orm(string_value = "Rejected")]
     Rejected,
+    #[sea_orm(string_value = "Withdrawn")]
+    Withdrawn,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(
@@ -40,8 +44,14 @@ pub enum GroupFilterCriteria {
     Alliance,
     #[sea_orm(string_value = "Corporation")]
     Corporation,
+    #[sea_orm(string_value = "CorporationRole")]
+    CorporationRole,
+    #[sea_orm(string_value = "CorporationTitle")]
+    CorporationTitle,
     #[sea_orm(string_value = "Group")]
     Group,
+    #[sea_orm(string_value = "Organization")]
+    Organization,
     #[sea_orm(string_value = "Role")]
     Role,
 }
@@ -62,6 +72,16 @@ pub enum GroupFilterCriteriaType {
     LessThan,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "group_filter_scope")]
+pub enum GroupFilterScope {
+    #[sea_orm(string_value = "AllCharacters")]
+    AllCharacters,
+    #[sea_orm(string_value = "AnyCharacter")]
+    AnyCharacter,
+    #[sea_orm(string_value = "MainCharacter")]
+    MainCharacter,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "group_filter_type")]
 pub enum GroupFilterType {
     #[sea_orm(string_value = "All")]
@@ -70,6 +90,66 @@ pub enum GroupFilterType {
     Any,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "group_invitation_status"
+)]
+pub enum GroupInvitationStatus {
+    #[sea_orm(string_value = "Accepted")]
+    Accepted,
+    #[sea_orm(string_value = "Declined")]
+    Declined,
+    #[sea_orm(string_value = "Pending")]
+    Pending,
+    #[sea_orm(string_value = "Revoked")]
+    Revoked,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "group_membership_action"
+)]
+pub enum GroupMembershipAction {
+    #[sea_orm(string_value = "Joined")]
+    Joined,
+    #[sea_orm(string_value = "Removed")]
+    Removed,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "group_membership_inheritance"
+)]
+pub enum GroupMembershipInheritance {
+    #[sea_orm(string_value = "ChildToParent")]
+    ChildToParent,
+    #[sea_orm(string_value = "ParentToChild")]
+    ParentToChild,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "group_membership_reason"
+)]
+pub enum GroupMembershipReason {
+    #[sea_orm(string_value = "Application")]
+    Application,
+    #[sea_orm(string_value = "Expiry")]
+    Expiry,
+    #[sea_orm(string_value = "GroupDeleted")]
+    GroupDeleted,
+    #[sea_orm(string_value = "Invitation")]
+    Invitation,
+    #[sea_orm(string_value = "Manual")]
+    Manual,
+    #[sea_orm(string_value = "Reconciliation")]
+    Reconciliation,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "group_owner_type")]
 pub enum GroupOwnerType {
     #[sea_orm(string_value = "Alliance")]
@@ -78,6 +158,24 @@ pub enum GroupOwnerType {
     Auth,
     #[sea_orm(string_value = "Corporation")]
     Corporation,
+    #[sea_orm(string_value = "Organization")]
+    Organization,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "group_question_type"
+)]
+pub enum GroupQuestionType {
+    #[sea_orm(string_value = "Boolean")]
+    Boolean,
+    #[sea_orm(string_value = "Choice")]
+    Choice,
+    #[sea_orm(string_value = "Number")]
+    Number,
+    #[sea_orm(string_value = "Text")]
+    Text,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "group_type")]
@@ -91,3 +189,31 @@ pub enum GroupType {
     #[sea_orm(string_value = "Open")]
     Open,
 }
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "organization_member_type"
+)]
+pub enum OrganizationMemberType {
+    #[sea_orm(string_value = "Alliance")]
+    Alliance,
+    #[sea_orm(string_value = "Corporation")]
+    Corporation,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "recruitment_status")]
+pub enum RecruitmentStatus {
+    #[sea_orm(string_value = "Approved")]
+    Approved,
+    #[sea_orm(string_value = "AwaitingScopes")]
+    AwaitingScopes,
+    #[sea_orm(string_value = "Joined")]
+    Joined,
+    #[sea_orm(string_value = "Rejected")]
+    Rejected,
+    #[sea_orm(string_value = "Vetting")]
+    Vetting,
+    #[sea_orm(string_value = "Withdrawn")]
+    Withdrawn,
+}
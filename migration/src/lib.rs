@@ -2,6 +2,24 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20240222_000001_initial;
 mod m20240303_000002_groups;
+mod m20240520_000003_group_filter_scope;
+mod m20240524_000004_character_roles;
+mod m20240527_000005_nested_filter_groups;
+mod m20240601_000006_subgroups;
+mod m20240603_000007_group_categories;
+mod m20240605_000008_organizations;
+mod m20240608_000009_group_owner_delegates;
+mod m20240611_000010_group_membership_expiry;
+mod m20240613_000011_group_membership_history;
+mod m20240617_000012_group_archive;
+mod m20240620_000013_group_templates;
+mod m20240623_000014_group_application_comments;
+mod m20240626_000015_group_questionnaires;
+mod m20240629_000016_group_application_expiry;
+mod m20240702_000017_group_application_history;
+mod m20240705_000018_group_application_assignment;
+mod m20240708_000019_group_invitations;
+mod m20240712_000020_recruitment;
 
 pub struct Migrator;
 
@@ -11,6 +29,24 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20240222_000001_initial::Migration),
             Box::new(m20240303_000002_groups::Migration),
+            Box::new(m20240520_000003_group_filter_scope::Migration),
+            Box::new(m20240524_000004_character_roles::Migration),
+            Box::new(m20240527_000005_nested_filter_groups::Migration),
+            Box::new(m20240601_000006_subgroups::Migration),
+            Box::new(m20240603_000007_group_categories::Migration),
+            Box::new(m20240605_000008_organizations::Migration),
+            Box::new(m20240608_000009_group_owner_delegates::Migration),
+            Box::new(m20240611_000010_group_membership_expiry::Migration),
+            Box::new(m20240613_000011_group_membership_history::Migration),
+            Box::new(m20240617_000012_group_archive::Migration),
+            Box::new(m20240620_000013_group_templates::Migration),
+            Box::new(m20240623_000014_group_application_comments::Migration),
+            Box::new(m20240626_000015_group_questionnaires::Migration),
+            Box::new(m20240629_000016_group_application_expiry::Migration),
+            Box::new(m20240702_000017_group_application_history::Migration),
+            Box::new(m20240705_000018_group_application_assignment::Migration),
+            Box::new(m20240708_000019_group_invitations::Migration),
+            Box::new(m20240712_000020_recruitment::Migration),
         ]
     }
 }
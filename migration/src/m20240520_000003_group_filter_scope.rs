@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Alias::new("group_filter_scope"))
+                    .values([
+                        Alias::new("AnyCharacter"),
+                        Alias::new("MainCharacter"),
+                        Alias::new("AllCharacters"),
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroupFilterRule::Table)
+                    .add_column(
+                        ColumnDef::new(AuthGroupFilterRule::Scope)
+                            .enumeration(
+                                Alias::new("group_filter_scope"),
+                                [
+                                    Alias::new("AnyCharacter"),
+                                    Alias::new("MainCharacter"),
+                                    Alias::new("AllCharacters"),
+                                ],
+                            )
+                            .not_null()
+                            .default("AnyCharacter"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroupFilterRule::Table)
+                    .drop_column(AuthGroupFilterRule::Scope)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Alias::new("group_filter_scope"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupFilterRule {
+    Table,
+    Scope, // AnyCharacter, MainCharacter, AllCharacters
+}
@@ -0,0 +1,228 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(Alias::new("group_filter_criteria"))
+                    .add_value(Alias::new("CorporationRole"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(Alias::new("group_filter_criteria"))
+                    .add_value(Alias::new("CorporationTitle"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(EveCharacterRole::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EveCharacterRole::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EveCharacterRole::CharacterId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EveCharacterRole::Role).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-eve_character_role-character_id")
+                    .table(EveCharacterRole::Table)
+                    .col(EveCharacterRole::CharacterId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-eve_character_role-eve_character")
+                    .from_tbl(EveCharacterRole::Table)
+                    .from_col(EveCharacterRole::CharacterId)
+                    .to_tbl(EveCharacter::Table)
+                    .to_col(EveCharacter::CharacterId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(EveCharacterTitle::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EveCharacterTitle::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EveCharacterTitle::CharacterId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EveCharacterTitle::TitleId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EveCharacterTitle::TitleName)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-eve_character_title-character_id")
+                    .table(EveCharacterTitle::Table)
+                    .col(EveCharacterTitle::CharacterId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-eve_character_title-eve_character")
+                    .from_tbl(EveCharacterTitle::Table)
+                    .from_col(EveCharacterTitle::CharacterId)
+                    .to_tbl(EveCharacter::Table)
+                    .to_col(EveCharacter::CharacterId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(EveCharacterToken::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EveCharacterToken::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EveCharacterToken::CharacterId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EveCharacterToken::RefreshToken)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EveCharacterToken::Scopes).text().not_null())
+                    .col(
+                        ColumnDef::new(EveCharacterToken::LastUpdated)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-eve_character_token-eve_character")
+                    .from_tbl(EveCharacterToken::Table)
+                    .from_col(EveCharacterToken::CharacterId)
+                    .to_tbl(EveCharacter::Table)
+                    .to_col(EveCharacter::CharacterId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Postgres does not support removing values from an enum type, the
+        // CorporationRole & CorporationTitle criteria values are left in place
+        manager
+            .drop_table(Table::drop().table(EveCharacterToken::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(EveCharacterTitle::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(EveCharacterRole::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum EveCharacterRole {
+    Table,
+    Id,
+    CharacterId,
+    Role,
+}
+
+#[derive(DeriveIden)]
+enum EveCharacterTitle {
+    Table,
+    Id,
+    CharacterId,
+    TitleId,
+    TitleName,
+}
+
+#[derive(DeriveIden)]
+enum EveCharacterToken {
+    Table,
+    Id,
+    CharacterId,
+    RefreshToken,
+    Scopes,
+    LastUpdated,
+}
+
+#[derive(DeriveIden)]
+enum EveCharacter {
+    Table,
+    CharacterId,
+}
@@ -0,0 +1,82 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroupFilterGroup::Table)
+                    .add_column(ColumnDef::new(AuthGroupFilterGroup::ParentId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_filter_group-parent_id")
+                    .table(AuthGroupFilterGroup::Table)
+                    .col(AuthGroupFilterGroup::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_filter_group-parent")
+                    .from_tbl(AuthGroupFilterGroup::Table)
+                    .from_col(AuthGroupFilterGroup::ParentId)
+                    .to_tbl(AuthGroupFilterGroup::Table)
+                    .to_col(AuthGroupFilterGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                sea_query::ForeignKey::drop()
+                    .name("fk-auth_group_filter_group-parent")
+                    .table(AuthGroupFilterGroup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-auth_group_filter_group-parent_id")
+                    .table(AuthGroupFilterGroup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroupFilterGroup::Table)
+                    .drop_column(AuthGroupFilterGroup::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupFilterGroup {
+    Table,
+    Id,
+    ParentId, // If null then it is a top level filter group
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Alias::new("group_membership_inheritance"))
+                    .values([Alias::new("ChildToParent"), Alias::new("ParentToChild")])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroup::Table)
+                    .add_column(ColumnDef::new(AuthGroup::ParentId).integer())
+                    .add_column(
+                        ColumnDef::new(AuthGroup::MembershipInheritance)
+                            .enumeration(
+                                Alias::new("group_membership_inheritance"),
+                                [Alias::new("ChildToParent"), Alias::new("ParentToChild")],
+                            )
+                            .not_null()
+                            .default("ChildToParent"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group-parent_id")
+                    .table(AuthGroup::Table)
+                    .col(AuthGroup::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group-parent")
+                    .from_tbl(AuthGroup::Table)
+                    .from_col(AuthGroup::ParentId)
+                    .to_tbl(AuthGroup::Table)
+                    .to_col(AuthGroup::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                sea_query::ForeignKey::drop()
+                    .name("fk-auth_group-parent")
+                    .table(AuthGroup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-auth_group-parent_id")
+                    .table(AuthGroup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroup::Table)
+                    .drop_column(AuthGroup::ParentId)
+                    .drop_column(AuthGroup::MembershipInheritance)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Alias::new("group_membership_inheritance"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroup {
+    Table,
+    Id,
+    ParentId,              // If null then it is not a subgroup
+    MembershipInheritance, // ChildToParent, ParentToChild
+}
@@ -0,0 +1,118 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupCategory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupCategory::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuthGroupCategory::Name).string().not_null())
+                    .col(ColumnDef::new(AuthGroupCategory::Description).text())
+                    .col(
+                        ColumnDef::new(AuthGroupCategory::Ordering)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroup::Table)
+                    .add_column(ColumnDef::new(AuthGroup::CategoryId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group-category_id")
+                    .table(AuthGroup::Table)
+                    .col(AuthGroup::CategoryId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group-auth_group_category")
+                    .from_tbl(AuthGroup::Table)
+                    .from_col(AuthGroup::CategoryId)
+                    .to_tbl(AuthGroupCategory::Table)
+                    .to_col(AuthGroupCategory::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                sea_query::ForeignKey::drop()
+                    .name("fk-auth_group-auth_group_category")
+                    .table(AuthGroup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-auth_group-category_id")
+                    .table(AuthGroup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroup::Table)
+                    .drop_column(AuthGroup::CategoryId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(AuthGroupCategory::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupCategory {
+    Table,
+    Id,
+    Name,
+    Description,
+    Ordering, // Categories are listed in ascending order
+}
+
+#[derive(DeriveIden)]
+enum AuthGroup {
+    Table,
+    CategoryId, // If null then the group is uncategorized
+}
@@ -0,0 +1,161 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(Alias::new("group_owner_type"))
+                    .add_value(Alias::new("Organization"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(Alias::new("group_filter_criteria"))
+                    .add_value(Alias::new("Organization"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Alias::new("organization_member_type"))
+                    .values([Alias::new("Alliance"), Alias::new("Corporation")])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthOrganization::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthOrganization::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuthOrganization::Name).string().not_null())
+                    .col(ColumnDef::new(AuthOrganization::Description).text())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthOrganizationMember::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthOrganizationMember::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthOrganizationMember::OrganizationId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthOrganizationMember::MemberType)
+                            .enumeration(
+                                Alias::new("organization_member_type"),
+                                [Alias::new("Alliance"), Alias::new("Corporation")],
+                            )
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthOrganizationMember::MemberId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_organization_member-unique")
+                    .table(AuthOrganizationMember::Table)
+                    .col(AuthOrganizationMember::OrganizationId)
+                    .col(AuthOrganizationMember::MemberType)
+                    .col(AuthOrganizationMember::MemberId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_organization_member-auth_organization")
+                    .from_tbl(AuthOrganizationMember::Table)
+                    .from_col(AuthOrganizationMember::OrganizationId)
+                    .to_tbl(AuthOrganization::Table)
+                    .to_col(AuthOrganization::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Postgres does not support removing values from an enum type, the
+        // Organization owner type & criteria values are left in place
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AuthOrganizationMember::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(AuthOrganization::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Alias::new("organization_member_type"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthOrganization {
+    Table,
+    Id,
+    Name,
+    Description,
+}
+
+#[derive(DeriveIden)]
+enum AuthOrganizationMember {
+    Table,
+    Id,
+    OrganizationId,
+    MemberType, // Alliance, Corporation
+    MemberId,   // Alliance or corporation id depending on the member type
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240222_000001_initial::AuthUser;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupOwnerDelegate::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupOwnerDelegate::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupOwnerDelegate::OwnerType)
+                            .enumeration(
+                                Alias::new("group_owner_type"),
+                                [
+                                    Alias::new("Auth"),
+                                    Alias::new("Corporation"),
+                                    Alias::new("Alliance"),
+                                    Alias::new("Organization"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupOwnerDelegate::OwnerId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupOwnerDelegate::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_owner_delegate-unique")
+                    .table(AuthGroupOwnerDelegate::Table)
+                    .col(AuthGroupOwnerDelegate::OwnerType)
+                    .col(AuthGroupOwnerDelegate::OwnerId)
+                    .col(AuthGroupOwnerDelegate::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_owner_delegate-user_id")
+                    .table(AuthGroupOwnerDelegate::Table)
+                    .col(AuthGroupOwnerDelegate::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_owner_delegate-auth_user")
+                    .from_tbl(AuthGroupOwnerDelegate::Table)
+                    .from_col(AuthGroupOwnerDelegate::UserId)
+                    .to_tbl(AuthUser::Table)
+                    .to_col(AuthUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AuthGroupOwnerDelegate::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupOwnerDelegate {
+    Table,
+    Id,
+    OwnerType, // Corporation, Alliance
+    OwnerId,   // Corporation or alliance id depending on the owner type
+    UserId,    // User allowed to manage the owner's groups
+}
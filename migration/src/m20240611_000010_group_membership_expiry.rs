@@ -0,0 +1,95 @@
+use chrono::Utc;
+use sea_orm_migration::prelude::*;
+
+use crate::m20240222_000001_initial::AuthUser;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroupUser::Table)
+                    .add_column(
+                        ColumnDef::new(AuthGroupUser::Created)
+                            .timestamp()
+                            .not_null()
+                            .default(Utc::now().naive_utc()),
+                    )
+                    .add_column(ColumnDef::new(AuthGroupUser::Expires).timestamp())
+                    .add_column(ColumnDef::new(AuthGroupUser::AddedBy).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_user-expires")
+                    .table(AuthGroupUser::Table)
+                    .col(AuthGroupUser::Expires)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_user_added_by-user_id")
+                    .from_tbl(AuthGroupUser::Table)
+                    .from_col(AuthGroupUser::AddedBy)
+                    .to_tbl(AuthUser::Table)
+                    .to_col(AuthUser::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                sea_query::ForeignKey::drop()
+                    .name("fk-auth_group_user_added_by-user_id")
+                    .table(AuthGroupUser::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-auth_group_user-expires")
+                    .table(AuthGroupUser::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroupUser::Table)
+                    .drop_column(AuthGroupUser::Created)
+                    .drop_column(AuthGroupUser::Expires)
+                    .drop_column(AuthGroupUser::AddedBy)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupUser {
+    Table,
+    Created,
+    Expires, // Memberships past this time are removed by the expiry job, never expires when null
+    AddedBy, // User who added the member or accepted their application, null when joined automatically
+}
@@ -0,0 +1,153 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Alias::new("group_membership_action"))
+                    .values([Alias::new("Joined"), Alias::new("Removed")])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Alias::new("group_membership_reason"))
+                    .values([
+                        Alias::new("Manual"),
+                        Alias::new("Application"),
+                        Alias::new("Reconciliation"),
+                        Alias::new("Expiry"),
+                        Alias::new("GroupDeleted"),
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupMembershipHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupMembershipHistory::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupMembershipHistory::GroupId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupMembershipHistory::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupMembershipHistory::Action)
+                            .enumeration(
+                                Alias::new("group_membership_action"),
+                                [Alias::new("Joined"), Alias::new("Removed")],
+                            )
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupMembershipHistory::Reason)
+                            .enumeration(
+                                Alias::new("group_membership_reason"),
+                                [
+                                    Alias::new("Manual"),
+                                    Alias::new("Application"),
+                                    Alias::new("Reconciliation"),
+                                    Alias::new("Expiry"),
+                                    Alias::new("GroupDeleted"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthGroupMembershipHistory::ActorId).integer())
+                    .col(
+                        ColumnDef::new(AuthGroupMembershipHistory::Created)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_membership_history-group_id")
+                    .table(AuthGroupMembershipHistory::Table)
+                    .col(AuthGroupMembershipHistory::GroupId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_membership_history-user_id")
+                    .table(AuthGroupMembershipHistory::Table)
+                    .col(AuthGroupMembershipHistory::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AuthGroupMembershipHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Alias::new("group_membership_reason"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Alias::new("group_membership_action"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+// Append only, rows have no foreign keys so history is kept after groups & users are deleted
+#[derive(DeriveIden)]
+enum AuthGroupMembershipHistory {
+    Table,
+    Id,
+    GroupId,
+    UserId,
+    Action,  // Joined, Removed
+    Reason,  // Manual, Application, Reconciliation, Expiry, GroupDeleted
+    ActorId, // User who made the change, null for changes made by the auth itself
+    Created,
+}
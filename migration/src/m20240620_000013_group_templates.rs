@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupTemplate::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupTemplate::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuthGroupTemplate::Name).string().not_null())
+                    .col(ColumnDef::new(AuthGroupTemplate::Description).text())
+                    .col(
+                        ColumnDef::new(AuthGroupTemplate::Definition)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupTemplate::Created)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuthGroupTemplate::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupTemplate {
+    Table,
+    Id,
+    Name,
+    Description,
+    Definition, // Group settings & filters, criteria values may contain placeholders such as {corporation_id}
+    Created,
+}
@@ -0,0 +1,121 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240222_000001_initial::AuthUser;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupApplicationComment::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationComment::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationComment::ApplicationId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationComment::AuthorId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationComment::Body)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationComment::Internal)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationComment::Created)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_application_comment-application_id")
+                    .table(AuthGroupApplicationComment::Table)
+                    .col(AuthGroupApplicationComment::ApplicationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_application_comment-auth_group_application")
+                    .from_tbl(AuthGroupApplicationComment::Table)
+                    .from_col(AuthGroupApplicationComment::ApplicationId)
+                    .to_tbl(AuthGroupApplication::Table)
+                    .to_col(AuthGroupApplication::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_application_comment-auth_user")
+                    .from_tbl(AuthGroupApplicationComment::Table)
+                    .from_col(AuthGroupApplicationComment::AuthorId)
+                    .to_tbl(AuthUser::Table)
+                    .to_col(AuthUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AuthGroupApplicationComment::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupApplicationComment {
+    Table,
+    Id,
+    ApplicationId,
+    AuthorId,
+    Body,
+    Internal, // Reviewer notes hidden from the applicant
+    Created,
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupApplication {
+    Table,
+    Id,
+}
@@ -0,0 +1,230 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Alias::new("group_question_type"))
+                    .values([
+                        Alias::new("Text"),
+                        Alias::new("Choice"),
+                        Alias::new("Boolean"),
+                        Alias::new("Number"),
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupQuestion::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupQuestion::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupQuestion::GroupId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupQuestion::Question)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupQuestion::QuestionType)
+                            .enumeration(
+                                Alias::new("group_question_type"),
+                                [
+                                    Alias::new("Text"),
+                                    Alias::new("Choice"),
+                                    Alias::new("Boolean"),
+                                    Alias::new("Number"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthGroupQuestion::Choices).json_binary())
+                    .col(
+                        ColumnDef::new(AuthGroupQuestion::Required)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupQuestion::Ordering)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_question-group_id")
+                    .table(AuthGroupQuestion::Table)
+                    .col(AuthGroupQuestion::GroupId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_question-auth_group")
+                    .from_tbl(AuthGroupQuestion::Table)
+                    .from_col(AuthGroupQuestion::GroupId)
+                    .to_tbl(AuthGroup::Table)
+                    .to_col(AuthGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupApplicationAnswer::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationAnswer::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationAnswer::ApplicationId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthGroupApplicationAnswer::QuestionId).integer())
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationAnswer::Question)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationAnswer::Answer)
+                            .text()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_application_answer-application_id")
+                    .table(AuthGroupApplicationAnswer::Table)
+                    .col(AuthGroupApplicationAnswer::ApplicationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_application_answer-auth_group_application")
+                    .from_tbl(AuthGroupApplicationAnswer::Table)
+                    .from_col(AuthGroupApplicationAnswer::ApplicationId)
+                    .to_tbl(AuthGroupApplication::Table)
+                    .to_col(AuthGroupApplication::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_application_answer-auth_group_question")
+                    .from_tbl(AuthGroupApplicationAnswer::Table)
+                    .from_col(AuthGroupApplicationAnswer::QuestionId)
+                    .to_tbl(AuthGroupQuestion::Table)
+                    .to_col(AuthGroupQuestion::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AuthGroupApplicationAnswer::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(AuthGroupQuestion::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Alias::new("group_question_type"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupQuestion {
+    Table,
+    Id,
+    GroupId,
+    Question,
+    QuestionType, // Text, Choice, Boolean, Number
+    Choices,      // Array of options for choice questions
+    Required,
+    Ordering, // Questions are asked in ascending order
+}
+
+// Answers keep a copy of the question so they still make sense after the questionnaire changes
+#[derive(DeriveIden)]
+enum AuthGroupApplicationAnswer {
+    Table,
+    Id,
+    ApplicationId,
+    QuestionId, // Null once the question is removed from the questionnaire
+    Question,
+    Answer,
+}
+
+#[derive(DeriveIden)]
+enum AuthGroup {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupApplication {
+    Table,
+    Id,
+}
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(Alias::new("group_application_status"))
+                    .add_value(Alias::new("Expired"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(Alias::new("group_application_status"))
+                    .add_value(Alias::new("Withdrawn"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroup::Table)
+                    .add_column(ColumnDef::new(AuthGroup::ApplicationExpiryDays).integer())
+                    .add_column(ColumnDef::new(AuthGroup::ApplicationCooldownDays).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // Postgres doesn't support removing values from an enum type so the Expired & Withdrawn
+    // statuses are left in place
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroup::Table)
+                    .drop_column(AuthGroup::ApplicationExpiryDays)
+                    .drop_column(AuthGroup::ApplicationCooldownDays)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroup {
+    Table,
+    ApplicationExpiryDays, // Outstanding applications become Expired after this many days
+    ApplicationCooldownDays, // Days after a rejection before the user can apply again
+}
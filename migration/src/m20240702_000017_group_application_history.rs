@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let statuses = [
+            Alias::new("Outstanding"),
+            Alias::new("Accepted"),
+            Alias::new("Rejected"),
+            Alias::new("Expired"),
+            Alias::new("Withdrawn"),
+        ];
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupApplicationHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationHistory::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationHistory::ApplicationId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthGroupApplicationHistory::ActorId).integer())
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationHistory::OldStatus)
+                            .enumeration(Alias::new("group_application_status"), statuses.clone()),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationHistory::NewStatus)
+                            .enumeration(Alias::new("group_application_status"), statuses)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthGroupApplicationHistory::Message).text())
+                    .col(
+                        ColumnDef::new(AuthGroupApplicationHistory::Created)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_application_history-application_id")
+                    .table(AuthGroupApplicationHistory::Table)
+                    .col(AuthGroupApplicationHistory::ApplicationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_application_history-auth_group_application")
+                    .from_tbl(AuthGroupApplicationHistory::Table)
+                    .from_col(AuthGroupApplicationHistory::ApplicationId)
+                    .to_tbl(AuthGroupApplication::Table)
+                    .to_col(AuthGroupApplication::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AuthGroupApplicationHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+// Append only, the actor has no foreign key so entries are kept after the user is deleted
+#[derive(DeriveIden)]
+enum AuthGroupApplicationHistory {
+    Table,
+    Id,
+    ApplicationId,
+    ActorId,   // User who changed the status, null for changes made by the auth itself
+    OldStatus, // Null for the entry recorded when the application is created
+    NewStatus,
+    Message, // Request message on creation, response message when accepted or rejected
+    Created,
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupApplication {
+    Table,
+    Id,
+}
@@ -0,0 +1,83 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240222_000001_initial::AuthUser;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroupApplication::Table)
+                    .add_column(ColumnDef::new(AuthGroupApplication::AssigneeId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_application-assignee_id")
+                    .table(AuthGroupApplication::Table)
+                    .col(AuthGroupApplication::AssigneeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_application_assignee_id-user_id")
+                    .from_tbl(AuthGroupApplication::Table)
+                    .from_col(AuthGroupApplication::AssigneeId)
+                    .to_tbl(AuthUser::Table)
+                    .to_col(AuthUser::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                sea_query::ForeignKey::drop()
+                    .name("fk-auth_group_application_assignee_id-user_id")
+                    .table(AuthGroupApplication::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-auth_group_application-assignee_id")
+                    .table(AuthGroupApplication::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthGroupApplication::Table)
+                    .drop_column(AuthGroupApplication::AssigneeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupApplication {
+    Table,
+    AssigneeId, // Reviewer who claimed or was assigned the application, null when unassigned
+}
@@ -0,0 +1,188 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+use crate::m20240222_000001_initial::AuthUser;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(Alias::new("group_membership_reason"))
+                    .add_value(Alias::new("Invitation"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Alias::new("group_invitation_status"))
+                    .values([
+                        Alias::new("Pending"),
+                        Alias::new("Accepted"),
+                        Alias::new("Declined"),
+                        Alias::new("Revoked"),
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthGroupInvitation::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthGroupInvitation::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupInvitation::GroupId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupInvitation::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthGroupInvitation::InvitedBy).integer())
+                    .col(
+                        ColumnDef::new(AuthGroupInvitation::Status)
+                            .enumeration(
+                                Alias::new("group_invitation_status"),
+                                [
+                                    Alias::new("Pending"),
+                                    Alias::new("Accepted"),
+                                    Alias::new("Declined"),
+                                    Alias::new("Revoked"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthGroupInvitation::Message).text())
+                    .col(ColumnDef::new(AuthGroupInvitation::Expires).timestamp())
+                    .col(
+                        ColumnDef::new(AuthGroupInvitation::Created)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthGroupInvitation::LastUpdated)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_invitation-group_id")
+                    .table(AuthGroupInvitation::Table)
+                    .col(AuthGroupInvitation::GroupId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_group_invitation-user_id")
+                    .table(AuthGroupInvitation::Table)
+                    .col(AuthGroupInvitation::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_invitation-auth_group")
+                    .from_tbl(AuthGroupInvitation::Table)
+                    .from_col(AuthGroupInvitation::GroupId)
+                    .to_tbl(AuthGroup::Table)
+                    .to_col(AuthGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_invitation-auth_user")
+                    .from_tbl(AuthGroupInvitation::Table)
+                    .from_col(AuthGroupInvitation::UserId)
+                    .to_tbl(AuthUser::Table)
+                    .to_col(AuthUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_group_invitation_invited_by-user_id")
+                    .from_tbl(AuthGroupInvitation::Table)
+                    .from_col(AuthGroupInvitation::InvitedBy)
+                    .to_tbl(AuthUser::Table)
+                    .to_col(AuthUser::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // Postgres doesn't support removing values from an enum type so the Invitation membership
+    // reason is left in place
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuthGroupInvitation::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Alias::new("group_invitation_status"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupInvitation {
+    Table,
+    Id,
+    GroupId,
+    UserId,
+    InvitedBy, // Manager who sent the invitation, null if their user has since been deleted
+    Status,    // Pending, Accepted, Declined, Revoked
+    Message,
+    Expires, // Pending invitations past this time can no longer be accepted, never expires when null
+    Created,
+    LastUpdated,
+}
+
+#[derive(DeriveIden)]
+enum AuthGroup {
+    Table,
+    Id,
+}
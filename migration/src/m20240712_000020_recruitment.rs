@@ -0,0 +1,289 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+use crate::m20240222_000001_initial::AuthUser;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Alias::new("recruitment_status"))
+                    .values([
+                        Alias::new("AwaitingScopes"),
+                        Alias::new("Vetting"),
+                        Alias::new("Approved"),
+                        Alias::new("Joined"),
+                        Alias::new("Rejected"),
+                        Alias::new("Withdrawn"),
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthRecruitmentPipeline::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthRecruitmentPipeline::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitmentPipeline::Name)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitmentPipeline::CorporationId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitmentPipeline::GroupId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitmentPipeline::RequiredScopes)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitmentPipeline::Open)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitmentPipeline::Created)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_recruitment_pipeline-auth_group")
+                    .from_tbl(AuthRecruitmentPipeline::Table)
+                    .from_col(AuthRecruitmentPipeline::GroupId)
+                    .to_tbl(AuthGroup::Table)
+                    .to_col(AuthGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthRecruitment::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthRecruitment::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitment::PipelineId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthRecruitment::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(AuthRecruitment::CharacterId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitment::ApplicationId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitment::Status)
+                            .enumeration(
+                                Alias::new("recruitment_status"),
+                                [
+                                    Alias::new("AwaitingScopes"),
+                                    Alias::new("Vetting"),
+                                    Alias::new("Approved"),
+                                    Alias::new("Joined"),
+                                    Alias::new("Rejected"),
+                                    Alias::new("Withdrawn"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthRecruitment::GrantedScopes).text())
+                    .col(ColumnDef::new(AuthRecruitment::RecruiterId).integer())
+                    .col(ColumnDef::new(AuthRecruitment::ResponseMessage).text())
+                    .col(
+                        ColumnDef::new(AuthRecruitment::Created)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthRecruitment::LastUpdated)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthRecruitment::Joined).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_recruitment-pipeline_id")
+                    .table(AuthRecruitment::Table)
+                    .col(AuthRecruitment::PipelineId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-auth_recruitment-character_id")
+                    .table(AuthRecruitment::Table)
+                    .col(AuthRecruitment::CharacterId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_recruitment-auth_recruitment_pipeline")
+                    .from_tbl(AuthRecruitment::Table)
+                    .from_col(AuthRecruitment::PipelineId)
+                    .to_tbl(AuthRecruitmentPipeline::Table)
+                    .to_col(AuthRecruitmentPipeline::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_recruitment-auth_user")
+                    .from_tbl(AuthRecruitment::Table)
+                    .from_col(AuthRecruitment::UserId)
+                    .to_tbl(AuthUser::Table)
+                    .to_col(AuthUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_recruitment-auth_group_application")
+                    .from_tbl(AuthRecruitment::Table)
+                    .from_col(AuthRecruitment::ApplicationId)
+                    .to_tbl(AuthGroupApplication::Table)
+                    .to_col(AuthGroupApplication::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk-auth_recruitment_recruiter_id-user_id")
+                    .from_tbl(AuthRecruitment::Table)
+                    .from_col(AuthRecruitment::RecruiterId)
+                    .to_tbl(AuthUser::Table)
+                    .to_col(AuthUser::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuthRecruitment::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AuthRecruitmentPipeline::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Alias::new("recruitment_status"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthRecruitmentPipeline {
+    Table,
+    Id,
+    Name,
+    CorporationId,  // Corporation prospects are recruited into
+    GroupId,        // Membership group recruits are handed off to
+    RequiredScopes, // Space separated ESI scopes prospects must grant before vetting
+    Open,
+    Created,
+}
+
+#[derive(DeriveIden)]
+enum AuthRecruitment {
+    Table,
+    Id,
+    PipelineId,
+    UserId,
+    CharacterId,   // Character being recruited, must be owned by the user
+    ApplicationId, // Application to the pipeline's group holding the form answers & comments
+    Status,        // AwaitingScopes, Vetting, Approved, Joined, Rejected, Withdrawn
+    GrantedScopes, // Space separated ESI scopes granted during the recruitment login
+    RecruiterId,   // Recruiter who approved or rejected the prospect
+    ResponseMessage,
+    Created,
+    LastUpdated,
+    Joined, // When the character was seen in the corporation & handed off to the group
+}
+
+#[derive(DeriveIden)]
+enum AuthGroup {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum AuthGroupApplication {
+    Table,
+    Id,
+}
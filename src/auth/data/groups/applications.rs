@@ -1,31 +1,70 @@
 use anyhow::anyhow;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DeleteResult, EntityTrait,
-    QueryFilter,
+    sea_query::Query, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition,
+    DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Select,
 };
 use std::collections::{HashMap, HashSet};
 
 use crate::{
-    auth::model::groups::GroupApplicationDto, eve::service::affiliation::get_character_affiliations,
+    auth::model::groups::{
+        ApplicationActionResultDto, ApplicationMembershipDto, GroupApplicationAnswerDto,
+        GroupApplicationCommentDto, GroupApplicationDto, GroupApplicationHistoryDto,
+        GroupApplicationQueueDto, GroupApplicationSettingsDto, NewGroupApplicationCommentDto,
+    },
+    eve::{
+        model::character::CharacterAffiliationDto, service::affiliation::get_character_affiliations,
+    },
 };
 
-use entity::sea_orm_active_enums::{GroupApplicationStatus, GroupApplicationType, GroupType};
+use entity::sea_orm_active_enums::{
+    GroupApplicationStatus, GroupApplicationType, GroupMembershipReason, GroupType,
+};
 
-use super::get_group_by_id;
+use super::{
+    filters::validate_group_members,
+    get_group_by_id,
+    history::{get_application_history, record_application_status_changes},
+    members::{delete_group_members, insert_group_members},
+    questionnaires::get_application_answers,
+    visibility::{get_group_access, get_user_group_access, GroupAccess},
+};
 
-use crate::auth::data::user::{bulk_get_user_main_characters, get_user};
+use crate::auth::data::{
+    recruitment::open_recruitment_statuses,
+    user::{bulk_get_user_main_characters, get_user},
+};
+use entity::auth_group::Model as Group;
 use entity::auth_group_application::Model as GroupApplication;
+use entity::auth_group_application_comment::Model as GroupApplicationComment;
+use entity::auth_user::Model as User;
 
-pub async fn get_group_application(
+// Page of applications to get, pages start from 0
+pub struct ApplicationPage {
+    pub page: u64,
+    pub page_size: u64,
+}
+
+// Filters for getting applications, unset filters match every application
+#[derive(Default)]
+pub struct GroupApplicationFilter {
+    pub application_status: Option<GroupApplicationStatus>,
+    pub application_type: Option<GroupApplicationType>,
+    pub application_id: Option<i32>,
+    pub group_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub assignee_id: Option<i32>,
+    pub unassigned: bool,
+    // Only applications created more than this many days ago
+    pub older_than_days: Option<i64>,
+}
+
+// Builds the query shared by the application list & the queue summary
+async fn get_application_query(
     db: &DatabaseConnection,
-    application_status: Option<GroupApplicationStatus>,
-    application_type: Option<GroupApplicationType>,
-    application_id: Option<i32>,
-    group_id: Option<i32>,
-    user_id: Option<i32>,
-) -> Result<Vec<GroupApplicationDto>, anyhow::Error> {
-    if let Some(group_id) = group_id {
+    filter: GroupApplicationFilter,
+) -> Result<Select<entity::prelude::AuthGroupApplication>, anyhow::Error> {
+    if let Some(group_id) = filter.group_id {
         match get_group_by_id(db, group_id).await? {
             Some(group) => {
                 if group.group_type == GroupType::Open || group.group_type == GroupType::Auto {
@@ -36,7 +75,7 @@ pub async fn get_group_application(
         };
     };
 
-    if let Some(user_id) = user_id {
+    if let Some(user_id) = filter.user_id {
         match get_user(db, user_id).await? {
             Some(_) => (),
             None => return Err(anyhow!("User does not exist")),
@@ -45,29 +84,103 @@ pub async fn get_group_application(
 
     let mut query = entity::prelude::AuthGroupApplication::find();
 
-    if let Some(application_type) = application_type {
+    if let Some(application_type) = filter.application_type {
         query = query
             .filter(entity::auth_group_application::Column::RequestType.eq(Some(application_type)));
     }
 
-    if let Some(application_status) = application_status {
+    if let Some(application_status) = filter.application_status {
         query = query
             .filter(entity::auth_group_application::Column::Status.eq(Some(application_status)));
     }
 
-    if let Some(application_id) = application_id {
+    if let Some(application_id) = filter.application_id {
         query = query.filter(entity::auth_group_application::Column::Id.eq(Some(application_id)));
     }
 
-    if let Some(group_id) = group_id {
+    if let Some(group_id) = filter.group_id {
         query = query.filter(entity::auth_group_application::Column::GroupId.eq(Some(group_id)));
     }
 
-    if let Some(user_id) = user_id {
+    if let Some(user_id) = filter.user_id {
         query = query.filter(entity::auth_group_application::Column::UserId.eq(Some(user_id)));
     }
 
-    let applications = query.all(db).await?;
+    if let Some(assignee_id) = filter.assignee_id {
+        query = query.filter(entity::auth_group_application::Column::AssigneeId.eq(assignee_id));
+    }
+
+    if filter.unassigned {
+        query = query.filter(entity::auth_group_application::Column::AssigneeId.is_null());
+    }
+
+    if let Some(older_than_days) = filter.older_than_days {
+        let age = match Duration::try_days(older_than_days) {
+            Some(age) if older_than_days >= 0 => age,
+            _ => return Err(anyhow!("Invalid application age")),
+        };
+
+        query = query.filter(
+            entity::auth_group_application::Column::Created.lt((Utc::now() - age).naive_utc()),
+        );
+    }
+
+    Ok(query)
+}
+
+pub async fn get_group_application(
+    db: &DatabaseConnection,
+    filter: GroupApplicationFilter,
+    page: Option<ApplicationPage>,
+    // Internal comments are reviewer notes, only include them for reviewers
+    include_internal: bool,
+) -> Result<Vec<GroupApplicationDto>, anyhow::Error> {
+    if page.as_ref().is_some_and(|page| page.page_size == 0) {
+        return Err(anyhow!("Invalid page size"));
+    }
+
+    let query = get_application_query(db, filter).await?;
+
+    // Newest first, ordered by id as well so pages are stable for applications created together
+    let query = query
+        .order_by_desc(entity::auth_group_application::Column::Created)
+        .order_by_desc(entity::auth_group_application::Column::Id);
+
+    let applications = match page {
+        Some(page) => {
+            query
+                .paginate(db, page.page_size)
+                .fetch_page(page.page)
+                .await?
+        }
+        None => query.all(db).await?,
+    };
+
+    let application_ids: Vec<i32> = applications.iter().map(|app| app.id).collect();
+
+    let mut comments: HashMap<i32, Vec<GroupApplicationCommentDto>> = HashMap::new();
+
+    let mut answers: HashMap<i32, Vec<GroupApplicationAnswerDto>> = HashMap::new();
+
+    for answer in get_application_answers(db, application_ids.clone()).await? {
+        answers
+            .entry(answer.application_id)
+            .or_default()
+            .push(answer.into());
+    }
+
+    let mut history: HashMap<i32, Vec<GroupApplicationHistoryDto>> = HashMap::new();
+
+    for entry in get_application_history(db, application_ids.clone()).await? {
+        history.entry(entry.application_id).or_default().push(entry);
+    }
+
+    for comment in get_application_comments(db, application_ids, include_internal).await? {
+        comments
+            .entry(comment.application_id)
+            .or_default()
+            .push(comment);
+    }
 
     let user_ids: HashSet<i32> = applications
         .iter()
@@ -76,71 +189,95 @@ pub async fn get_group_application(
             if let Some(responder_id) = app.responder {
                 ids.push(responder_id);
             }
+            if let Some(assignee_id) = app.assignee_id {
+                ids.push(assignee_id);
+            }
             ids.into_iter()
         })
         .collect();
     let user_ids: Vec<i32> = user_ids.into_iter().collect();
 
-    let mains = bulk_get_user_main_characters(db, user_ids).await?;
-
-    let character_ids = mains
-        .iter()
-        .map(|main| main.character_id)
-        .collect::<Vec<i32>>();
-    let affiliations = get_character_affiliations(db, character_ids).await?;
-
-    let mut applications_map: HashMap<i32, _> = applications
+    let mains: HashMap<i32, i32> = bulk_get_user_main_characters(db, user_ids)
+        .await?
         .into_iter()
-        .map(|app| (app.user_id, app))
+        .map(|main| (main.user_id, main.character_id))
         .collect();
 
-    let mut group_applications = vec![];
+    let character_ids = mains.values().copied().collect::<Vec<i32>>();
+    let affiliations: HashMap<i32, CharacterAffiliationDto> =
+        get_character_affiliations(db, character_ids)
+            .await?
+            .into_iter()
+            .map(|affiliation| (affiliation.character_id, affiliation))
+            .collect();
 
-    for main in mains.clone() {
-        if let (Some(character), Some(application)) = (
-            affiliations
-                .iter()
-                .find(|affiliation| affiliation.character_id == main.character_id),
-            applications_map.remove(&main.user_id),
-        ) {
-            let mut responder_info = None;
-
-            if let Some(responder) = application.responder {
-                let main_character = mains
-                    .iter()
-                    .find(|main| main.user_id == responder)
-                    .map(|main| main.character_id);
-
-                if let Some(main_character) = main_character {
-                    let responder_character = affiliations
-                        .iter()
-                        .find(|affiliation| affiliation.character_id == main_character);
+    // Users without a main or whose character data is missing are listed without character info
+    let get_character_info = |user_id: i32| {
+        mains
+            .get(&user_id)
+            .and_then(|character_id| affiliations.get(character_id))
+            .cloned()
+    };
 
-                    if let Some(responder_character) = responder_character {
-                        responder_info = Some(responder_character.clone());
-                    }
-                }
-            };
+    let group_applications = applications
+        .into_iter()
+        .map(|application| GroupApplicationDto {
+            id: application.id,
+            group_id: application.group_id,
+            user_id: application.user_id,
+            applicant_info: get_character_info(application.user_id),
+            responder_info: application.responder.and_then(get_character_info),
+            assignee_id: application.assignee_id,
+            assignee_info: application.assignee_id.and_then(get_character_info),
+            status: application.status.into(),
+            request_type: application.request_type.into(),
+            request_message: application.request_message,
+            response_message: application.response_message,
+            created: DateTime::from_naive_utc_and_offset(application.created, Utc),
+            last_updated: DateTime::from_naive_utc_and_offset(application.last_updated, Utc),
+            answers: answers.remove(&application.id).unwrap_or_default(),
+            comments: comments.remove(&application.id).unwrap_or_default(),
+            history: history.remove(&application.id).unwrap_or_default(),
+        })
+        .collect::<Vec<GroupApplicationDto>>();
 
-            let group_application = GroupApplicationDto {
-                id: application.id,
-                group_id: application.group_id,
-                user_id: application.user_id,
-                applicant_info: character.clone(),
-                responder_info,
-                status: application.status.into(),
-                request_type: application.request_type.into(),
-                request_message: application.request_message,
-                response_message: application.response_message,
-                created: DateTime::from_naive_utc_and_offset(application.created, Utc),
-                last_updated: DateTime::from_naive_utc_and_offset(application.last_updated, Utc),
-            };
+    Ok(group_applications)
+}
 
-            group_applications.push(group_application);
-        }
+// Creates an outstanding application & records it as the first entry of the application's history
+pub async fn create_group_application(
+    db: &DatabaseConnection,
+    group_id: i32,
+    user_id: i32,
+    request_type: GroupApplicationType,
+    request_message: Option<String>,
+) -> Result<GroupApplication, DbErr> {
+    let now = Utc::now().naive_utc();
+
+    let application = entity::auth_group_application::ActiveModel {
+        group_id: Set(group_id),
+        user_id: Set(user_id),
+        request_type: Set(request_type),
+        status: Set(GroupApplicationStatus::Outstanding),
+        request_message: Set(request_message),
+        created: Set(now),
+        last_updated: Set(now),
+        ..Default::default()
     }
+    .insert(db)
+    .await?;
 
-    Ok(group_applications)
+    record_application_status_changes(
+        db,
+        &[application.id],
+        None,
+        GroupApplicationStatus::Outstanding,
+        Some(user_id),
+        application.request_message.clone(),
+    )
+    .await?;
+
+    Ok(application)
 }
 
 pub async fn update_group_application(
@@ -162,6 +299,8 @@ pub async fn update_group_application(
                 return Err(anyhow!("Not allowed to update a completed application"));
             }
 
+            let old_status = application.status.clone();
+
             let mut application: entity::auth_group_application::ActiveModel = application.into();
 
             if let Some(application_request_message) = application_request_message {
@@ -176,11 +315,11 @@ pub async fn update_group_application(
                 if application_response_message.is_empty() {
                     application.response_message = Set(None);
                 } else {
-                    application.request_message = Set(Some(application_response_message));
+                    application.response_message = Set(Some(application_response_message));
                 }
             }
 
-            if let Some(application_status) = application_status {
+            if let Some(application_status) = application_status.clone() {
                 application.status = Set(application_status);
             }
 
@@ -192,33 +331,1506 @@ pub async fn update_group_application(
 
             let application = application.update(db).await?;
 
+            if let Some(application_status) = application_status {
+                record_application_status_changes(
+                    db,
+                    &[application.id],
+                    Some(old_status),
+                    application_status,
+                    application_responder,
+                    application.response_message.clone(),
+                )
+                .await?;
+            }
+
             Ok(application)
         }
         None => Err(anyhow!("Application not found")),
     }
 }
 
-pub async fn delete_group_application(
+// Accepting re-validates a join application against the group's filters as they may have
+// changed since the user applied, the application is left outstanding if the user is no longer
+// eligible. The membership is updated before the application so a failure doesn't leave an
+// accepted application without the matching membership.
+pub async fn respond_to_group_application(
     db: &DatabaseConnection,
     application_id: i32,
-) -> Result<DeleteResult, anyhow::Error> {
-    let application = entity::prelude::AuthGroupApplication::find()
-        .filter(entity::auth_group_application::Column::Id.eq(application_id))
+    responder_id: i32,
+    application_status: GroupApplicationStatus,
+    response_message: Option<String>,
+    duration: Option<Duration>,
+) -> Result<ApplicationMembershipDto, anyhow::Error> {
+    let application = match get_application_by_id(db, application_id).await? {
+        Some(application) => application,
+        None => return Err(anyhow!("Application not found")),
+    };
+
+    if application.status != GroupApplicationStatus::Outstanding {
+        return Err(anyhow!("Not allowed to update a completed application"));
+    }
+
+    if application_status == GroupApplicationStatus::Accepted {
+        if get_group_by_id(db, application.group_id).await?.is_none() {
+            return Err(anyhow!("Group does not exist"));
+        }
+
+        match application.request_type {
+            GroupApplicationType::Join => {
+                if duration.is_some_and(|duration| duration <= Duration::zero()) {
+                    return Err(anyhow!("Invalid membership duration"));
+                }
+
+                let eligible_user_ids =
+                    validate_group_members(db, application.group_id, vec![application.user_id])
+                        .await?;
+
+                if !eligible_user_ids.contains(&application.user_id) {
+                    return Err(anyhow!("User does not meet group requirements"));
+                }
+
+                insert_group_members(
+                    db,
+                    application.group_id,
+                    eligible_user_ids,
+                    Some(responder_id),
+                    duration,
+                    GroupMembershipReason::Application,
+                )
+                .await?;
+            }
+            GroupApplicationType::Leave => {
+                delete_group_members(
+                    db,
+                    application.group_id,
+                    vec![application.user_id],
+                    Some(responder_id),
+                    GroupMembershipReason::Application,
+                )
+                .await?;
+            }
+        }
+    }
+
+    let application = update_group_application(
+        db,
+        application_id,
+        None,
+        Some(response_message.unwrap_or_default()),
+        Some(application_status),
+        Some(responder_id),
+    )
+    .await?;
+
+    let membership = entity::prelude::AuthGroupUser::find()
+        .filter(entity::auth_group_user::Column::GroupId.eq(application.group_id))
+        .filter(entity::auth_group_user::Column::UserId.eq(application.user_id))
         .one(db)
         .await?;
 
-    match application {
+    Ok(ApplicationMembershipDto {
+        application_id: application.id,
+        group_id: application.group_id,
+        user_id: application.user_id,
+        status: application.status.into(),
+        member: membership.is_some(),
+        expires: membership
+            .and_then(|membership| membership.expires)
+            .map(|expires| DateTime::from_naive_utc_and_offset(expires, Utc)),
+    })
+}
+
+// Accepts or rejects the applications together, validating accepted join applications against
+// their group's filters once per group. Applications which can't be updated are left unchanged
+// & reported alongside the error in the results.
+pub async fn bulk_update_group_applications(
+    db: &DatabaseConnection,
+    responder: &User,
+    application_ids: Vec<i32>,
+    application_status: GroupApplicationStatus,
+    response_message: Option<String>,
+    duration: Option<Duration>,
+) -> Result<Vec<ApplicationActionResultDto>, anyhow::Error> {
+    let applications = entity::prelude::AuthGroupApplication::find()
+        .filter(entity::auth_group_application::Column::Id.is_in(application_ids.clone()))
+        .all(db)
+        .await?;
+
+    let group_ids: HashSet<i32> = applications.iter().map(|app| app.group_id).collect();
+
+    let groups = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::Id.is_in(group_ids))
+        .filter(entity::auth_group::Column::Archived.is_null())
+        .all(db)
+        .await?;
+
+    let access = get_group_access(db, responder, &groups).await?;
+
+    let mut errors: HashMap<i32, String> = HashMap::new();
+    let mut pending: Vec<&GroupApplication> = vec![];
+
+    for application in &applications {
+        let error = if !groups.iter().any(|group| group.id == application.group_id) {
+            Some("Group does not exist")
+        } else if access.get(&application.group_id) != Some(&GroupAccess::Manager) {
+            Some("Insufficient permissions")
+        } else if application.status != GroupApplicationStatus::Outstanding {
+            Some("Not allowed to update a completed application")
+        } else {
+            None
+        };
+
+        match error {
+            Some(error) => {
+                errors.insert(application.id, error.to_string());
+            }
+            None => pending.push(application),
+        }
+    }
+
+    if application_status == GroupApplicationStatus::Accepted {
+        let pending_group_ids: HashSet<i32> = pending.iter().map(|app| app.group_id).collect();
+
+        for group_id in pending_group_ids {
+            let group_applications: Vec<&&GroupApplication> = pending
+                .iter()
+                .filter(|app| app.group_id == group_id)
+                .collect();
+
+            let join_user_ids: Vec<i32> = group_applications
+                .iter()
+                .filter(|app| app.request_type == GroupApplicationType::Join)
+                .map(|app| app.user_id)
+                .collect();
+
+            let leave_user_ids: Vec<i32> = group_applications
+                .iter()
+                .filter(|app| app.request_type == GroupApplicationType::Leave)
+                .map(|app| app.user_id)
+                .collect();
+
+            if !join_user_ids.is_empty() {
+                let eligible_user_ids = validate_group_members(db, group_id, join_user_ids).await?;
+
+                for application in &group_applications {
+                    if application.request_type == GroupApplicationType::Join
+                        && !eligible_user_ids.contains(&application.user_id)
+                    {
+                        errors.insert(
+                            application.id,
+                            "User does not meet group requirements".to_string(),
+                        );
+                    }
+                }
+
+                insert_group_members(
+                    db,
+                    group_id,
+                    eligible_user_ids,
+                    Some(responder.id),
+                    duration,
+                    GroupMembershipReason::Application,
+                )
+                .await?;
+            }
+
+            if !leave_user_ids.is_empty() {
+                delete_group_members(
+                    db,
+                    group_id,
+                    leave_user_ids,
+                    Some(responder.id),
+                    GroupMembershipReason::Application,
+                )
+                .await?;
+            }
+        }
+    }
+
+    let updated_ids: Vec<i32> = pending
+        .iter()
+        .map(|app| app.id)
+        .filter(|id| !errors.contains_key(id))
+        .collect();
+
+    let response_message = response_message.filter(|message| !message.is_empty());
+
+    if !updated_ids.is_empty() {
+        entity::prelude::AuthGroupApplication::update_many()
+            .set(entity::auth_group_application::ActiveModel {
+                status: Set(application_status.clone()),
+                response_message: Set(response_message.clone()),
+                responder: Set(Some(responder.id)),
+                last_updated: Set(Utc::now().naive_utc()),
+                ..Default::default()
+            })
+            .filter(entity::auth_group_application::Column::Id.is_in(updated_ids.clone()))
+            .exec(db)
+            .await?;
+
+        record_application_status_changes(
+            db,
+            &updated_ids,
+            Some(GroupApplicationStatus::Outstanding),
+            application_status.clone(),
+            Some(responder.id),
+            response_message,
+        )
+        .await?;
+    }
+
+    let mut seen = HashSet::new();
+
+    let results = application_ids
+        .into_iter()
+        .filter(|id| seen.insert(*id))
+        .map(|id| {
+            if updated_ids.contains(&id) {
+                ApplicationActionResultDto {
+                    application_id: id,
+                    status: Some(application_status.clone().into()),
+                    error: None,
+                }
+            } else {
+                ApplicationActionResultDto {
+                    application_id: id,
+                    status: None,
+                    error: Some(
+                        errors
+                            .remove(&id)
+                            .unwrap_or_else(|| "Application not found".to_string()),
+                    ),
+                }
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+pub async fn get_application_queues(
+    db: &DatabaseConnection,
+    filter: GroupApplicationFilter,
+) -> Result<Vec<GroupApplicationQueueDto>, anyhow::Error> {
+    let applications = get_application_query(db, filter).await?.all(db).await?;
+
+    let now = Utc::now().naive_utc();
+    let mut queues: HashMap<i32, GroupApplicationQueueDto> = HashMap::new();
+
+    for application in applications {
+        let queue =
+            queues
+                .entry(application.group_id)
+                .or_insert_with(|| GroupApplicationQueueDto {
+                    group_id: application.group_id,
+                    outstanding: 0,
+                    accepted: 0,
+                    rejected: 0,
+                    expired: 0,
+                    withdrawn: 0,
+                    unassigned: 0,
+                    outstanding_under_1_day: 0,
+                    outstanding_1_to_7_days: 0,
+                    outstanding_over_7_days: 0,
+                    oldest_outstanding: None,
+                });
+
+        match application.status {
+            GroupApplicationStatus::Outstanding => {
+                queue.outstanding += 1;
+
+                if application.assignee_id.is_none() {
+                    queue.unassigned += 1;
+                }
+
+                let age = now - application.created;
+
+                if age < Duration::days(1) {
+                    queue.outstanding_under_1_day += 1;
+                } else if age < Duration::days(7) {
+                    queue.outstanding_1_to_7_days += 1;
+                } else {
+                    queue.outstanding_over_7_days += 1;
+                }
+
+                let created = DateTime::from_naive_utc_and_offset(application.created, Utc);
+
+                let is_oldest = match queue.oldest_outstanding {
+                    Some(oldest) => created < oldest,
+                    None => true,
+                };
+
+                if is_oldest {
+                    queue.oldest_outstanding = Some(created);
+                }
+            }
+            GroupApplicationStatus::Accepted => queue.accepted += 1,
+            GroupApplicationStatus::Rejected => queue.rejected += 1,
+            GroupApplicationStatus::Expired => queue.expired += 1,
+            GroupApplicationStatus::Withdrawn => queue.withdrawn += 1,
+        }
+    }
+
+    let mut queues: Vec<GroupApplicationQueueDto> = queues.into_values().collect();
+    queues.sort_by_key(|queue| queue.group_id);
+
+    Ok(queues)
+}
+
+// Claims an unassigned application for the reviewer, an application assigned to someone else
+// has to be reassigned instead so reviewers don't take over each other's applications
+pub async fn claim_group_application(
+    db: &DatabaseConnection,
+    application_id: i32,
+    user_id: i32,
+) -> Result<GroupApplication, anyhow::Error> {
+    let application = match get_application_by_id(db, application_id).await? {
+        Some(application) => application,
+        None => return Err(anyhow!("Application not found")),
+    };
+
+    if application.status != GroupApplicationStatus::Outstanding {
+        return Err(anyhow!("Not allowed to assign a completed application"));
+    }
+
+    // The assignee is checked as part of the update so concurrent claims can't both succeed
+    let result = entity::prelude::AuthGroupApplication::update_many()
+        .set(entity::auth_group_application::ActiveModel {
+            assignee_id: Set(Some(user_id)),
+            ..Default::default()
+        })
+        .filter(entity::auth_group_application::Column::Id.eq(application_id))
+        .filter(
+            Condition::any()
+                .add(entity::auth_group_application::Column::AssigneeId.is_null())
+                .add(entity::auth_group_application::Column::AssigneeId.eq(user_id)),
+        )
+        .exec(db)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(anyhow!("Application already claimed"));
+    }
+
+    match get_application_by_id(db, application_id).await? {
+        Some(application) => Ok(application),
+        None => Err(anyhow!("Application not found")),
+    }
+}
+
+// Assigns the application to a reviewer who is able to manage its group, None unassigns it
+pub async fn assign_group_application(
+    db: &DatabaseConnection,
+    application_id: i32,
+    assignee_id: Option<i32>,
+) -> Result<GroupApplication, anyhow::Error> {
+    let application = match get_application_by_id(db, application_id).await? {
+        Some(application) => application,
+        None => return Err(anyhow!("Application not found")),
+    };
+
+    if application.status != GroupApplicationStatus::Outstanding {
+        return Err(anyhow!("Not allowed to assign a completed application"));
+    }
+
+    if let Some(assignee_id) = assignee_id {
+        let assignee = match get_user(db, assignee_id).await? {
+            Some(assignee) => assignee,
+            None => return Err(anyhow!("Assignee does not exist")),
+        };
+
+        let access = get_user_group_access(db, &assignee, application.group_id).await?;
+
+        if !matches!(access, Some((_, GroupAccess::Manager))) {
+            return Err(anyhow!(
+                "Assignee is not able to review applications for this group"
+            ));
+        }
+    }
+
+    let mut application: entity::auth_group_application::ActiveModel = application.into();
+
+    application.assignee_id = Set(assignee_id);
+
+    let application = application.update(db).await?;
+
+    Ok(application)
+}
+
+// Withdrawn applications are kept as a record unlike deleted ones
+pub async fn withdraw_group_application(
+    db: &DatabaseConnection,
+    application_id: i32,
+) -> Result<GroupApplication, anyhow::Error> {
+    match get_application_by_id(db, application_id).await? {
         Some(application) => {
             if application.status != GroupApplicationStatus::Outstanding {
-                return Err(anyhow!("Not allowed to delete a completed application"));
+                return Err(anyhow!("Not allowed to withdraw a completed application"));
             }
 
-            let result = entity::prelude::AuthGroupApplication::delete_by_id(application_id)
-                .exec(db)
-                .await?;
+            let mut application: entity::auth_group_application::ActiveModel = application.into();
+
+            application.status = Set(GroupApplicationStatus::Withdrawn);
+            application.last_updated = Set(Utc::now().naive_utc());
 
-            Ok(result)
+            let application = application.update(db).await?;
+
+            record_application_status_changes(
+                db,
+                &[application.id],
+                Some(GroupApplicationStatus::Outstanding),
+                GroupApplicationStatus::Withdrawn,
+                Some(application.user_id),
+                None,
+            )
+            .await?;
+
+            Ok(application)
         }
         None => Err(anyhow!("Application not found")),
     }
 }
+
+pub async fn get_application_by_id(
+    db: &DatabaseConnection,
+    application_id: i32,
+) -> Result<Option<GroupApplication>, DbErr> {
+    entity::prelude::AuthGroupApplication::find()
+        .filter(entity::auth_group_application::Column::Id.eq(application_id))
+        .one(db)
+        .await
+}
+
+async fn get_comment_dtos(
+    db: &DatabaseConnection,
+    comments: Vec<GroupApplicationComment>,
+) -> Result<Vec<GroupApplicationCommentDto>, DbErr> {
+    let author_ids: HashSet<i32> = comments.iter().map(|comment| comment.author_id).collect();
+
+    let mains = bulk_get_user_main_characters(db, author_ids.into_iter().collect()).await?;
+
+    let character_ids = mains.iter().map(|main| main.character_id).collect();
+    let affiliations = get_character_affiliations(db, character_ids).await?;
+
+    let comments = comments
+        .into_iter()
+        .map(|comment| {
+            let author_info = mains
+                .iter()
+                .find(|main| main.user_id == comment.author_id)
+                .and_then(|main| {
+                    affiliations
+                        .iter()
+                        .find(|affiliation| affiliation.character_id == main.character_id)
+                })
+                .cloned();
+
+            GroupApplicationCommentDto {
+                id: comment.id,
+                application_id: comment.application_id,
+                author_id: comment.author_id,
+                author_info,
+                body: comment.body,
+                internal: comment.internal,
+                created: DateTime::from_naive_utc_and_offset(comment.created, Utc),
+            }
+        })
+        .collect();
+
+    Ok(comments)
+}
+
+pub async fn get_application_comments(
+    db: &DatabaseConnection,
+    application_ids: Vec<i32>,
+    // Set false when listing comments for the applicant to leave out reviewer notes
+    include_internal: bool,
+) -> Result<Vec<GroupApplicationCommentDto>, DbErr> {
+    let mut query = entity::prelude::AuthGroupApplicationComment::find()
+        .filter(
+            entity::auth_group_application_comment::Column::ApplicationId.is_in(application_ids),
+        )
+        .order_by_asc(entity::auth_group_application_comment::Column::Created)
+        .order_by_asc(entity::auth_group_application_comment::Column::Id);
+
+    if !include_internal {
+        query = query.filter(entity::auth_group_application_comment::Column::Internal.eq(false));
+    }
+
+    let comments = query.all(db).await?;
+
+    get_comment_dtos(db, comments).await
+}
+
+pub async fn create_application_comment(
+    db: &DatabaseConnection,
+    application_id: i32,
+    author_id: i32,
+    comment: NewGroupApplicationCommentDto,
+) -> Result<GroupApplicationCommentDto, anyhow::Error> {
+    if comment.body.trim().is_empty() {
+        return Err(anyhow!("Comment can't be empty"));
+    }
+
+    if get_application_by_id(db, application_id).await?.is_none() {
+        return Err(anyhow!("Application not found"));
+    }
+
+    let comment = entity::auth_group_application_comment::ActiveModel {
+        application_id: Set(application_id),
+        author_id: Set(author_id),
+        body: Set(comment.body),
+        internal: Set(comment.internal),
+        created: Set(Utc::now().naive_utc()),
+        ..Default::default()
+    };
+
+    let comment = comment.insert(db).await?;
+
+    let mut comments = get_comment_dtos(db, vec![comment]).await?;
+
+    comments
+        .pop()
+        .ok_or_else(|| anyhow!("Error getting created comment"))
+}
+
+pub async fn update_application_settings(
+    db: &DatabaseConnection,
+    group_id: i32,
+    settings: GroupApplicationSettingsDto,
+) -> Result<Option<Group>, anyhow::Error> {
+    let days = [
+        settings.application_expiry_days,
+        settings.application_cooldown_days,
+    ];
+
+    if days.into_iter().flatten().any(|days| days < 1) {
+        return Err(anyhow!("Invalid application settings"));
+    }
+
+    if get_group_by_id(db, group_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    let group = entity::auth_group::ActiveModel {
+        id: Set(group_id),
+        application_expiry_days: Set(settings.application_expiry_days),
+        application_cooldown_days: Set(settings.application_cooldown_days),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+
+    Ok(Some(group))
+}
+
+// Prevents users from reapplying to join a group until its cooldown has passed since their
+// last rejected application
+pub async fn check_application_cooldown(
+    db: &DatabaseConnection,
+    group: &Group,
+    user_id: i32,
+) -> Result<(), anyhow::Error> {
+    let cooldown = match group
+        .application_cooldown_days
+        .and_then(|days| Duration::try_days(days.into()))
+    {
+        Some(cooldown) => cooldown,
+        None => return Ok(()),
+    };
+
+    let last_rejection = entity::prelude::AuthGroupApplication::find()
+        .filter(entity::auth_group_application::Column::GroupId.eq(group.id))
+        .filter(entity::auth_group_application::Column::UserId.eq(user_id))
+        .filter(entity::auth_group_application::Column::RequestType.eq(GroupApplicationType::Join))
+        .filter(entity::auth_group_application::Column::Status.eq(GroupApplicationStatus::Rejected))
+        .order_by_desc(entity::auth_group_application::Column::LastUpdated)
+        .one(db)
+        .await?;
+
+    if let Some(rejection) = last_rejection {
+        let reapply_after = rejection.last_updated + cooldown;
+
+        if reapply_after > Utc::now().naive_utc() {
+            return Err(anyhow!(
+                "Application cooldown active until {}",
+                DateTime::<Utc>::from_naive_utc_and_offset(reapply_after, Utc).to_rfc3339()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// Marks outstanding applications older than their group's expiry as Expired
+pub async fn expire_applications(db: &DatabaseConnection) -> Result<u64, DbErr> {
+    let groups = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::ApplicationExpiryDays.is_not_null())
+        .filter(entity::auth_group::Column::Archived.is_null())
+        .all(db)
+        .await?;
+
+    let now = Utc::now().naive_utc();
+    let mut expired = 0;
+
+    for group in groups {
+        let expiry = match group
+            .application_expiry_days
+            .and_then(|days| Duration::try_days(days.into()))
+        {
+            Some(expiry) => expiry,
+            None => continue,
+        };
+
+        // Ids are fetched first so the expiry can be recorded in each application's history
+        let application_ids: Vec<i32> = entity::prelude::AuthGroupApplication::find()
+            .filter(entity::auth_group_application::Column::GroupId.eq(group.id))
+            .filter(
+                entity::auth_group_application::Column::Status
+                    .eq(GroupApplicationStatus::Outstanding),
+            )
+            .filter(entity::auth_group_application::Column::Created.lt(now - expiry))
+            // Recruitment applications stay outstanding until the recruitment is completed
+            .filter(
+                entity::auth_group_application::Column::Id.not_in_subquery(
+                    Query::select()
+                        .column(entity::auth_recruitment::Column::ApplicationId)
+                        .from(entity::prelude::AuthRecruitment)
+                        .and_where(
+                            entity::auth_recruitment::Column::Status
+                                .is_in(open_recruitment_statuses()),
+                        )
+                        .to_owned(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|application| application.id)
+            .collect();
+
+        if application_ids.is_empty() {
+            continue;
+        }
+
+        let result = entity::prelude::AuthGroupApplication::update_many()
+            .set(entity::auth_group_application::ActiveModel {
+                status: Set(GroupApplicationStatus::Expired),
+                last_updated: Set(now),
+                ..Default::default()
+            })
+            .filter(entity::auth_group_application::Column::Id.is_in(application_ids.clone()))
+            .filter(
+                entity::auth_group_application::Column::Status
+                    .eq(GroupApplicationStatus::Outstanding),
+            )
+            .exec(db)
+            .await?;
+
+        record_application_status_changes(
+            db,
+            &application_ids,
+            Some(GroupApplicationStatus::Outstanding),
+            GroupApplicationStatus::Expired,
+            None,
+            None,
+        )
+        .await?;
+
+        expired += result.rows_affected;
+    }
+
+    Ok(expired)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sea_orm::{
+        ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
+        QueryFilter,
+    };
+
+    use super::{
+        assign_group_application, bulk_update_group_applications, check_application_cooldown,
+        claim_group_application, create_application_comment, create_group_application,
+        expire_applications, get_application_by_id, get_application_comments,
+        get_application_queues, get_group_application, respond_to_group_application,
+        update_application_settings, update_group_application, withdraw_group_application,
+        ApplicationPage, GroupApplicationFilter,
+    };
+    use crate::{
+        auth::{
+            data::{
+                groups::{create_group, history::get_application_history, members::join_group},
+                user::{create_user, get_user},
+            },
+            model::groups::{
+                GroupApplicationSettingsDto, GroupApplicationStatus as ApplicationStatusDto,
+                GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterScope, GroupType,
+                NewGroupApplicationCommentDto, NewGroupDto, NewGroupFilterRuleDto,
+            },
+        },
+        test_utils::{create_user_with_main, initialize_test_db, new_group_dto},
+    };
+    use entity::sea_orm_active_enums::{GroupApplicationStatus, GroupApplicationType};
+
+    fn new_application_group(name: &str) -> NewGroupDto {
+        NewGroupDto {
+            group_type: GroupType::Apply,
+            ..new_group_dto(name)
+        }
+    }
+
+    #[tokio::test]
+    async fn application_comment_thread() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        let applicant_id = create_user(&db).await?;
+        let reviewer_id = create_user(&db).await?;
+
+        let application = create_group_application(
+            &db,
+            group.id,
+            applicant_id,
+            GroupApplicationType::Join,
+            None,
+        )
+        .await?;
+
+        let new_comment = |body: &str, internal: bool| NewGroupApplicationCommentDto {
+            body: body.to_string(),
+            internal,
+        };
+
+        create_application_comment(
+            &db,
+            application.id,
+            reviewer_id,
+            new_comment("Could you link your killboard?", false),
+        )
+        .await?;
+        create_application_comment(
+            &db,
+            application.id,
+            reviewer_id,
+            new_comment("Checked with previous corp, no issues", true),
+        )
+        .await?;
+
+        assert!(create_application_comment(
+            &db,
+            application.id,
+            applicant_id,
+            new_comment(" ", false)
+        )
+        .await
+        .is_err());
+
+        let thread = get_application_comments(&db, vec![application.id], true).await?;
+        assert_eq!(thread.len(), 2);
+
+        // Reviewer notes are left out of the applicant's view of the thread
+        let thread = get_application_comments(&db, vec![application.id], false).await?;
+        assert_eq!(thread.len(), 1);
+        assert_eq!(thread[0].author_id, reviewer_id);
+        assert!(!thread[0].internal);
+
+        // The applicant's view of the application never includes reviewer notes
+        let get_application = |include_internal: bool| {
+            let db = &db;
+
+            async move {
+                get_group_application(
+                    db,
+                    GroupApplicationFilter {
+                        application_id: Some(application.id),
+                        ..Default::default()
+                    },
+                    None,
+                    include_internal,
+                )
+                .await
+            }
+        };
+
+        let applicant_view = get_application(false).await?;
+        assert_eq!(applicant_view[0].comments.len(), 1);
+        assert!(applicant_view
+            .iter()
+            .all(|application| application.comments.iter().all(|comment| !comment.internal)));
+
+        let reviewer_view = get_application(true).await?;
+        assert_eq!(reviewer_view[0].comments.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn application_expiry_withdrawal_and_cooldown() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        assert!(update_application_settings(
+            &db,
+            group.id,
+            GroupApplicationSettingsDto {
+                application_expiry_days: Some(0),
+                application_cooldown_days: None,
+            },
+        )
+        .await
+        .is_err());
+
+        let group = update_application_settings(
+            &db,
+            group.id,
+            GroupApplicationSettingsDto {
+                application_expiry_days: Some(7),
+                application_cooldown_days: Some(30),
+            },
+        )
+        .await?
+        .unwrap();
+
+        let user_id = create_user(&db).await?;
+
+        let new_application = |status: GroupApplicationStatus, age: Duration| {
+            create_application(
+                &db,
+                group.id,
+                user_id,
+                GroupApplicationType::Join,
+                status,
+                age,
+            )
+        };
+
+        let stale =
+            new_application(GroupApplicationStatus::Outstanding, Duration::days(10)).await?;
+        let recent =
+            new_application(GroupApplicationStatus::Outstanding, Duration::days(1)).await?;
+
+        assert_eq!(expire_applications(&db).await?, 1);
+        assert!(withdraw_group_application(&db, stale.id).await.is_err());
+
+        let withdrawn = withdraw_group_application(&db, recent.id).await?;
+        assert_eq!(withdrawn.status, GroupApplicationStatus::Withdrawn);
+
+        // Withdrawn & expired applications don't start a cooldown
+        check_application_cooldown(&db, &group, user_id).await?;
+
+        new_application(GroupApplicationStatus::Rejected, Duration::days(2)).await?;
+
+        assert!(check_application_cooldown(&db, &group, user_id)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bulk_accept_applications() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        let admin_id = create_user(&db).await?;
+        let mut admin = get_user(&db, admin_id).await?.unwrap();
+        admin.admin = true;
+
+        let new_application = |user_id: i32, status: GroupApplicationStatus| {
+            create_application(
+                &db,
+                group.id,
+                user_id,
+                GroupApplicationType::Join,
+                status,
+                Duration::zero(),
+            )
+        };
+
+        let first_id = create_user(&db).await?;
+        let second_id = create_user(&db).await?;
+        let rejected_id = create_user(&db).await?;
+
+        let first = new_application(first_id, GroupApplicationStatus::Outstanding).await?;
+        let second = new_application(second_id, GroupApplicationStatus::Outstanding).await?;
+        let rejected = new_application(rejected_id, GroupApplicationStatus::Rejected).await?;
+
+        let results = bulk_update_group_applications(
+            &db,
+            &admin,
+            vec![first.id, second.id, rejected.id, first.id, 999],
+            GroupApplicationStatus::Accepted,
+            Some("Welcome".to_string()),
+            None,
+        )
+        .await?;
+
+        // Duplicates are dropped while failures are reported per application
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].application_id, first.id);
+        assert!(matches!(
+            results[0].status,
+            Some(ApplicationStatusDto::Accepted)
+        ));
+        assert!(matches!(
+            results[1].status,
+            Some(ApplicationStatusDto::Accepted)
+        ));
+        assert_eq!(
+            results[2].error.as_deref(),
+            Some("Not allowed to update a completed application")
+        );
+        assert_eq!(results[3].error.as_deref(), Some("Application not found"));
+
+        let members = entity::prelude::AuthGroupUser::find()
+            .filter(entity::auth_group_user::Column::GroupId.eq(group.id))
+            .all(&db)
+            .await?;
+        assert_eq!(members.len(), 2);
+        assert!(!members.iter().any(|member| member.user_id == rejected_id));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn accept_revalidates_group_requirements() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let new_group = |name: &str, filter_rules: Vec<NewGroupFilterRuleDto>| NewGroupDto {
+            filter_rules,
+            ..new_application_group(name)
+        };
+
+        let prerequisite = create_group(&db, new_group("Members", vec![])).await?;
+        let group = create_group(
+            &db,
+            new_group(
+                "Capitals",
+                vec![NewGroupFilterRuleDto {
+                    criteria: GroupFilterCriteria::Group,
+                    criteria_type: GroupFilterCriteriaType::Is,
+                    criteria_value: prerequisite.id.to_string(),
+                    scope: GroupFilterScope::AnyCharacter,
+                }],
+            ),
+        )
+        .await?;
+
+        let responder_id = create_user(&db).await?;
+        let user_id = create_user(&db).await?;
+
+        let application =
+            create_group_application(&db, group.id, user_id, GroupApplicationType::Join, None)
+                .await?;
+
+        // The user was removed from the required group after applying
+        let result = respond_to_group_application(
+            &db,
+            application.id,
+            responder_id,
+            GroupApplicationStatus::Accepted,
+            None,
+            None,
+        )
+        .await;
+
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("User does not meet group requirements")
+        );
+
+        let application = get_application_by_id(&db, application.id).await?.unwrap();
+        assert_eq!(application.status, GroupApplicationStatus::Outstanding);
+
+        entity::auth_group_user::ActiveModel {
+            group_id: Set(prerequisite.id),
+            user_id: Set(user_id),
+            created: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+
+        let membership = respond_to_group_application(
+            &db,
+            application.id,
+            responder_id,
+            GroupApplicationStatus::Accepted,
+            None,
+            Some(Duration::days(30)),
+        )
+        .await?;
+
+        assert!(membership.member);
+        assert!(membership.expires.is_some());
+        assert!(matches!(membership.status, ApplicationStatusDto::Accepted));
+
+        Ok(())
+    }
+
+    // Creates an application through the data layer so its history is recorded, completed
+    // statuses are set through an update & the timestamps are moved back by the given duration
+    async fn create_application(
+        db: &DatabaseConnection,
+        group_id: i32,
+        user_id: i32,
+        request_type: GroupApplicationType,
+        status: GroupApplicationStatus,
+        age: Duration,
+    ) -> Result<entity::auth_group_application::Model, anyhow::Error> {
+        let mut application =
+            create_group_application(db, group_id, user_id, request_type, None).await?;
+
+        if status != GroupApplicationStatus::Outstanding {
+            application =
+                update_group_application(db, application.id, None, None, Some(status), None)
+                    .await?;
+        }
+
+        let timestamp = (Utc::now() - age).naive_utc();
+
+        let mut application: entity::auth_group_application::ActiveModel = application.into();
+
+        application.created = Set(timestamp);
+        application.last_updated = Set(timestamp);
+
+        Ok(application.update(db).await?)
+    }
+
+    // Creates 3 applications to a group a day apart, returned oldest first
+    async fn create_group_applications(
+        db: &DatabaseConnection,
+        group_id: i32,
+    ) -> Result<Vec<entity::auth_group_application::Model>, anyhow::Error> {
+        let mut applications = vec![];
+
+        for days in (1..=3).rev() {
+            let user_id = create_user(db).await?;
+
+            applications.push(
+                create_application(
+                    db,
+                    group_id,
+                    user_id,
+                    GroupApplicationType::Join,
+                    GroupApplicationStatus::Outstanding,
+                    Duration::days(days),
+                )
+                .await?,
+            );
+        }
+
+        Ok(applications)
+    }
+
+    fn group_page(
+        group_id: i32,
+        page: u64,
+        page_size: u64,
+    ) -> (GroupApplicationFilter, ApplicationPage) {
+        (
+            GroupApplicationFilter {
+                group_id: Some(group_id),
+                ..Default::default()
+            },
+            ApplicationPage { page, page_size },
+        )
+    }
+
+    #[tokio::test]
+    async fn list_several_applications_per_user() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let new_group = |name: &str| NewGroupDto {
+            leave_applications: true,
+            ..new_application_group(name)
+        };
+
+        let recruitment = create_group(&db, new_group("Recruitment")).await?;
+        let capitals = create_group(&db, new_group("Capitals")).await?;
+
+        let user_id = create_user_with_main(&db, 180548812).await?;
+
+        // A user can have join & leave applications as well as applications to several groups
+        let join = create_application(
+            &db,
+            recruitment.id,
+            user_id,
+            GroupApplicationType::Join,
+            GroupApplicationStatus::Outstanding,
+            Duration::days(3),
+        )
+        .await?;
+        let leave = create_application(
+            &db,
+            recruitment.id,
+            user_id,
+            GroupApplicationType::Leave,
+            GroupApplicationStatus::Outstanding,
+            Duration::days(2),
+        )
+        .await?;
+        let other_group = create_application(
+            &db,
+            capitals.id,
+            user_id,
+            GroupApplicationType::Join,
+            GroupApplicationStatus::Outstanding,
+            Duration::days(1),
+        )
+        .await?;
+
+        let applications = get_group_application(
+            &db,
+            GroupApplicationFilter {
+                user_id: Some(user_id),
+                ..Default::default()
+            },
+            None,
+            true,
+        )
+        .await?;
+
+        let application_ids: Vec<i32> = applications.iter().map(|app| app.id).collect();
+        assert_eq!(application_ids, vec![other_group.id, leave.id, join.id]);
+        assert!(applications.iter().all(|app| app
+            .applicant_info
+            .as_ref()
+            .map(|info| info.character_id)
+            == Some(180548812)));
+        assert!(applications.iter().all(|app| app.history.len() == 1));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_applications_of_applicant_without_main() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        let user_id = create_user(&db).await?;
+
+        let application = create_application(
+            &db,
+            group.id,
+            user_id,
+            GroupApplicationType::Join,
+            GroupApplicationStatus::Outstanding,
+            Duration::zero(),
+        )
+        .await?;
+
+        // Applicants without a main are still listed, just without character info
+        let applications = get_group_application(
+            &db,
+            GroupApplicationFilter {
+                application_id: Some(application.id),
+                ..Default::default()
+            },
+            None,
+            true,
+        )
+        .await?;
+
+        assert_eq!(applications.len(), 1);
+        assert!(applications[0].applicant_info.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_applications_by_page() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        let applications = create_group_applications(&db, group.id).await?;
+
+        let (filter, page) = group_page(group.id, 0, 2);
+        let first_page = get_group_application(&db, filter, Some(page), true).await?;
+
+        let (filter, page) = group_page(group.id, 1, 2);
+        let second_page = get_group_application(&db, filter, Some(page), true).await?;
+
+        // Pages are ordered newest first
+        let first_page_ids: Vec<i32> = first_page.iter().map(|app| app.id).collect();
+        let second_page_ids: Vec<i32> = second_page.iter().map(|app| app.id).collect();
+        assert_eq!(first_page_ids, vec![applications[2].id, applications[1].id]);
+        assert_eq!(second_page_ids, vec![applications[0].id]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_applications_page_past_end() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        create_group_applications(&db, group.id).await?;
+
+        let (filter, page) = group_page(group.id, 2, 2);
+        let applications = get_group_application(&db, filter, Some(page), true).await?;
+
+        assert!(applications.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_applications_invalid_page_size() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        create_group_applications(&db, group.id).await?;
+
+        let (filter, page) = group_page(group.id, 0, 0);
+        let result = get_group_application(&db, filter, Some(page), true).await;
+
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some("Invalid page size".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn application_status_history() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        let applicant_id = create_user(&db).await?;
+        let recruiter_id = create_user(&db).await?;
+
+        let application = join_group(
+            &db,
+            group.id,
+            applicant_id,
+            Some("Looking to fly with you".to_string()),
+            vec![],
+        )
+        .await?
+        .unwrap();
+
+        respond_to_group_application(
+            &db,
+            application.id,
+            recruiter_id,
+            GroupApplicationStatus::Rejected,
+            Some("Not recruiting right now".to_string()),
+            None,
+        )
+        .await?;
+
+        let history = get_application_history(&db, vec![application.id]).await?;
+
+        assert_eq!(history.len(), 2);
+        assert!(history[0].old_status.is_none());
+        assert!(matches!(
+            history[0].new_status,
+            ApplicationStatusDto::Outstanding
+        ));
+        assert_eq!(history[0].actor_id, Some(applicant_id));
+        assert_eq!(
+            history[0].message.as_deref(),
+            Some("Looking to fly with you")
+        );
+        assert!(matches!(
+            history[1].old_status,
+            Some(ApplicationStatusDto::Outstanding)
+        ));
+        assert!(matches!(
+            history[1].new_status,
+            ApplicationStatusDto::Rejected
+        ));
+        assert_eq!(history[1].actor_id, Some(recruiter_id));
+        assert_eq!(
+            history[1].message.as_deref(),
+            Some("Not recruiting right now")
+        );
+
+        // Expiry is made by the auth itself so it is recorded without an actor
+        update_application_settings(
+            &db,
+            group.id,
+            GroupApplicationSettingsDto {
+                application_expiry_days: Some(7),
+                application_cooldown_days: None,
+            },
+        )
+        .await?;
+
+        let stale = create_application(
+            &db,
+            group.id,
+            applicant_id,
+            GroupApplicationType::Join,
+            GroupApplicationStatus::Outstanding,
+            Duration::days(10),
+        )
+        .await?;
+
+        assert_eq!(expire_applications(&db).await?, 1);
+
+        let history = get_application_history(&db, vec![stale.id]).await?;
+
+        assert_eq!(history.len(), 2);
+        assert!(matches!(
+            history[1].new_status,
+            ApplicationStatusDto::Expired
+        ));
+        assert!(history[1].actor_id.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn withdrawn_application_keeps_history() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        let applicant_id = create_user(&db).await?;
+
+        let application = join_group(&db, group.id, applicant_id, None, vec![])
+            .await?
+            .unwrap();
+
+        withdraw_group_application(&db, application.id).await?;
+
+        // The application & its history are kept for leadership to audit
+        let application = get_application_by_id(&db, application.id).await?.unwrap();
+
+        assert_eq!(application.status, GroupApplicationStatus::Withdrawn);
+
+        let history = get_application_history(&db, vec![application.id]).await?;
+
+        assert_eq!(history.len(), 2);
+        assert!(matches!(
+            history[1].old_status,
+            Some(ApplicationStatusDto::Outstanding)
+        ));
+        assert!(matches!(
+            history[1].new_status,
+            ApplicationStatusDto::Withdrawn
+        ));
+        assert_eq!(history[1].actor_id, Some(applicant_id));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn application_assignment_and_queues() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_application_group("Recruitment")).await?;
+
+        let recruiter_id = entity::auth_user::ActiveModel {
+            admin: Set(true),
+            created: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?
+        .id;
+        let other_recruiter_id = create_user(&db).await?;
+        let applicant_id = create_user(&db).await?;
+
+        let new_application = |status: GroupApplicationStatus, age: Duration| {
+            create_application(
+                &db,
+                group.id,
+                applicant_id,
+                GroupApplicationType::Join,
+                status,
+                age,
+            )
+        };
+
+        let recent =
+            new_application(GroupApplicationStatus::Outstanding, Duration::hours(1)).await?;
+        let stale =
+            new_application(GroupApplicationStatus::Outstanding, Duration::days(10)).await?;
+        let rejected = new_application(GroupApplicationStatus::Rejected, Duration::days(3)).await?;
+
+        claim_group_application(&db, recent.id, recruiter_id).await?;
+
+        // Claims don't take over applications another reviewer is assigned to
+        assert!(claim_group_application(&db, recent.id, other_recruiter_id)
+            .await
+            .is_err());
+        assert!(claim_group_application(&db, rejected.id, recruiter_id)
+            .await
+            .is_err());
+
+        // Only users able to manage the group can be assigned its applications
+        assert!(
+            assign_group_application(&db, stale.id, Some(other_recruiter_id))
+                .await
+                .is_err()
+        );
+
+        let get_application_ids = |filter: GroupApplicationFilter| {
+            let db = &db;
+
+            async move {
+                get_group_application(db, filter, None, true)
+                    .await
+                    .map(|applications| applications.iter().map(|app| app.id).collect::<Vec<_>>())
+            }
+        };
+
+        let assigned = get_application_ids(GroupApplicationFilter {
+            assignee_id: Some(recruiter_id),
+            ..Default::default()
+        })
+        .await?;
+        assert_eq!(assigned, vec![recent.id]);
+
+        let unassigned = get_application_ids(GroupApplicationFilter {
+            application_status: Some(GroupApplicationStatus::Outstanding),
+            unassigned: true,
+            ..Default::default()
+        })
+        .await?;
+        assert_eq!(unassigned, vec![stale.id]);
+
+        let old = get_application_ids(GroupApplicationFilter {
+            older_than_days: Some(7),
+            ..Default::default()
+        })
+        .await?;
+        assert_eq!(old, vec![stale.id]);
+
+        let queues = get_application_queues(
+            &db,
+            GroupApplicationFilter {
+                group_id: Some(group.id),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        assert_eq!(queues.len(), 1);
+        assert_eq!(queues[0].outstanding, 2);
+        assert_eq!(queues[0].rejected, 1);
+        assert_eq!(queues[0].unassigned, 1);
+        assert_eq!(queues[0].outstanding_under_1_day, 1);
+        assert_eq!(queues[0].outstanding_over_7_days, 1);
+
+        assign_group_application(&db, recent.id, None).await?;
+        claim_group_application(&db, recent.id, other_recruiter_id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::auth::model::groups::NewGroupCategoryDto;
+
+use entity::auth_group_category::Model as GroupCategory;
+
+pub async fn create_category(
+    db: &DatabaseConnection,
+    category: NewGroupCategoryDto,
+) -> Result<GroupCategory, DbErr> {
+    let category = entity::auth_group_category::ActiveModel {
+        name: Set(category.name),
+        description: Set(category.description),
+        ordering: Set(category.ordering),
+        ..Default::default()
+    };
+
+    category.insert(db).await
+}
+
+pub async fn get_categories(db: &DatabaseConnection) -> Result<Vec<GroupCategory>, DbErr> {
+    entity::prelude::AuthGroupCategory::find()
+        .order_by_asc(entity::auth_group_category::Column::Ordering)
+        .order_by_asc(entity::auth_group_category::Column::Name)
+        .all(db)
+        .await
+}
+
+pub async fn get_category_by_id(
+    db: &DatabaseConnection,
+    id: i32,
+) -> Result<Option<GroupCategory>, DbErr> {
+    entity::prelude::AuthGroupCategory::find()
+        .filter(entity::auth_group_category::Column::Id.eq(id))
+        .one(db)
+        .await
+}
+
+pub async fn update_category(
+    db: &DatabaseConnection,
+    category_id: i32,
+    category: NewGroupCategoryDto,
+) -> Result<Option<GroupCategory>, DbErr> {
+    if get_category_by_id(db, category_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    let category = entity::auth_group_category::ActiveModel {
+        id: Set(category_id),
+        name: Set(category.name),
+        description: Set(category.description),
+        ordering: Set(category.ordering),
+    };
+
+    let category = category.update(db).await?;
+
+    Ok(Some(category))
+}
+
+pub async fn delete_category(
+    db: &DatabaseConnection,
+    category_id: i32,
+) -> Result<Option<i32>, DbErr> {
+    // Groups in the category become uncategorized
+    entity::prelude::AuthGroup::update_many()
+        .col_expr(
+            entity::auth_group::Column::CategoryId,
+            sea_orm::sea_query::Expr::value(Option::<i32>::None),
+        )
+        .filter(entity::auth_group::Column::CategoryId.eq(category_id))
+        .exec(db)
+        .await?;
+
+    let result = entity::prelude::AuthGroupCategory::delete_by_id(category_id)
+        .exec(db)
+        .await?;
+
+    if result.rows_affected == 1 {
+        Ok(Some(category_id))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{create_category, delete_category, get_categories};
+    use crate::{
+        auth::{
+            data::groups::{create_group, get_group_dto},
+            model::groups::{NewGroupCategoryDto, NewGroupDto},
+        },
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+
+    fn new_category(name: &str, ordering: i32) -> NewGroupCategoryDto {
+        NewGroupCategoryDto {
+            name: name.to_string(),
+            description: None,
+            ordering,
+        }
+    }
+
+    #[tokio::test]
+    async fn categorize_groups() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let fleets = create_category(&db, new_category("Fleets", 1)).await?;
+        let industry = create_category(&db, new_category("Industry", 0)).await?;
+
+        // Ordered by ordering first, then by name
+        let categories = get_categories(&db).await?;
+
+        assert_eq!(categories, vec![industry.clone(), fleets.clone()]);
+
+        let fleet_group = create_group(
+            &db,
+            NewGroupDto {
+                category_id: Some(fleets.id),
+                ..new_group_dto("Capitals")
+            },
+        )
+        .await?;
+        let _ = create_group(&db, new_group_dto("Uncategorized")).await?;
+
+        let groups = get_group_dto(&db, None, Some(fleets.id)).await?;
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].id, fleet_group.id);
+        assert_eq!(groups[0].category.as_ref().map(|c| c.id), Some(fleets.id));
+
+        let result = create_group(
+            &db,
+            NewGroupDto {
+                category_id: Some(fleets.id + industry.id),
+                ..new_group_dto("Missing category")
+            },
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Category not found: {}", fleets.id + industry.id)
+        );
+
+        // Groups of a deleted category become uncategorized
+        assert_eq!(delete_category(&db, fleets.id).await?, Some(fleets.id));
+
+        let groups = get_group_dto(&db, Some(vec![fleet_group.id]), None).await?;
+
+        assert!(groups[0].category.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,429 @@
+use anyhow::anyhow;
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+
+use crate::auth::model::groups::GroupMembershipInheritance;
+
+// Maps each group id to the ids of the groups required by its group filter rules
+pub type GroupDependencies = HashMap<i32, HashSet<i32>>;
+
+// Maps each group to the groups its members inherit membership of
+pub async fn get_group_inheritance(
+    db: &DatabaseConnection,
+) -> Result<HashMap<i32, Vec<i32>>, DbErr> {
+    let subgroups = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::ParentId.is_not_null())
+        .all(db)
+        .await?;
+
+    let mut inheritance: HashMap<i32, Vec<i32>> = HashMap::new();
+
+    for subgroup in subgroups {
+        if let Some(parent_id) = subgroup.parent_id {
+            match subgroup.membership_inheritance.into() {
+                GroupMembershipInheritance::ChildToParent => {
+                    inheritance.entry(subgroup.id).or_default().push(parent_id)
+                }
+                GroupMembershipInheritance::ParentToChild => {
+                    inheritance.entry(parent_id).or_default().push(subgroup.id)
+                }
+            }
+        }
+    }
+
+    Ok(inheritance)
+}
+
+// The group dependencies & membership inheritance, loaded once & shared when reconciling a
+// batch of membership changes
+pub struct GroupGraph {
+    pub dependencies: GroupDependencies,
+    // Only the dependencies from group filter rules, without those from inheritance
+    pub rule_dependencies: GroupDependencies,
+    pub inheritance: HashMap<i32, Vec<i32>>,
+}
+
+// Groups depend on the groups required by their group filter rules, both "is" & "is not" rules
+// count as a membership change in the required group can change eligibility either way
+// Groups inheriting membership from another group also depend on that group
+pub async fn get_group_graph(db: &DatabaseConnection) -> Result<GroupGraph, DbErr> {
+    let inheritance = get_group_inheritance(db).await?;
+    let rule_dependencies = get_rule_dependencies(db).await?;
+
+    let mut dependencies = rule_dependencies.clone();
+    add_inheritance_dependencies(&mut dependencies, &inheritance);
+
+    Ok(GroupGraph {
+        dependencies,
+        rule_dependencies,
+        inheritance,
+    })
+}
+
+async fn get_rule_dependencies(db: &DatabaseConnection) -> Result<GroupDependencies, DbErr> {
+    let rules = entity::prelude::AuthGroupFilterRule::find()
+        .filter(
+            entity::auth_group_filter_rule::Column::Criteria
+                .eq(entity::sea_orm_active_enums::GroupFilterCriteria::Group),
+        )
+        .all(db)
+        .await?;
+
+    let mut dependencies: GroupDependencies = HashMap::new();
+
+    for rule in rules {
+        if let Ok(required_group_id) = rule.criteria_value.parse::<i32>() {
+            dependencies
+                .entry(rule.group_id)
+                .or_default()
+                .insert(required_group_id);
+        }
+    }
+
+    Ok(dependencies)
+}
+
+fn add_inheritance_dependencies(
+    dependencies: &mut GroupDependencies,
+    inheritance: &HashMap<i32, Vec<i32>>,
+) {
+    for (group_id, inheriting_group_ids) in inheritance {
+        for inheriting_group_id in inheriting_group_ids {
+            dependencies
+                .entry(*inheriting_group_id)
+                .or_default()
+                .insert(*group_id);
+        }
+    }
+}
+
+// Returns the group & every group it requires directly or through other groups
+pub fn get_required_groups(dependencies: &GroupDependencies, group_id: i32) -> HashSet<i32> {
+    let mut required_groups: HashSet<i32> = HashSet::new();
+    let mut queue = vec![group_id];
+
+    while let Some(id) = queue.pop() {
+        if required_groups.insert(id) {
+            if let Some(required_group_ids) = dependencies.get(&id) {
+                queue.extend(required_group_ids);
+            }
+        }
+    }
+
+    required_groups
+}
+
+// Orders groups so each group comes after every group it requires, this is the order
+// group members should be reconciled in
+// Returns the path of a cycle, e.g. [3, 5, 3] for group 3 requires 5 which requires 3, if there is one
+pub fn get_evaluation_order(dependencies: &GroupDependencies) -> Result<Vec<i32>, Vec<i32>> {
+    let mut groups: HashSet<i32> = dependencies.keys().copied().collect();
+    groups.extend(dependencies.values().flatten());
+
+    let mut remaining_requirements: HashMap<i32, usize> = groups
+        .iter()
+        .map(|id| {
+            (
+                *id,
+                dependencies.get(id).map_or(0, |required| required.len()),
+            )
+        })
+        .collect();
+
+    let mut dependents: HashMap<i32, Vec<i32>> = HashMap::new();
+
+    for (group_id, required_group_ids) in dependencies {
+        for required_group_id in required_group_ids {
+            dependents
+                .entry(*required_group_id)
+                .or_default()
+                .push(*group_id);
+        }
+    }
+
+    let mut queue: Vec<i32> = remaining_requirements
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(id, _)| *id)
+        .collect();
+
+    // Sorted in reverse as the queue is popped from the back, keeps the order stable
+    queue.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut order: Vec<i32> = vec![];
+
+    while let Some(group_id) = queue.pop() {
+        order.push(group_id);
+
+        let mut ready: Vec<i32> = vec![];
+
+        for dependent in dependents.get(&group_id).into_iter().flatten() {
+            if let Some(count) = remaining_requirements.get_mut(dependent) {
+                *count -= 1;
+
+                if *count == 0 {
+                    ready.push(*dependent);
+                }
+            }
+        }
+
+        ready.sort_unstable_by(|a, b| b.cmp(a));
+        queue.extend(ready);
+    }
+
+    if order.len() == groups.len() {
+        return Ok(order);
+    }
+
+    let mut unresolved: Vec<i32> = groups
+        .into_iter()
+        .filter(|id| !order.contains(id))
+        .collect();
+
+    unresolved.sort_unstable();
+
+    Err(find_cycle(dependencies, &unresolved).unwrap_or(unresolved))
+}
+
+fn find_cycle(dependencies: &GroupDependencies, groups: &[i32]) -> Option<Vec<i32>> {
+    fn visit(
+        dependencies: &GroupDependencies,
+        group_id: i32,
+        path: &mut Vec<i32>,
+        visited: &mut HashSet<i32>,
+    ) -> Option<Vec<i32>> {
+        if let Some(position) = path.iter().position(|id| *id == group_id) {
+            let mut cycle = path[position..].to_vec();
+            cycle.push(group_id);
+
+            return Some(cycle);
+        }
+
+        if !visited.insert(group_id) {
+            return None;
+        }
+
+        path.push(group_id);
+
+        let mut required_group_ids: Vec<i32> = dependencies
+            .get(&group_id)
+            .map(|required| required.iter().copied().collect())
+            .unwrap_or_default();
+
+        required_group_ids.sort_unstable();
+
+        for required_group_id in required_group_ids {
+            if let Some(cycle) = visit(dependencies, required_group_id, path, visited) {
+                return Some(cycle);
+            }
+        }
+
+        path.pop();
+
+        None
+    }
+
+    let mut visited: HashSet<i32> = HashSet::new();
+
+    for group_id in groups {
+        if let Some(cycle) = visit(dependencies, *group_id, &mut vec![], &mut visited) {
+            return Some(cycle);
+        }
+    }
+
+    None
+}
+
+// Checks that the group's new group filter rules & parent group, along with every group they
+// require, don't form a cycle. Reconciling the members of groups in a cycle would never settle.
+pub async fn validate_group_dependencies(
+    db: &DatabaseConnection,
+    // None when validating a group which hasn't been created yet
+    group_id: Option<i32>,
+    required_group_ids: Vec<i32>,
+    parent: Option<(i32, &GroupMembershipInheritance)>,
+) -> Result<(), anyhow::Error> {
+    // The database never assigns 0 as an id so it stands in for a group which hasn't been created
+    let id = group_id.unwrap_or(0);
+
+    // The group's stored group filter rules & parent are replaced by the new ones, the stored
+    // parent can add an edge in either direction so it is removed from the inheritance first
+    let mut dependencies = get_rule_dependencies(db).await?;
+    dependencies.remove(&id);
+
+    let mut inheritance = get_group_inheritance(db).await?;
+
+    if let Some(group_id) = group_id {
+        let stored_parent_id = entity::prelude::AuthGroup::find_by_id(group_id)
+            .one(db)
+            .await?
+            .and_then(|group| group.parent_id);
+
+        if let Some(stored_parent_id) = stored_parent_id {
+            if let Some(inheriting_group_ids) = inheritance.get_mut(&group_id) {
+                inheriting_group_ids.retain(|inheriting_id| *inheriting_id != stored_parent_id);
+            }
+
+            if let Some(inheriting_group_ids) = inheritance.get_mut(&stored_parent_id) {
+                inheriting_group_ids.retain(|inheriting_id| *inheriting_id != group_id);
+            }
+        }
+    }
+
+    add_inheritance_dependencies(&mut dependencies, &inheritance);
+
+    dependencies
+        .entry(id)
+        .or_default()
+        .extend(required_group_ids);
+
+    if let Some((parent_id, inheritance)) = parent {
+        match inheritance {
+            GroupMembershipInheritance::ParentToChild => {
+                dependencies.entry(id).or_default().insert(parent_id);
+            }
+            GroupMembershipInheritance::ChildToParent => {
+                dependencies.entry(parent_id).or_default().insert(id);
+            }
+        }
+    }
+
+    let required_groups = get_required_groups(&dependencies, id);
+
+    dependencies.retain(|group_id, _| required_groups.contains(group_id));
+
+    if let Err(mut cycle) = get_evaluation_order(&dependencies) {
+        // Start the path from the group being edited if it is part of the cycle
+        if let Some(position) = cycle.iter().position(|group_id| *group_id == id) {
+            cycle.pop();
+            cycle.rotate_left(position);
+            cycle.push(id);
+        }
+
+        let path = cycle
+            .iter()
+            .map(|cycle_group_id| {
+                if group_id.is_none() && *cycle_group_id == id {
+                    "new group".to_string()
+                } else {
+                    cycle_group_id.to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" -> ");
+
+        return Err(anyhow!(
+            "Invalid group filter, group dependencies form a cycle: {}",
+            path
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        auth::{data::groups::create_group, model::groups::NewGroupDto},
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+
+    fn dependencies(edges: &[(i32, i32)]) -> GroupDependencies {
+        let mut dependencies: GroupDependencies = HashMap::new();
+
+        for (group_id, required_group_id) in edges {
+            dependencies
+                .entry(*group_id)
+                .or_default()
+                .insert(*required_group_id);
+        }
+
+        dependencies
+    }
+
+    #[test]
+    fn evaluation_order_requirements_first() {
+        let dependencies = dependencies(&[(3, 2), (2, 1), (4, 1), (4, 3)]);
+
+        let order = get_evaluation_order(&dependencies).unwrap();
+
+        let position = |id: i32| order.iter().position(|group| *group == id).unwrap();
+
+        assert_eq!(order.len(), 4);
+        assert!(position(1) < position(2));
+        assert!(position(2) < position(3));
+        assert!(position(3) < position(4));
+    }
+
+    #[test]
+    fn evaluation_order_cycle_path() {
+        let dependencies = dependencies(&[(1, 2), (3, 5), (5, 3), (5, 1)]);
+
+        let cycle = get_evaluation_order(&dependencies).unwrap_err();
+
+        assert_eq!(cycle, vec![3, 5, 3]);
+    }
+
+    #[test]
+    fn evaluation_order_self_dependency() {
+        let dependencies = dependencies(&[(7, 7)]);
+
+        let cycle = get_evaluation_order(&dependencies).unwrap_err();
+
+        assert_eq!(cycle, vec![7, 7]);
+    }
+
+    #[test]
+    fn required_groups_transitive() {
+        let dependencies = dependencies(&[(1, 2), (2, 3), (4, 5)]);
+
+        let required_groups = get_required_groups(&dependencies, 1);
+
+        assert_eq!(required_groups, HashSet::from([1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn switch_subgroup_inheritance_direction() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let parent = create_group(&db, new_group_dto("Parent")).await?;
+        let child = create_group(
+            &db,
+            NewGroupDto {
+                parent_id: Some(parent.id),
+                membership_inheritance: GroupMembershipInheritance::ChildToParent,
+                ..new_group_dto("Child")
+            },
+        )
+        .await?;
+
+        // The stored child to parent edge is replaced rather than reported as a cycle
+        validate_group_dependencies(
+            &db,
+            Some(child.id),
+            vec![],
+            Some((parent.id, &GroupMembershipInheritance::ParentToChild)),
+        )
+        .await?;
+
+        let result = validate_group_dependencies(
+            &db,
+            Some(child.id),
+            vec![parent.id],
+            Some((parent.id, &GroupMembershipInheritance::ChildToParent)),
+        )
+        .await;
+
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some(format!(
+                "Invalid group filter, group dependencies form a cycle: {} -> {} -> {}",
+                child.id, parent.id, child.id
+            ))
+        );
+
+        Ok(())
+    }
+}
@@ -3,25 +3,33 @@ use core::panic;
 use std::collections::HashSet;
 
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, DeleteResult,
-    EntityTrait, QueryFilter,
+    sea_query::Expr, ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr,
+    DeleteResult, EntityTrait, QueryFilter,
 };
 
 use crate::{
     auth::{
-        data::user::{bulk_get_user_affiliations, bulk_get_user_groups},
+        data::{
+            groups::dependencies::validate_group_dependencies,
+            organizations::{get_organization_by_id, get_organization_members},
+            user::{bulk_get_user_affiliations, bulk_get_user_groups},
+        },
         model::{
             groups::{
                 GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterGroupDto,
-                GroupFilterRuleDto, GroupFilterType, GroupFiltersDto, NewGroupDto,
-                NewGroupFilterGroupDto, NewGroupFilterRuleDto, UpdateGroupFilterGroupDto,
-                UpdateGroupFilterRuleDto,
+                GroupFilterRuleDto, GroupFilterScope, GroupFilterType, GroupFiltersDto,
+                NewGroupDto, NewGroupFilterGroupDto, NewGroupFilterRuleDto,
+                UpdateGroupFilterGroupDto, UpdateGroupFilterRuleDto,
             },
-            user::{UserAffiliations, UserGroups},
+            user::{UserAffiliations, UserCharacterAffiliation, UserGroups},
         },
     },
     eve::{
-        data::{alliance::AllianceRepository, corporation::CorporationRepository},
+        data::{
+            alliance::AllianceRepository, character_role::CharacterRoleRepository,
+            character_title::CharacterTitleRepository, corporation::CorporationRepository,
+        },
+        esi::CORPORATION_ROLES,
         service::{alliance::get_or_create_alliance, corporation::get_or_create_corporation},
     },
 };
@@ -43,6 +51,12 @@ pub async fn validate_filter_rules(
                     ));
                 };
 
+                if rule.scope != GroupFilterScope::AnyCharacter {
+                    return Err(anyhow!(
+                        "Invalid scope for group filter, must be 'any character'"
+                    ));
+                }
+
                 let group_id: i32 = match rule.criteria_value.parse::<i32>() {
                     Ok(id) => id,
                     Err(_) => return Err(anyhow!("Invalid group id: {}", rule.criteria_value)),
@@ -100,228 +114,525 @@ pub async fn validate_filter_rules(
                     return Err(anyhow!("Role must be set to either CEO or Executor"));
                 }
             }
+            GroupFilterCriteria::CorporationRole => {
+                if rule.criteria_type != GroupFilterCriteriaType::Is
+                    && rule.criteria_type != GroupFilterCriteriaType::IsNot
+                {
+                    return Err(anyhow!(
+                        "Invalid criteria type for group filter, must be either 'is' or 'is not'"
+                    ));
+                };
+
+                if !CORPORATION_ROLES.contains(&rule.criteria_value.as_str()) {
+                    return Err(anyhow!("Invalid corporation role: {}", rule.criteria_value));
+                }
+            }
+            GroupFilterCriteria::CorporationTitle => {
+                if rule.criteria_type != GroupFilterCriteriaType::Is
+                    && rule.criteria_type != GroupFilterCriteriaType::IsNot
+                {
+                    return Err(anyhow!(
+                        "Invalid criteria type for group filter, must be either 'is' or 'is not'"
+                    ));
+                };
+
+                if rule.criteria_value.trim().is_empty() {
+                    return Err(anyhow!("Corporation title must not be empty"));
+                }
+            }
+            GroupFilterCriteria::Organization => {
+                if rule.criteria_type != GroupFilterCriteriaType::Is
+                    && rule.criteria_type != GroupFilterCriteriaType::IsNot
+                {
+                    return Err(anyhow!(
+                        "Invalid criteria type for group filter, must be either 'is' or 'is not'"
+                    ));
+                };
+
+                let organization_id: i32 = match rule.criteria_value.parse::<i32>() {
+                    Ok(id) => id,
+                    Err(_) => {
+                        return Err(anyhow!("Invalid organization id: {}", rule.criteria_value))
+                    }
+                };
+
+                if get_organization_by_id(db, organization_id).await?.is_none() {
+                    return Err(anyhow!("Organization not found: {}", organization_id));
+                }
+            }
         }
     }
 
     Ok(())
 }
 
+// Maximum depth of nested filter groups, filter groups directly on the group are at depth 1
+pub const MAX_FILTER_GROUP_DEPTH: usize = 5;
+
 pub async fn validate_group_filters(
     db: &DatabaseConnection,
+    // None when validating the filters of a group which hasn't been created yet
+    group_id: Option<i32>,
     group: &NewGroupDto,
 ) -> Result<(), anyhow::Error> {
     validate_filter_rules(db, &group.filter_rules).await?;
 
-    for filter_group in &group.filter_groups {
+    let mut required_group_ids = get_required_group_ids(&group.filter_rules);
+
+    let mut filter_groups: Vec<(usize, &NewGroupFilterGroupDto)> =
+        group.filter_groups.iter().map(|group| (1, group)).collect();
+
+    while let Some((depth, filter_group)) = filter_groups.pop() {
+        if depth > MAX_FILTER_GROUP_DEPTH {
+            return Err(anyhow!(
+                "Filter groups can't be nested more than {} levels deep",
+                MAX_FILTER_GROUP_DEPTH
+            ));
+        }
+
         validate_filter_rules(db, &filter_group.rules).await?;
+
+        required_group_ids.extend(get_required_group_ids(&filter_group.rules));
+
+        filter_groups.extend(
+            filter_group
+                .filter_groups
+                .iter()
+                .map(|group| (depth + 1, group)),
+        );
     }
 
+    let parent = group
+        .parent_id
+        .map(|parent_id| (parent_id, &group.membership_inheritance));
+
+    validate_group_dependencies(db, group_id, required_group_ids, parent).await?;
+
     Ok(())
 }
 
-// Checks a vec of user_ids against a group's filters and returns a vec of user_ids that are eligible to be in the group
-pub async fn validate_group_members(
+fn get_required_group_ids(rules: &[NewGroupFilterRuleDto]) -> Vec<i32> {
+    rules
+        .iter()
+        .filter(|rule| rule.criteria == GroupFilterCriteria::Group)
+        .filter_map(|rule| rule.criteria_value.parse::<i32>().ok())
+        .collect()
+}
+
+// Checks if a user's characters match a filter rule based on the rule's scope
+// Users without a main character never match rules scoped to the main character
+fn match_characters(
+    affiliation: &UserAffiliations,
+    scope: &GroupFilterScope,
+    predicate: impl Fn(&UserCharacterAffiliation) -> bool,
+) -> bool {
+    match scope {
+        GroupFilterScope::AnyCharacter => affiliation.characters.iter().any(predicate),
+        GroupFilterScope::MainCharacter => affiliation
+            .characters
+            .iter()
+            .filter(|character| character.main)
+            .any(predicate),
+        GroupFilterScope::AllCharacters => {
+            !affiliation.characters.is_empty() && affiliation.characters.iter().all(predicate)
+        }
+    }
+}
+
+// Data required to evaluate filter rules, only the data for criteria used by the group's filters is fetched
+#[derive(Default)]
+struct FilterData {
+    user_affiliation: Vec<UserAffiliations>,
+    user_groups: Vec<UserGroups>,
+    corporations: Vec<entity::eve_corporation::Model>,
+    executor_ids: Vec<i32>,
+    character_roles: Vec<entity::eve_character_role::Model>,
+    character_titles: Vec<entity::eve_character_title::Model>,
+    organization_members: Vec<entity::auth_organization_member::Model>,
+}
+
+async fn get_filter_data(
     db: &DatabaseConnection,
-    group_id: i32,
-    user_ids: Vec<i32>,
-) -> Result<Vec<i32>, DbErr> {
-    let filters = get_group_filters(db, group_id).await?;
+    user_ids: &[i32],
+    rules: &[&GroupFilterRuleDto],
+) -> Result<FilterData, DbErr> {
+    let uses_criteria =
+        |criteria: GroupFilterCriteria| rules.iter().any(|rule| rule.criteria == criteria);
 
-    let mut result: Vec<HashSet<i32>> = vec![];
-    struct RuleSet {
-        filter_type: GroupFilterType,
-        rules: Vec<GroupFilterRuleDto>,
+    let mut data = FilterData::default();
+
+    if uses_criteria(GroupFilterCriteria::Group) {
+        data.user_groups = bulk_get_user_groups(db, user_ids.to_vec()).await?;
     }
 
-    let mut filter_groups = vec![];
+    if rules
+        .iter()
+        .any(|rule| rule.criteria != GroupFilterCriteria::Group)
+    {
+        data.user_affiliation = bulk_get_user_affiliations(db, user_ids.to_vec()).await?;
+    }
 
-    if let Some(ref filters) = filters {
-        if filters.filter_rules.is_empty() && filters.filter_groups.is_empty() {
-            result.push(user_ids.clone().into_iter().collect());
-        }
+    let character_ids: Vec<i32> = data
+        .user_affiliation
+        .iter()
+        .flat_map(|affiliation| {
+            affiliation
+                .characters
+                .iter()
+                .map(|character| character.character_id)
+        })
+        .collect();
 
-        filter_groups = filters
-            .filter_groups
+    if uses_criteria(GroupFilterCriteria::Role) {
+        let corporation_ids: Vec<i32> = data
+            .user_affiliation
             .iter()
-            .map(|group| RuleSet {
-                filter_type: group.filter_type.clone(),
-                rules: group.rules.clone(),
+            .flat_map(|affiliation| {
+                affiliation
+                    .characters
+                    .iter()
+                    .map(|character| character.corporation_id)
             })
+            .collect::<HashSet<i32>>()
+            .into_iter()
             .collect();
 
-        filter_groups.push(RuleSet {
-            filter_type: filters.filter_type.clone(),
-            rules: filters.filter_rules.clone(),
-        });
-    }
+        // Paginating with a page size of 0 panics
+        if !corporation_ids.is_empty() {
+            let corporation_repo = CorporationRepository::new(db);
 
-    let mut user_affiliation: Vec<UserAffiliations> = vec![];
-    let mut user_groups: Vec<UserGroups> = vec![];
-    let mut corporation_ids: Vec<i32> = vec![];
-    let mut corporations = vec![];
-    let mut executor_ids: Vec<i32> = vec![];
+            let corporation_ids_len = corporation_ids.len() as u64;
 
-    for group in filter_groups {
-        let mut eligible_users: HashSet<i32> = HashSet::new();
+            let filters =
+                vec![entity::eve_corporation::Column::CorporationId.is_in(corporation_ids)];
 
-        if group.filter_type == GroupFilterType::All {
-            eligible_users.extend(user_ids.clone());
+            data.corporations = corporation_repo
+                .get_by_filtered(filters, 0, corporation_ids_len)
+                .await?;
         }
 
-        for filter in group.rules {
-            let users: Vec<(i32, bool)> = match filter.criteria {
-                GroupFilterCriteria::Group => {
-                    if user_groups.is_empty() {
-                        user_groups = bulk_get_user_groups(db, user_ids.clone()).await?;
-                    }
+        let uses_executor = rules.iter().any(|rule| {
+            rule.criteria == GroupFilterCriteria::Role && rule.criteria_value == "Executor"
+        });
 
-                    let group_id: i32 = filter.criteria_value.parse::<i32>().unwrap_or_else(|_| panic!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}",
-                        filter.id));
+        let alliance_ids = data
+            .corporations
+            .iter()
+            .filter_map(|corp| corp.alliance_id)
+            .collect::<HashSet<i32>>()
+            .into_iter()
+            .collect::<Vec<i32>>();
 
-                    user_groups
-                        .iter()
-                        .map(|user| (user.user_id, user.groups.contains(&group_id)))
-                        .collect()
-                }
-                GroupFilterCriteria::Corporation => {
-                    if user_affiliation.is_empty() {
-                        user_affiliation = bulk_get_user_affiliations(db, user_ids.clone()).await?;
-                    }
+        if uses_executor && !alliance_ids.is_empty() {
+            let alliance_repo = AllianceRepository::new(db);
 
-                    let corporation_id = filter.criteria_value.parse::<i32>().unwrap_or_else(|_| panic!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}",
-                        filter.id));
+            let alliance_ids_len = alliance_ids.len() as u64;
 
-                    user_affiliation
-                        .iter()
-                        .map(|user| (user.user_id, user.corporations.contains(&corporation_id)))
-                        .collect()
-                }
-                GroupFilterCriteria::Alliance => {
-                    if user_affiliation.is_empty() {
-                        user_affiliation = bulk_get_user_affiliations(db, user_ids.clone()).await?;
-                    }
+            let filters = vec![entity::eve_alliance::Column::AllianceId.is_in(alliance_ids)];
 
-                    let alliance_id = filter.criteria_value.parse::<i32>().unwrap_or_else(|_| panic!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}",
-                        filter.id));
+            data.executor_ids = alliance_repo
+                .get_by_filtered(filters, 0, alliance_ids_len)
+                .await?
+                .iter()
+                .filter_map(|alliance| alliance.executor)
+                .collect();
+        }
+    }
 
-                    user_affiliation
-                        .iter()
-                        .map(|user| (user.user_id, user.alliances.contains(&alliance_id)))
-                        .collect()
-                }
-                GroupFilterCriteria::Role => {
-                    if user_affiliation.is_empty() {
-                        user_affiliation = bulk_get_user_affiliations(db, user_ids.clone()).await?;
-                    }
+    if uses_criteria(GroupFilterCriteria::CorporationRole) {
+        data.character_roles = CharacterRoleRepository::new(db)
+            .get_by_character_ids(&character_ids)
+            .await?;
+    }
 
-                    if corporation_ids.is_empty() {
-                        corporation_ids = user_affiliation
-                            .iter()
-                            .flat_map(|affiliation| affiliation.corporations.clone())
-                            .collect();
-                    }
+    if uses_criteria(GroupFilterCriteria::CorporationTitle) {
+        data.character_titles = CharacterTitleRepository::new(db)
+            .get_by_character_ids(&character_ids)
+            .await?;
+    }
 
-                    if corporations.is_empty() {
-                        let corporation_repo = CorporationRepository::new(db);
+    if uses_criteria(GroupFilterCriteria::Organization) {
+        let organization_ids: Vec<i32> = rules
+            .iter()
+            .filter(|rule| rule.criteria == GroupFilterCriteria::Organization)
+            .filter_map(|rule| rule.criteria_value.parse::<i32>().ok())
+            .collect();
 
-                        let ids: Vec<sea_orm::Value> =
-                            corporation_ids.iter().map(|&id| id.into()).collect();
+        data.organization_members = get_organization_members(db, organization_ids).await?;
+    }
 
-                        let corporation_ids_len = corporation_ids.len() as u64;
+    Ok(data)
+}
 
-                        let filters =
-                            vec![entity::eve_corporation::Column::CorporationId.is_in(ids)];
+// Returns whether each user matches the rule's criteria, the criteria type is applied by the caller
+fn evaluate_filter_rule(filter: &GroupFilterRuleDto, data: &FilterData) -> Vec<(i32, bool)> {
+    match filter.criteria {
+        GroupFilterCriteria::Group => {
+            let group_id: i32 = filter.criteria_value.parse::<i32>().unwrap_or_else(|_| panic!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}",
+                filter.id));
+
+            data.user_groups
+                .iter()
+                .map(|user| {
+                    (
+                        user.user_id,
+                        user.groups
+                            .iter()
+                            .any(|membership| membership.group_id == group_id),
+                    )
+                })
+                .collect()
+        }
+        GroupFilterCriteria::Corporation => {
+            let corporation_id = filter.criteria_value.parse::<i32>().unwrap_or_else(|_| panic!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}",
+                filter.id));
+
+            data.user_affiliation
+                .iter()
+                .map(|user| {
+                    (
+                        user.user_id,
+                        match_characters(user, &filter.scope, |character| {
+                            character.corporation_id == corporation_id
+                        }),
+                    )
+                })
+                .collect()
+        }
+        GroupFilterCriteria::Alliance => {
+            let alliance_id = filter.criteria_value.parse::<i32>().unwrap_or_else(|_| panic!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}",
+                filter.id));
+
+            data.user_affiliation
+                .iter()
+                .map(|user| {
+                    (
+                        user.user_id,
+                        match_characters(user, &filter.scope, |character| {
+                            character.alliance_id == Some(alliance_id)
+                        }),
+                    )
+                })
+                .collect()
+        }
+        GroupFilterCriteria::Role => {
+            let leadership_ids: Vec<i32> = match filter.criteria_value.as_str() {
+                "CEO" => data.corporations.iter().map(|corporation| corporation.ceo).collect(),
+                "Executor" => data
+                    .corporations
+                    .iter()
+                    .filter(|corp| data.executor_ids.contains(&corp.corporation_id))
+                    .map(|corp| corp.ceo)
+                    .collect(),
+                _ => panic!("{}", format!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}", filter.id))
+            };
 
-                        corporations = corporation_repo
-                            .get_by_filtered(filters, 0, corporation_ids_len)
-                            .await?;
-                    }
+            data.user_affiliation
+                .iter()
+                .map(|user| {
+                    (
+                        user.user_id,
+                        match_characters(user, &filter.scope, |character| {
+                            leadership_ids.contains(&character.character_id)
+                        }),
+                    )
+                })
+                .collect()
+        }
+        GroupFilterCriteria::CorporationRole => data
+            .user_affiliation
+            .iter()
+            .map(|user| {
+                (
+                    user.user_id,
+                    match_characters(user, &filter.scope, |character| {
+                        data.character_roles.iter().any(|role| {
+                            role.character_id == character.character_id
+                                && role.role == filter.criteria_value
+                        })
+                    }),
+                )
+            })
+            .collect(),
+        GroupFilterCriteria::CorporationTitle => data
+            .user_affiliation
+            .iter()
+            .map(|user| {
+                (
+                    user.user_id,
+                    match_characters(user, &filter.scope, |character| {
+                        data.character_titles.iter().any(|title| {
+                            title.character_id == character.character_id
+                                && title.title_name == filter.criteria_value
+                        })
+                    }),
+                )
+            })
+            .collect(),
+        GroupFilterCriteria::Organization => {
+            use entity::sea_orm_active_enums::OrganizationMemberType;
+
+            let organization_id = filter.criteria_value.parse::<i32>().unwrap_or_else(|_| panic!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}",
+                filter.id));
+
+            let members: Vec<&entity::auth_organization_member::Model> = data
+                .organization_members
+                .iter()
+                .filter(|member| member.organization_id == organization_id)
+                .collect();
+
+            data.user_affiliation
+                .iter()
+                .map(|user| {
+                    (
+                        user.user_id,
+                        match_characters(user, &filter.scope, |character| {
+                            members.iter().any(|member| match member.member_type {
+                                OrganizationMemberType::Alliance => {
+                                    character.alliance_id == Some(member.member_id)
+                                }
+                                OrganizationMemberType::Corporation => {
+                                    character.corporation_id == member.member_id
+                                }
+                            })
+                        }),
+                    )
+                })
+                .collect()
+        }
+    }
+}
 
-                    let leadership_ids: Vec<i32> = match filter.criteria_value.as_str() {
-                        "CEO" => {
-                            corporations.iter()
-                            .map(|corporation| corporation.ceo)
-                            .collect()
-                        }
-                        "Executor" => {
-                            if executor_ids.is_empty() {
-                                let alliance_ids = corporations.iter()
-                                    .filter_map(|corp| corp.alliance_id)
-                                    .collect::<Vec<i32>>();
+// Evaluates a filter group & its nested filter groups, returning the user_ids which pass
+// An "all" group starts with every user & removes those failing a rule or nested group,
+// an "any" group starts empty & adds those passing a rule or nested group
+fn evaluate_filter_group(
+    filter_type: &GroupFilterType,
+    rules: &[GroupFilterRuleDto],
+    filter_groups: &[GroupFilterGroupDto],
+    user_ids: &[i32],
+    data: &FilterData,
+) -> HashSet<i32> {
+    let mut eligible_users: HashSet<i32> = HashSet::new();
+
+    if filter_type == &GroupFilterType::All {
+        eligible_users.extend(user_ids);
+    }
 
-                                let alliance_repo = AllianceRepository::new(db);
+    for filter in rules {
+        for user in evaluate_filter_rule(filter, data) {
+            match (filter_type, &filter.criteria_type, user.1) {
+                (GroupFilterType::All, GroupFilterCriteriaType::Is, false) => {
+                    eligible_users.remove(&user.0);
+                }
+                (GroupFilterType::All, GroupFilterCriteriaType::IsNot, true) => {
+                    eligible_users.remove(&user.0);
+                }
+                (GroupFilterType::Any, GroupFilterCriteriaType::Is, true) => {
+                    eligible_users.insert(user.0);
+                }
+                (GroupFilterType::Any, GroupFilterCriteriaType::IsNot, false) => {
+                    eligible_users.insert(user.0);
+                }
+                _ => (),
+            }
+        }
+    }
 
-                                let alliance_ids_len = alliance_ids.len() as u64;
+    for filter_group in filter_groups {
+        let group_users = evaluate_filter_group(
+            &filter_group.filter_type,
+            &filter_group.rules,
+            &filter_group.filter_groups,
+            user_ids,
+            data,
+        );
+
+        match filter_type {
+            GroupFilterType::All => eligible_users.retain(|user| group_users.contains(user)),
+            GroupFilterType::Any => eligible_users.extend(group_users),
+        }
+    }
 
-                                let filters =
-                                vec![entity::eve_alliance::Column::AllianceId.is_in(alliance_ids)];
+    eligible_users
+}
 
-                                executor_ids = alliance_repo.get_by_filtered(filters, 0, alliance_ids_len).await?.iter()
-                                .filter_map(|alliance| alliance.executor)
-                                .collect();
-                            }
+// Gets every rule of a group's filters, including the rules of nested filter groups
+fn get_all_filter_rules(filters: &GroupFiltersDto) -> Vec<&GroupFilterRuleDto> {
+    let mut rules: Vec<&GroupFilterRuleDto> = filters.filter_rules.iter().collect();
+    let mut filter_groups: Vec<&GroupFilterGroupDto> = filters.filter_groups.iter().collect();
 
-                            corporations
-                            .iter()
-                            .filter(|corp| executor_ids.contains(&corp.corporation_id))
-                            .map(|corp| corp.ceo)
-                            .collect()
-                        }
-                        _ => panic!("{}", format!("Filter rule saved incorrectly, invalid criteria value insterted for filter rule {}", filter.id))
-                    };
-
-                    user_affiliation
-                        .iter()
-                        .map(|user| {
-                            (
-                                user.user_id,
-                                user.characters.iter().any(|id| leadership_ids.contains(id)),
-                            )
-                        })
-                        .collect()
-                }
-            };
+    while let Some(filter_group) = filter_groups.pop() {
+        rules.extend(filter_group.rules.iter());
+        filter_groups.extend(filter_group.filter_groups.iter());
+    }
 
-            for user in users {
-                match (&group.filter_type, &filter.criteria_type, user.1) {
-                    (GroupFilterType::All, GroupFilterCriteriaType::Is, false) => {
-                        eligible_users.remove(&user.0);
-                    }
-                    (GroupFilterType::All, GroupFilterCriteriaType::IsNot, true) => {
-                        eligible_users.remove(&user.0);
-                    }
-                    (GroupFilterType::Any, GroupFilterCriteriaType::Is, true) => {
-                        eligible_users.insert(user.0);
-                    }
-                    (GroupFilterType::Any, GroupFilterCriteriaType::IsNot, false) => {
-                        eligible_users.insert(user.0);
-                    }
-                    _ => (),
-                }
-            }
-        }
+    rules
+}
 
-        result.push(eligible_users);
+// Returns the user_ids which pass a group's filters, all users pass a group without filters
+fn evaluate_group_filters(
+    filters: &GroupFiltersDto,
+    user_ids: &[i32],
+    data: &FilterData,
+) -> HashSet<i32> {
+    if filters.filter_rules.is_empty() && filters.filter_groups.is_empty() {
+        return user_ids.iter().copied().collect();
     }
 
-    let eligible_users = match filters {
-        Some(filters) => match filters.filter_type {
-            // If ALL filters are met add to new_members
-            GroupFilterType::All => result.iter().skip(1).fold(result[0].clone(), |acc, set| {
-                acc.intersection(set).cloned().collect::<HashSet<i32>>()
-            }),
-            // If ANY filters are met add to new_members
-            GroupFilterType::Any => result.iter().skip(1).fold(result[0].clone(), |acc, set| {
-                acc.union(set).cloned().collect::<HashSet<i32>>()
-            }),
-        }
-        .into_iter()
-        .collect::<Vec<i32>>(),
-        // If no filters then add all users to new_members
-        None => result[0].clone().into_iter().collect::<Vec<i32>>(),
+    evaluate_filter_group(
+        &filters.filter_type,
+        &filters.filter_rules,
+        &filters.filter_groups,
+        user_ids,
+        data,
+    )
+}
+
+// Checks a vec of user_ids against a group's filters and returns a vec of user_ids that are eligible to be in the group
+pub async fn validate_group_members(
+    db: &DatabaseConnection,
+    group_id: i32,
+    user_ids: Vec<i32>,
+) -> Result<Vec<i32>, DbErr> {
+    let filters = match get_group_filters(db, group_id).await? {
+        Some(filters) => filters,
+        // If no filters then all users are eligible
+        None => return Ok(user_ids),
     };
 
-    Ok(eligible_users)
+    if filters.filter_rules.is_empty() && filters.filter_groups.is_empty() {
+        return Ok(user_ids);
+    }
+
+    let data = get_filter_data(db, &user_ids, &get_all_filter_rules(&filters)).await?;
+
+    let eligible_users = evaluate_group_filters(&filters, &user_ids, &data);
+
+    Ok(eligible_users.into_iter().collect())
+}
+
+// Returns the ids of the groups whose filters a user passes, the data required by the filters of
+// every group is fetched once rather than per group
+pub async fn get_eligible_group_ids(
+    db: &DatabaseConnection,
+    group_ids: &[i32],
+    user_id: i32,
+) -> Result<HashSet<i32>, DbErr> {
+    let group_filters = bulk_get_group_filters(db, group_ids).await?;
+
+    let rules: Vec<&GroupFilterRuleDto> = group_filters
+        .iter()
+        .flat_map(get_all_filter_rules)
+        .collect();
+
+    let data = get_filter_data(db, &[user_id], &rules).await?;
+
+    Ok(group_filters
+        .iter()
+        .filter(|filters| evaluate_group_filters(filters, &[user_id], &data).contains(&user_id))
+        .map(|filters| filters.id)
+        .collect())
 }
 
 pub async fn create_filter_groups(
@@ -329,16 +640,30 @@ pub async fn create_filter_groups(
     group_id: i32,
     filter_groups: Vec<NewGroupFilterGroupDto>,
 ) -> Result<(), DbErr> {
-    for group in filter_groups {
+    // (parent filter group id, filter group)
+    let mut queue: Vec<(Option<i32>, NewGroupFilterGroupDto)> = filter_groups
+        .into_iter()
+        .map(|group| (None, group))
+        .collect();
+
+    while let Some((parent_id, group)) = queue.pop() {
         let new_group = entity::auth_group_filter_group::ActiveModel {
             group_id: Set(group_id),
             filter_type: Set(group.filter_type.into()),
+            parent_id: Set(parent_id),
             ..Default::default()
         };
 
         let filter_group = new_group.insert(db).await?;
 
-        let _ = bulk_create_filter_rules(db, group_id, Some(filter_group.id), group.rules).await;
+        bulk_create_filter_rules(db, group_id, Some(filter_group.id), group.rules).await?;
+
+        queue.extend(
+            group
+                .filter_groups
+                .into_iter()
+                .map(|child| (Some(filter_group.id), child)),
+        );
     }
 
     Ok(())
@@ -363,6 +688,7 @@ pub async fn bulk_create_filter_rules(
             criteria: Set(rule.criteria.into()),
             criteria_type: Set(rule.criteria_type.into()),
             criteria_value: Set(rule.criteria_value),
+            scope: Set(rule.scope.into()),
             ..Default::default()
         };
 
@@ -376,57 +702,94 @@ pub async fn bulk_create_filter_rules(
     Ok(())
 }
 
+// Builds the filter groups nested under parent_id, groups nested deeper than
+// MAX_FILTER_GROUP_DEPTH are ignored
+fn build_filter_groups(
+    parent_id: Option<i32>,
+    depth: usize,
+    filter_groups: &[entity::auth_group_filter_group::Model],
+    filter_rules: &[entity::auth_group_filter_rule::Model],
+) -> Vec<GroupFilterGroupDto> {
+    if depth > MAX_FILTER_GROUP_DEPTH {
+        return vec![];
+    }
+
+    filter_groups
+        .iter()
+        .filter(|group| group.parent_id == parent_id)
+        .map(|group| GroupFilterGroupDto {
+            id: group.id,
+            filter_type: group.filter_type.clone().into(),
+            rules: filter_rules
+                .iter()
+                .filter(|rule| rule.filter_group_id == Some(group.id))
+                .map(|rule| rule.clone().into())
+                .collect(),
+            filter_groups: build_filter_groups(
+                Some(group.id),
+                depth + 1,
+                filter_groups,
+                filter_rules,
+            ),
+        })
+        .collect()
+}
+
 pub async fn get_group_filters(
     db: &DatabaseConnection,
     id: i32,
 ) -> Result<Option<GroupFiltersDto>, DbErr> {
-    let group = entity::prelude::AuthGroup::find()
-        .filter(entity::auth_group::Column::Id.eq(id))
-        .one(db)
-        .await?;
-
-    match group {
-        Some(group) => {
-            let filter_rules = entity::prelude::AuthGroupFilterRule::find()
-                .filter(entity::auth_group_filter_rule::Column::GroupId.eq(id))
-                .filter(entity::auth_group_filter_rule::Column::FilterGroupId.is_null())
-                .all(db)
-                .await?;
-
-            let filter_groups = entity::prelude::AuthGroupFilterGroup::find()
-                .filter(entity::auth_group_filter_group::Column::GroupId.eq(id))
-                .all(db)
-                .await?;
-
-            let mut groups: Vec<GroupFilterGroupDto> = vec![];
+    Ok(bulk_get_group_filters(db, &[id]).await?.pop())
+}
 
-            for group in filter_groups {
-                let rules = entity::prelude::AuthGroupFilterRule::find()
-                    .filter(entity::auth_group_filter_rule::Column::GroupId.eq(id))
-                    .filter(entity::auth_group_filter_rule::Column::FilterGroupId.eq(group.id))
-                    .all(db)
-                    .await?;
+pub async fn bulk_get_group_filters(
+    db: &DatabaseConnection,
+    group_ids: &[i32],
+) -> Result<Vec<GroupFiltersDto>, DbErr> {
+    let groups = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::Id.is_in(group_ids.to_vec()))
+        .all(db)
+        .await?;
 
-                let group = GroupFilterGroupDto {
-                    id: group.id,
-                    filter_type: group.filter_type.into(),
-                    rules: rules.into_iter().map(|rule| rule.into()).collect(),
-                };
+    let filter_rules = entity::prelude::AuthGroupFilterRule::find()
+        .filter(entity::auth_group_filter_rule::Column::GroupId.is_in(group_ids.to_vec()))
+        .all(db)
+        .await?;
 
-                groups.push(group)
-            }
+    let filter_groups = entity::prelude::AuthGroupFilterGroup::find()
+        .filter(entity::auth_group_filter_group::Column::GroupId.is_in(group_ids.to_vec()))
+        .all(db)
+        .await?;
 
-            let result = GroupFiltersDto {
+    Ok(groups
+        .into_iter()
+        .map(|group| {
+            let group_filter_rules: Vec<entity::auth_group_filter_rule::Model> = filter_rules
+                .iter()
+                .filter(|rule| rule.group_id == group.id)
+                .cloned()
+                .collect();
+
+            let group_filter_groups: Vec<entity::auth_group_filter_group::Model> = filter_groups
+                .iter()
+                .filter(|filter_group| filter_group.group_id == group.id)
+                .cloned()
+                .collect();
+
+            let groups = build_filter_groups(None, 1, &group_filter_groups, &group_filter_rules);
+
+            GroupFiltersDto {
                 id: group.id,
                 filter_type: group.filter_type.into(),
-                filter_rules: filter_rules.into_iter().map(|rule| rule.into()).collect(),
+                filter_rules: group_filter_rules
+                    .into_iter()
+                    .filter(|rule| rule.filter_group_id.is_none())
+                    .map(|rule| rule.into())
+                    .collect(),
                 filter_groups: groups,
-            };
-
-            Ok(Some(result))
-        }
-        None => Ok(None),
-    }
+            }
+        })
+        .collect())
 }
 
 pub async fn update_filter_groups(
@@ -434,42 +797,108 @@ pub async fn update_filter_groups(
     group_id: i32,
     groups: Vec<UpdateGroupFilterGroupDto>,
 ) -> Result<(), anyhow::Error> {
-    let group_ids: Vec<i32> = groups
-        .clone()
-        .into_iter()
-        .filter_map(|group| group.id)
-        .collect();
+    let mut filter_group_ids: Vec<i32> = vec![];
+    let mut nested_groups: Vec<&UpdateGroupFilterGroupDto> = groups.iter().collect();
+
+    while let Some(group) = nested_groups.pop() {
+        if let Some(id) = group.id {
+            if filter_group_ids.contains(&id) {
+                return Err(anyhow!(
+                    "Filter group with id {} is included more than once",
+                    id
+                ));
+            }
 
-    entity::prelude::AuthGroupFilterGroup::delete_many()
+            filter_group_ids.push(id);
+        }
+
+        nested_groups.extend(group.filter_groups.iter());
+    }
+
+    let existing_group_ids = entity::prelude::AuthGroupFilterGroup::find()
         .filter(entity::auth_group_filter_group::Column::GroupId.eq(group_id))
-        .filter(entity::auth_group_filter_group::Column::Id.is_not_in(group_ids))
-        .exec(db)
-        .await
-        .unwrap();
-
-    for group in groups {
-        if let Some(filter_group_id) = group.id {
-            let updated_filter_group: entity::auth_group_filter_group::ActiveModel =
-                entity::auth_group_filter_group::ActiveModel {
-                    id: Set(filter_group_id),
-                    filter_type: Set(group.filter_type.into()),
-                    ..Default::default()
-                };
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|group| group.id)
+        .collect::<Vec<i32>>();
+
+    let invalid_id = filter_group_ids
+        .iter()
+        .find(|id| !existing_group_ids.contains(id));
+
+    if let Some(invalid_id) = invalid_id {
+        return Err(anyhow!(
+            "Filter group with id {} does not belong to group {}",
+            invalid_id,
+            group_id
+        ));
+    }
+
+    let removed_group_ids = existing_group_ids
+        .into_iter()
+        .filter(|id| !filter_group_ids.contains(id))
+        .collect::<Vec<i32>>();
+
+    if !removed_group_ids.is_empty() {
+        // Kept groups are detached before removing their old parents, otherwise a group moved
+        // out of a removed parent would be deleted along with it by the cascading foreign key
+        entity::prelude::AuthGroupFilterGroup::update_many()
+            .col_expr(
+                entity::auth_group_filter_group::Column::ParentId,
+                Expr::value(None::<i32>),
+            )
+            .filter(entity::auth_group_filter_group::Column::Id.is_in(filter_group_ids.clone()))
+            .exec(db)
+            .await?;
+
+        entity::prelude::AuthGroupFilterRule::delete_many()
+            .filter(
+                entity::auth_group_filter_rule::Column::FilterGroupId
+                    .is_in(removed_group_ids.clone()),
+            )
+            .exec(db)
+            .await?;
+
+        entity::prelude::AuthGroupFilterGroup::delete_many()
+            .filter(entity::auth_group_filter_group::Column::Id.is_in(removed_group_ids))
+            .exec(db)
+            .await?;
+    }
 
-            updated_filter_group.update(db).await?;
+    // (parent filter group id, filter group)
+    let mut queue: Vec<(Option<i32>, UpdateGroupFilterGroupDto)> =
+        groups.into_iter().map(|group| (None, group)).collect();
 
-            update_filter_rules(db, group_id, Some(filter_group_id), group.rules).await?;
+    while let Some((parent_id, group)) = queue.pop() {
+        let filter_group = if let Some(filter_group_id) = group.id {
+            let updated_filter_group = entity::auth_group_filter_group::ActiveModel {
+                id: Set(filter_group_id),
+                filter_type: Set(group.filter_type.into()),
+                parent_id: Set(parent_id),
+                ..Default::default()
+            };
+
+            updated_filter_group.update(db).await?
         } else {
             let new_filter_group = entity::auth_group_filter_group::ActiveModel {
                 group_id: Set(group_id),
                 filter_type: Set(group.filter_type.into()),
+                parent_id: Set(parent_id),
                 ..Default::default()
             };
 
-            let new_filter_group = new_filter_group.insert(db).await?;
+            new_filter_group.insert(db).await?
+        };
 
-            update_filter_rules(db, group_id, Some(new_filter_group.id), group.rules).await?;
-        }
+        update_filter_rules(db, group_id, Some(filter_group.id), group.rules).await?;
+
+        queue.extend(
+            group
+                .filter_groups
+                .into_iter()
+                .map(|child| (Some(filter_group.id), child)),
+        );
     }
 
     Ok(())
@@ -526,6 +955,7 @@ pub async fn update_filter_rules(
                 criteria: Set(rule.criteria.into()),
                 criteria_type: Set(rule.criteria_type.into()),
                 criteria_value: Set(rule.criteria_value),
+                scope: Set(rule.scope.into()),
             };
 
             updated_rule.update(db).await?;
@@ -536,6 +966,7 @@ pub async fn update_filter_rules(
                 criteria: Set(rule.criteria.into()),
                 criteria_type: Set(rule.criteria_type.into()),
                 criteria_value: Set(rule.criteria_value),
+                scope: Set(rule.scope.into()),
                 ..Default::default()
             };
 
@@ -573,3 +1004,346 @@ pub async fn delete_filter_rules(
         .exec(db)
         .await
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{
+        evaluate_filter_group, get_group_filters, match_characters, update_filter_groups,
+        validate_group_filters, validate_group_members, FilterData, MAX_FILTER_GROUP_DEPTH,
+    };
+    use crate::{
+        auth::{
+            data::{
+                groups::create_group,
+                user::{create_user, update_ownership},
+            },
+            model::{
+                groups::{
+                    GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterGroupDto,
+                    GroupFilterRuleDto, GroupFilterScope, GroupFilterType, NewGroupDto,
+                    NewGroupFilterGroupDto, NewGroupFilterRuleDto, UpdateGroupFilterGroupDto,
+                    UpdateGroupFilterRuleDto,
+                },
+                user::{UserAffiliations, UserCharacterAffiliation},
+            },
+        },
+        eve::service::character::get_or_create_character,
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+
+    fn affiliation(characters: &[(i32, i32, bool)]) -> UserAffiliations {
+        UserAffiliations {
+            user_id: 1,
+            characters: characters
+                .iter()
+                .map(
+                    |(character_id, corporation_id, main)| UserCharacterAffiliation {
+                        character_id: *character_id,
+                        corporation_id: *corporation_id,
+                        alliance_id: None,
+                        main: *main,
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn match_main_character() {
+        let in_corporation = |character: &UserCharacterAffiliation| character.corporation_id == 1;
+
+        // Only the main is checked, regardless of the user's other characters
+        let user = affiliation(&[(1, 1, false), (2, 2, true)]);
+        assert!(!match_characters(
+            &user,
+            &GroupFilterScope::MainCharacter,
+            in_corporation
+        ));
+        assert!(match_characters(
+            &user,
+            &GroupFilterScope::AnyCharacter,
+            in_corporation
+        ));
+
+        let user = affiliation(&[(1, 1, true), (2, 2, false)]);
+        assert!(match_characters(
+            &user,
+            &GroupFilterScope::MainCharacter,
+            in_corporation
+        ));
+
+        // Users without a main never match rules scoped to the main character
+        let user = affiliation(&[(1, 1, false)]);
+        assert!(!match_characters(
+            &user,
+            &GroupFilterScope::MainCharacter,
+            in_corporation
+        ));
+    }
+
+    #[test]
+    fn match_all_characters() {
+        let in_corporation = |character: &UserCharacterAffiliation| character.corporation_id == 1;
+
+        let user = affiliation(&[(1, 1, true), (2, 2, false)]);
+        assert!(!match_characters(
+            &user,
+            &GroupFilterScope::AllCharacters,
+            in_corporation
+        ));
+
+        let user = affiliation(&[(1, 1, true), (2, 1, false)]);
+        assert!(match_characters(
+            &user,
+            &GroupFilterScope::AllCharacters,
+            in_corporation
+        ));
+
+        // Applies to every character even if the user has no main
+        let user = affiliation(&[(1, 1, false), (2, 1, false)]);
+        assert!(match_characters(
+            &user,
+            &GroupFilterScope::AllCharacters,
+            in_corporation
+        ));
+
+        // Users without characters don't match, rather than matching vacuously
+        let user = affiliation(&[]);
+        assert!(!match_characters(
+            &user,
+            &GroupFilterScope::AllCharacters,
+            in_corporation
+        ));
+    }
+
+    fn corporation_rule(
+        criteria_type: GroupFilterCriteriaType,
+        corporation_id: i32,
+    ) -> GroupFilterRuleDto {
+        GroupFilterRuleDto {
+            id: corporation_id,
+            criteria: GroupFilterCriteria::Corporation,
+            criteria_type,
+            criteria_value: corporation_id.to_string(),
+            scope: GroupFilterScope::AnyCharacter,
+        }
+    }
+
+    fn filter_group(
+        filter_type: GroupFilterType,
+        rules: Vec<GroupFilterRuleDto>,
+        filter_groups: Vec<GroupFilterGroupDto>,
+    ) -> GroupFilterGroupDto {
+        GroupFilterGroupDto {
+            id: 0,
+            filter_type,
+            rules,
+            filter_groups,
+        }
+    }
+
+    #[test]
+    fn evaluate_nested_filter_groups() {
+        // Users 1, 2 & 3 each have a single character in the corporation matching their id
+        let data = FilterData {
+            user_affiliation: (1..=3)
+                .map(|id| UserAffiliations {
+                    user_id: id,
+                    characters: vec![UserCharacterAffiliation {
+                        character_id: id,
+                        corporation_id: id,
+                        alliance_id: None,
+                        main: true,
+                    }],
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let user_ids = [1, 2, 3];
+
+        // All of: (corporation 1 or corporation 2) and (not corporation 2)
+        let eligible = evaluate_filter_group(
+            &GroupFilterType::All,
+            &[],
+            &[
+                filter_group(
+                    GroupFilterType::Any,
+                    vec![
+                        corporation_rule(GroupFilterCriteriaType::Is, 1),
+                        corporation_rule(GroupFilterCriteriaType::Is, 2),
+                    ],
+                    vec![],
+                ),
+                filter_group(
+                    GroupFilterType::All,
+                    vec![corporation_rule(GroupFilterCriteriaType::IsNot, 2)],
+                    vec![],
+                ),
+            ],
+            &user_ids,
+            &data,
+        );
+
+        assert_eq!(eligible, HashSet::from([1]));
+
+        // Any of: corporation 3 or (not corporation 1 and (any of corporation 2))
+        let eligible = evaluate_filter_group(
+            &GroupFilterType::Any,
+            &[corporation_rule(GroupFilterCriteriaType::Is, 3)],
+            &[filter_group(
+                GroupFilterType::All,
+                vec![corporation_rule(GroupFilterCriteriaType::IsNot, 1)],
+                vec![filter_group(
+                    GroupFilterType::Any,
+                    vec![corporation_rule(GroupFilterCriteriaType::Is, 2)],
+                    vec![],
+                )],
+            )],
+            &user_ids,
+            &data,
+        );
+
+        assert_eq!(eligible, HashSet::from([2, 3]));
+    }
+
+    #[tokio::test]
+    async fn reject_filter_groups_nested_too_deep() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let nested = |depth: usize| {
+            let mut filter_group = NewGroupFilterGroupDto {
+                filter_type: GroupFilterType::All,
+                rules: vec![],
+                filter_groups: vec![],
+            };
+
+            for _ in 1..depth {
+                filter_group = NewGroupFilterGroupDto {
+                    filter_type: GroupFilterType::Any,
+                    rules: vec![],
+                    filter_groups: vec![filter_group],
+                };
+            }
+
+            NewGroupDto {
+                filter_groups: vec![filter_group],
+                ..new_group_dto("Nested")
+            }
+        };
+
+        validate_group_filters(&db, None, &nested(MAX_FILTER_GROUP_DEPTH)).await?;
+
+        let result = validate_group_filters(&db, None, &nested(MAX_FILTER_GROUP_DEPTH + 1)).await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "Filter groups can't be nested more than {} levels deep",
+                MAX_FILTER_GROUP_DEPTH
+            )
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_moves_filter_group_out_of_removed_parent() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(
+            &db,
+            NewGroupDto {
+                filter_groups: vec![NewGroupFilterGroupDto {
+                    filter_type: GroupFilterType::Any,
+                    rules: vec![],
+                    filter_groups: vec![NewGroupFilterGroupDto {
+                        filter_type: GroupFilterType::All,
+                        rules: vec![NewGroupFilterRuleDto {
+                            criteria: GroupFilterCriteria::Corporation,
+                            criteria_type: GroupFilterCriteriaType::Is,
+                            criteria_value: "109299958".to_string(),
+                            scope: GroupFilterScope::AnyCharacter,
+                        }],
+                        filter_groups: vec![],
+                    }],
+                }],
+                ..new_group_dto("Filtered")
+            },
+        )
+        .await?;
+
+        let filters = get_group_filters(&db, group.id).await?.unwrap();
+        let child = &filters.filter_groups[0].filter_groups[0];
+
+        // The child is kept & moved to the top level while its parent is removed
+        update_filter_groups(
+            &db,
+            group.id,
+            vec![UpdateGroupFilterGroupDto {
+                id: Some(child.id),
+                filter_type: GroupFilterType::All,
+                rules: vec![UpdateGroupFilterRuleDto {
+                    id: Some(child.rules[0].id),
+                    criteria: GroupFilterCriteria::Corporation,
+                    criteria_type: GroupFilterCriteriaType::Is,
+                    criteria_value: "109299958".to_string(),
+                    scope: GroupFilterScope::AnyCharacter,
+                }],
+                filter_groups: vec![],
+            }],
+        )
+        .await?;
+
+        let filters = get_group_filters(&db, group.id).await?.unwrap();
+
+        assert_eq!(filters.filter_groups.len(), 1);
+        assert_eq!(filters.filter_groups[0].id, child.id);
+        assert_eq!(filters.filter_groups[0].rules.len(), 1);
+        assert!(filters.filter_groups[0].filter_groups.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn role_rule_on_corporation_without_alliance() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        // The mocked CEO character's corporation isn't in an alliance
+        let character = get_or_create_character(&db, 180548812).await?;
+
+        let ceo_id = create_user(&db).await?;
+        update_ownership(&db, ceo_id, character.character_id, "ownerhash".to_string()).await?;
+
+        // A user without characters leaves no corporations to look up
+        let user_id = create_user(&db).await?;
+
+        let role_group = |name: &str, role: &str| NewGroupDto {
+            filter_rules: vec![NewGroupFilterRuleDto {
+                criteria: GroupFilterCriteria::Role,
+                criteria_type: GroupFilterCriteriaType::Is,
+                criteria_value: role.to_string(),
+                scope: GroupFilterScope::AnyCharacter,
+            }],
+            ..new_group_dto(name)
+        };
+
+        let ceo_group = create_group(&db, role_group("CEOs", "CEO")).await?;
+        let executor_group = create_group(&db, role_group("Executors", "Executor")).await?;
+
+        let eligible = validate_group_members(&db, ceo_group.id, vec![ceo_id]).await?;
+
+        assert_eq!(eligible, vec![ceo_id]);
+
+        let eligible = validate_group_members(&db, executor_group.id, vec![ceo_id]).await?;
+
+        assert!(eligible.is_empty());
+
+        // Without any corporations to look up the lookups are skipped rather than paginating by 0
+        validate_group_members(&db, ceo_group.id, vec![user_id]).await?;
+        validate_group_members(&db, executor_group.id, vec![user_id]).await?;
+
+        Ok(())
+    }
+}
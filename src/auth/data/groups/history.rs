@@ -0,0 +1,208 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+
+use entity::sea_orm_active_enums::{
+    GroupApplicationStatus, GroupMembershipAction, GroupMembershipReason,
+};
+
+use crate::auth::model::groups::{GroupApplicationHistoryDto, GroupMembershipHistoryDto};
+
+// Membership history is append only, entries are never updated or deleted
+pub async fn record_membership_changes(
+    db: &DatabaseConnection,
+    group_id: i32,
+    user_ids: &[i32],
+    action: GroupMembershipAction,
+    reason: GroupMembershipReason,
+    actor_id: Option<i32>,
+) -> Result<(), DbErr> {
+    let created = Utc::now().naive_utc();
+
+    let entries: Vec<entity::auth_group_membership_history::ActiveModel> = user_ids
+        .iter()
+        .map(
+            |user_id| entity::auth_group_membership_history::ActiveModel {
+                group_id: Set(group_id),
+                user_id: Set(*user_id),
+                action: Set(action.clone()),
+                reason: Set(reason.clone()),
+                actor_id: Set(actor_id),
+                created: Set(created),
+                ..Default::default()
+            },
+        )
+        .collect();
+
+    entity::prelude::AuthGroupMembershipHistory::insert_many(entries)
+        .on_empty_do_nothing()
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_membership_history(
+    db: &DatabaseConnection,
+    group_id: Option<i32>,
+    user_id: Option<i32>,
+) -> Result<Vec<GroupMembershipHistoryDto>, DbErr> {
+    let mut query = entity::prelude::AuthGroupMembershipHistory::find();
+
+    if let Some(group_id) = group_id {
+        query = query.filter(entity::auth_group_membership_history::Column::GroupId.eq(group_id));
+    }
+
+    if let Some(user_id) = user_id {
+        query = query.filter(entity::auth_group_membership_history::Column::UserId.eq(user_id));
+    }
+
+    let entries = query
+        .order_by_desc(entity::auth_group_membership_history::Column::Created)
+        .order_by_desc(entity::auth_group_membership_history::Column::Id)
+        .all(db)
+        .await?;
+
+    Ok(entries
+        .into_iter()
+        .map(GroupMembershipHistoryDto::from)
+        .collect())
+}
+
+// Application history is append only like membership history, an entry is recorded for every
+// status the application moves through starting with its creation
+pub async fn record_application_status_changes(
+    db: &DatabaseConnection,
+    application_ids: &[i32],
+    old_status: Option<GroupApplicationStatus>,
+    new_status: GroupApplicationStatus,
+    actor_id: Option<i32>,
+    message: Option<String>,
+) -> Result<(), DbErr> {
+    let created = Utc::now().naive_utc();
+
+    let entries: Vec<entity::auth_group_application_history::ActiveModel> = application_ids
+        .iter()
+        .map(
+            |application_id| entity::auth_group_application_history::ActiveModel {
+                application_id: Set(*application_id),
+                actor_id: Set(actor_id),
+                old_status: Set(old_status.clone()),
+                new_status: Set(new_status.clone()),
+                message: Set(message.clone()),
+                created: Set(created),
+                ..Default::default()
+            },
+        )
+        .collect();
+
+    entity::prelude::AuthGroupApplicationHistory::insert_many(entries)
+        .on_empty_do_nothing()
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+// Entries are returned in the order the changes were made
+pub async fn get_application_history(
+    db: &DatabaseConnection,
+    application_ids: Vec<i32>,
+) -> Result<Vec<GroupApplicationHistoryDto>, DbErr> {
+    let entries = entity::prelude::AuthGroupApplicationHistory::find()
+        .filter(
+            entity::auth_group_application_history::Column::ApplicationId.is_in(application_ids),
+        )
+        .order_by_asc(entity::auth_group_application_history::Column::Created)
+        .order_by_asc(entity::auth_group_application_history::Column::Id)
+        .all(db)
+        .await?;
+
+    Ok(entries
+        .into_iter()
+        .map(GroupApplicationHistoryDto::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_membership_history;
+    use crate::{
+        auth::{
+            data::{
+                groups::{
+                    create_group,
+                    members::{add_group_members, delete_group_members},
+                },
+                user::create_user,
+            },
+            model::groups::{GroupMembershipAction, GroupMembershipReason},
+        },
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+
+    #[tokio::test]
+    async fn record_membership_history() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_group_dto("Logistics")).await?;
+        let other_group = create_group(&db, new_group_dto("Scouts")).await?;
+
+        let admin_id = create_user(&db).await?;
+        let user_id = create_user(&db).await?;
+
+        for _ in 0..2 {
+            add_group_members(
+                &db,
+                group.id,
+                vec![user_id],
+                Some(admin_id),
+                None,
+                entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+            )
+            .await?;
+        }
+
+        add_group_members(
+            &db,
+            other_group.id,
+            vec![user_id],
+            None,
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        delete_group_members(
+            &db,
+            group.id,
+            vec![user_id],
+            Some(admin_id),
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        // Re-adding an existing member isn't recorded, entries are returned newest first
+        let history = get_membership_history(&db, Some(group.id), Some(user_id)).await?;
+
+        assert_eq!(history.len(), 2);
+        assert!(history[0].action == GroupMembershipAction::Removed);
+        assert!(history[1].action == GroupMembershipAction::Joined);
+        assert!(history
+            .iter()
+            .all(|entry| entry.reason == GroupMembershipReason::Manual
+                && entry.actor_id == Some(admin_id)));
+
+        let history = get_membership_history(&db, None, Some(user_id)).await?;
+
+        assert_eq!(history.len(), 3);
+
+        let history = get_membership_history(&db, Some(other_group.id), None).await?;
+
+        assert_eq!(history.len(), 1);
+        assert!(history[0].actor_id.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+use anyhow::anyhow;
+use chrono::{Duration, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter, QueryOrder,
+};
+
+use crate::auth::{data::user::get_user, model::groups::NewGroupInvitationDto};
+
+use entity::sea_orm_active_enums::{GroupInvitationStatus, GroupMembershipReason};
+
+use super::{filters::validate_group_members, get_group_by_id, members::insert_group_members};
+
+use entity::auth_group_invitation::Model as GroupInvitation;
+
+// Pending invitations past their expiry are treated as expired without needing to be updated
+fn invitation_expired(invitation: &GroupInvitation) -> bool {
+    invitation
+        .expires
+        .is_some_and(|expires| expires <= Utc::now().naive_utc())
+}
+
+pub async fn create_group_invitation(
+    db: &DatabaseConnection,
+    group_id: i32,
+    invited_by: Option<i32>,
+    invitation: NewGroupInvitationDto,
+) -> Result<GroupInvitation, anyhow::Error> {
+    if get_group_by_id(db, group_id).await?.is_none() {
+        return Err(anyhow!("Group does not exist"));
+    }
+
+    if get_user(db, invitation.user_id).await?.is_none() {
+        return Err(anyhow!("User does not exist"));
+    }
+
+    let membership = entity::prelude::AuthGroupUser::find()
+        .filter(entity::auth_group_user::Column::GroupId.eq(group_id))
+        .filter(entity::auth_group_user::Column::UserId.eq(invitation.user_id))
+        .one(db)
+        .await?;
+
+    if membership.is_some() {
+        return Err(anyhow!("Already a member"));
+    }
+
+    let created = Utc::now().naive_utc();
+
+    let pending_invitation = entity::prelude::AuthGroupInvitation::find()
+        .filter(entity::auth_group_invitation::Column::GroupId.eq(group_id))
+        .filter(entity::auth_group_invitation::Column::UserId.eq(invitation.user_id))
+        .filter(entity::auth_group_invitation::Column::Status.eq(GroupInvitationStatus::Pending))
+        .filter(
+            Condition::any()
+                .add(entity::auth_group_invitation::Column::Expires.is_null())
+                .add(entity::auth_group_invitation::Column::Expires.gt(created)),
+        )
+        .one(db)
+        .await?;
+
+    if pending_invitation.is_some() {
+        return Err(anyhow!("Invitation already exists"));
+    }
+
+    let expires = match invitation.expires_in_days {
+        Some(days) if days > 0 => match Duration::try_days(days)
+            .and_then(|duration| created.checked_add_signed(duration))
+        {
+            Some(expires) => Some(expires),
+            None => return Err(anyhow!("Invalid invitation expiry")),
+        },
+        Some(_) => return Err(anyhow!("Invalid invitation expiry")),
+        None => None,
+    };
+
+    let invitation = entity::auth_group_invitation::ActiveModel {
+        group_id: Set(group_id),
+        user_id: Set(invitation.user_id),
+        invited_by: Set(invited_by),
+        status: Set(GroupInvitationStatus::Pending),
+        message: Set(invitation.message),
+        expires: Set(expires),
+        created: Set(created),
+        last_updated: Set(created),
+        ..Default::default()
+    };
+
+    let invitation = invitation.insert(db).await?;
+
+    Ok(invitation)
+}
+
+pub async fn get_invitation_by_id(
+    db: &DatabaseConnection,
+    invitation_id: i32,
+) -> Result<Option<GroupInvitation>, DbErr> {
+    entity::prelude::AuthGroupInvitation::find()
+        .filter(entity::auth_group_invitation::Column::Id.eq(invitation_id))
+        .one(db)
+        .await
+}
+
+// Includes completed & expired invitations so managers can see who was already invited
+pub async fn get_group_invitations(
+    db: &DatabaseConnection,
+    group_id: i32,
+) -> Result<Vec<GroupInvitation>, DbErr> {
+    entity::prelude::AuthGroupInvitation::find()
+        .filter(entity::auth_group_invitation::Column::GroupId.eq(group_id))
+        .order_by_desc(entity::auth_group_invitation::Column::Created)
+        .all(db)
+        .await
+}
+
+// Only invitations the user is still able to accept
+pub async fn get_user_invitations(
+    db: &DatabaseConnection,
+    user_id: i32,
+) -> Result<Vec<GroupInvitation>, DbErr> {
+    let invitations = entity::prelude::AuthGroupInvitation::find()
+        .filter(entity::auth_group_invitation::Column::UserId.eq(user_id))
+        .filter(entity::auth_group_invitation::Column::Status.eq(GroupInvitationStatus::Pending))
+        .order_by_desc(entity::auth_group_invitation::Column::Created)
+        .all(db)
+        .await?;
+
+    let mut user_invitations = vec![];
+
+    for invitation in invitations {
+        if invitation_expired(&invitation) {
+            continue;
+        }
+
+        // Invitations to archived groups are hidden until the group is restored
+        if get_group_by_id(db, invitation.group_id).await?.is_some() {
+            user_invitations.push(invitation);
+        }
+    }
+
+    Ok(user_invitations)
+}
+
+async fn update_invitation_status(
+    db: &DatabaseConnection,
+    invitation: GroupInvitation,
+    status: GroupInvitationStatus,
+) -> Result<GroupInvitation, DbErr> {
+    let mut invitation: entity::auth_group_invitation::ActiveModel = invitation.into();
+
+    invitation.status = Set(status);
+    invitation.last_updated = Set(Utc::now().naive_utc());
+
+    invitation.update(db).await
+}
+
+// Accepting adds the user to the group if they still meet the group's requirements, otherwise
+// the invitation is left pending so it can be accepted once they do
+pub async fn respond_to_group_invitation(
+    db: &DatabaseConnection,
+    invitation_id: i32,
+    user_id: i32,
+    accept: bool,
+) -> Result<GroupInvitation, anyhow::Error> {
+    let invitation = match get_invitation_by_id(db, invitation_id).await? {
+        Some(invitation) if invitation.user_id == user_id => invitation,
+        _ => return Err(anyhow!("Invitation not found")),
+    };
+
+    if invitation.status != GroupInvitationStatus::Pending {
+        return Err(anyhow!("Invitation is no longer pending"));
+    }
+
+    if invitation_expired(&invitation) {
+        return Err(anyhow!("Invitation has expired"));
+    }
+
+    if !accept {
+        let invitation =
+            update_invitation_status(db, invitation, GroupInvitationStatus::Declined).await?;
+
+        return Ok(invitation);
+    }
+
+    if get_group_by_id(db, invitation.group_id).await?.is_none() {
+        return Err(anyhow!("Group does not exist"));
+    }
+
+    let eligible_user_ids = validate_group_members(db, invitation.group_id, vec![user_id]).await?;
+
+    if !eligible_user_ids.contains(&user_id) {
+        return Err(anyhow!("User does not meet group requirements"));
+    }
+
+    insert_group_members(
+        db,
+        invitation.group_id,
+        eligible_user_ids,
+        invitation.invited_by,
+        None,
+        GroupMembershipReason::Invitation,
+    )
+    .await?;
+
+    let invitation =
+        update_invitation_status(db, invitation, GroupInvitationStatus::Accepted).await?;
+
+    Ok(invitation)
+}
+
+pub async fn revoke_group_invitation(
+    db: &DatabaseConnection,
+    invitation_id: i32,
+) -> Result<GroupInvitation, anyhow::Error> {
+    let invitation = match get_invitation_by_id(db, invitation_id).await? {
+        Some(invitation) => invitation,
+        None => return Err(anyhow!("Invitation not found")),
+    };
+
+    if invitation.status != GroupInvitationStatus::Pending {
+        return Err(anyhow!("Invitation is no longer pending"));
+    }
+
+    let invitation =
+        update_invitation_status(db, invitation, GroupInvitationStatus::Revoked).await?;
+
+    Ok(invitation)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter};
+
+    use super::{
+        create_group_invitation, get_user_invitations, respond_to_group_invitation,
+        revoke_group_invitation,
+    };
+    use crate::{
+        auth::{
+            data::{groups::create_group, user::create_user},
+            model::groups::{
+                GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterScope, GroupType,
+                NewGroupDto, NewGroupFilterRuleDto, NewGroupInvitationDto,
+            },
+        },
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+    use entity::sea_orm_active_enums::GroupInvitationStatus;
+
+    #[tokio::test]
+    async fn invitation_respects_group_requirements() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let new_group = |name: &str, filter_rules: Vec<NewGroupFilterRuleDto>| NewGroupDto {
+            group_type: GroupType::Apply,
+            filter_rules,
+            ..new_group_dto(name)
+        };
+
+        let prerequisite = create_group(&db, new_group("Members", vec![])).await?;
+        let group = create_group(
+            &db,
+            new_group(
+                "Capitals",
+                vec![NewGroupFilterRuleDto {
+                    criteria: GroupFilterCriteria::Group,
+                    criteria_type: GroupFilterCriteriaType::Is,
+                    criteria_value: prerequisite.id.to_string(),
+                    scope: GroupFilterScope::AnyCharacter,
+                }],
+            ),
+        )
+        .await?;
+
+        let manager_id = create_user(&db).await?;
+        let user_id = create_user(&db).await?;
+
+        let new_invitation = |expires_in_days: Option<i64>| NewGroupInvitationDto {
+            user_id,
+            message: None,
+            expires_in_days,
+        };
+
+        let invitation =
+            create_group_invitation(&db, group.id, Some(manager_id), new_invitation(Some(7)))
+                .await?;
+
+        let result =
+            create_group_invitation(&db, group.id, Some(manager_id), new_invitation(None)).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Invitation already exists")
+        );
+
+        assert_eq!(get_user_invitations(&db, user_id).await?.len(), 1);
+
+        // Invitations can only be answered by the invited user
+        let result = respond_to_group_invitation(&db, invitation.id, manager_id, true).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Invitation not found")
+        );
+
+        // The user isn't a member of the required group yet
+        let result = respond_to_group_invitation(&db, invitation.id, user_id, true).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("User does not meet group requirements")
+        );
+
+        entity::auth_group_user::ActiveModel {
+            group_id: Set(prerequisite.id),
+            user_id: Set(user_id),
+            created: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+
+        let invitation = respond_to_group_invitation(&db, invitation.id, user_id, true).await?;
+        assert_eq!(invitation.status, GroupInvitationStatus::Accepted);
+        assert!(get_user_invitations(&db, user_id).await?.is_empty());
+
+        let member = entity::prelude::AuthGroupUser::find()
+            .filter(entity::auth_group_user::Column::GroupId.eq(group.id))
+            .filter(entity::auth_group_user::Column::UserId.eq(user_id))
+            .one(&db)
+            .await?;
+        assert_eq!(member.and_then(|member| member.added_by), Some(manager_id));
+
+        let result =
+            create_group_invitation(&db, prerequisite.id, Some(manager_id), new_invitation(None))
+                .await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Already a member")
+        );
+
+        // Expired invitations are hidden & can't be accepted
+        let other_user_id = create_user(&db).await?;
+        let expired = entity::auth_group_invitation::ActiveModel {
+            group_id: Set(group.id),
+            user_id: Set(other_user_id),
+            invited_by: Set(Some(manager_id)),
+            status: Set(GroupInvitationStatus::Pending),
+            expires: Set(Some(Utc::now().naive_utc() - Duration::days(1))),
+            created: Set(Utc::now().naive_utc() - Duration::days(8)),
+            last_updated: Set(Utc::now().naive_utc() - Duration::days(8)),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+
+        assert!(get_user_invitations(&db, other_user_id).await?.is_empty());
+
+        let result = respond_to_group_invitation(&db, expired.id, other_user_id, true).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Invitation has expired")
+        );
+
+        let revoked = revoke_group_invitation(&db, expired.id).await?;
+        assert_eq!(revoked.status, GroupInvitationStatus::Revoked);
+
+        Ok(())
+    }
+}
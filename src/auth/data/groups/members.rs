@@ -1,30 +1,45 @@
-use std::vec;
+use std::{collections::HashSet, vec};
 
 use anyhow::anyhow;
+use chrono::{DateTime, Duration, Utc};
 use migration::OnConflict;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, DeleteResult,
-    EntityTrait, InsertResult, QueryFilter, TryInsertResult,
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, DeleteResult, EntityTrait,
+    InsertResult, QueryFilter, TryInsertResult,
 };
 
 use crate::{
     auth::{
-        data::groups::filters::validate_group_members,
-        model::{groups::GroupApplicationDto, user::UserDto},
+        data::groups::{
+            dependencies::{get_evaluation_order, get_group_graph, GroupGraph},
+            filters::validate_group_members,
+            history::record_membership_changes,
+            questionnaires::{create_application_answers, validate_application_answers},
+        },
+        model::groups::{GroupApplicationDto, GroupMemberDto, NewGroupApplicationAnswerDto},
     },
     eve::data::character::CharacterRepository,
 };
 
-use entity::sea_orm_active_enums::{GroupApplicationStatus, GroupApplicationType, GroupType};
+use entity::sea_orm_active_enums::{
+    GroupApplicationStatus, GroupApplicationType, GroupMembershipAction, GroupMembershipReason,
+    GroupType,
+};
 
-use super::{applications::get_group_application, get_group_by_id};
+use super::{
+    applications::{
+        check_application_cooldown, create_group_application, get_group_application,
+        GroupApplicationFilter,
+    },
+    get_group_by_id,
+};
 
 use crate::auth::data::user::bulk_get_user_main_characters;
 
 pub async fn get_group_members(
     db: &DatabaseConnection,
     group_id: i32,
-) -> Result<Vec<UserDto>, sea_orm::DbErr> {
+) -> Result<Vec<GroupMemberDto>, sea_orm::DbErr> {
     let members = entity::prelude::AuthGroupUser::find()
         .filter(entity::auth_group_user::Column::GroupId.eq(group_id))
         .all(db)
@@ -55,36 +70,97 @@ pub async fn get_group_members(
             ownerships
                 .iter()
                 .find(|&model| model.character_id == character.character_id)
-                .map(|model| model.user_id)
-                .map(|user_id| UserDto {
-                    id: user_id,
+                .and_then(|model| {
+                    members
+                        .iter()
+                        .find(|member| member.user_id == model.user_id)
+                })
+                .map(|member| GroupMemberDto {
+                    id: member.user_id,
                     character_name: character.character_name.clone(),
                     character_id: character.character_id,
+                    created: DateTime::from_naive_utc_and_offset(member.created, Utc),
+                    expires: member
+                        .expires
+                        .map(|expires| DateTime::from_naive_utc_and_offset(expires, Utc)),
+                    added_by: member.added_by,
                 })
         })
-        .collect::<Vec<UserDto>>();
+        .collect::<Vec<GroupMemberDto>>();
 
     Ok(characters)
 }
 
+// Gets which of the given users are direct members of a group
+async fn get_member_ids(
+    db: &DatabaseConnection,
+    group_id: i32,
+    user_ids: Vec<i32>,
+) -> Result<HashSet<i32>, DbErr> {
+    let member_ids = entity::prelude::AuthGroupUser::find()
+        .filter(entity::auth_group_user::Column::GroupId.eq(group_id))
+        .filter(entity::auth_group_user::Column::UserId.is_in(user_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|member| member.user_id)
+        .collect();
+
+    Ok(member_ids)
+}
+
+// Memberships added with a duration are removed by the expiry job once it has passed
 pub async fn add_group_members(
     db: &DatabaseConnection,
     group_id: i32,
     user_ids: Vec<i32>,
+    added_by: Option<i32>,
+    duration: Option<Duration>,
+    reason: GroupMembershipReason,
 ) -> Result<TryInsertResult<InsertResult<entity::auth_group_user::ActiveModel>>, anyhow::Error> {
     let _ = match get_group_by_id(db, group_id).await? {
         Some(group) => group,
         None => return Err(anyhow!("Group does not exist")),
     };
 
+    if duration.is_some_and(|duration| duration <= Duration::zero()) {
+        return Err(anyhow!("Invalid membership duration"));
+    }
+
     let new_member_ids = validate_group_members(db, group_id, user_ids).await?;
 
+    insert_group_members(db, group_id, new_member_ids, added_by, duration, reason).await
+}
+
+// Adds users who have already been validated against the group's filters
+pub async fn insert_group_members(
+    db: &DatabaseConnection,
+    group_id: i32,
+    new_member_ids: Vec<i32>,
+    added_by: Option<i32>,
+    duration: Option<Duration>,
+    reason: GroupMembershipReason,
+) -> Result<TryInsertResult<InsertResult<entity::auth_group_user::ActiveModel>>, anyhow::Error> {
+    let existing_member_ids = get_member_ids(db, group_id, new_member_ids.clone()).await?;
+
+    let created = Utc::now().naive_utc();
+    let expires = match duration {
+        Some(duration) => match created.checked_add_signed(duration) {
+            Some(expires) => Some(expires),
+            None => return Err(anyhow!("Invalid membership duration")),
+        },
+        None => None,
+    };
+
     let new_members: Vec<entity::auth_group_user::ActiveModel> = new_member_ids
         .clone()
         .into_iter()
         .map(|user_id| entity::auth_group_user::ActiveModel {
             group_id: Set(group_id),
             user_id: Set(user_id),
+            created: Set(created),
+            expires: Set(expires),
+            added_by: Set(added_by),
             ..Default::default()
         })
         .collect();
@@ -102,14 +178,210 @@ pub async fn add_group_members(
         .exec(db)
         .await?;
 
+    if let TryInsertResult::Inserted(_) = result {
+        let added_ids: Vec<i32> = new_member_ids
+            .iter()
+            .filter(|user_id| !existing_member_ids.contains(user_id))
+            .copied()
+            .collect();
+
+        record_membership_changes(
+            db,
+            group_id,
+            &added_ids,
+            GroupMembershipAction::Joined,
+            reason,
+            added_by,
+        )
+        .await?;
+
+        let graph = get_group_graph(db).await?;
+
+        reconcile_dependent_groups(db, &graph, vec![group_id], new_member_ids).await?;
+    }
+
     Ok(result)
 }
 
+// Re-evaluates the given users against a group's filters, removing members who are no longer
+// eligible & adding newly eligible users if it is an auto group
+// Returns whether the group's members changed
+async fn reconcile_group(
+    db: &DatabaseConnection,
+    group_id: i32,
+    user_ids: &[i32],
+) -> Result<bool, DbErr> {
+    let group = match get_group_by_id(db, group_id).await? {
+        Some(group) => group,
+        None => return Ok(false),
+    };
+
+    let member_ids = get_member_ids(db, group_id, user_ids.to_vec()).await?;
+
+    let eligible_ids: HashSet<i32> = validate_group_members(db, group_id, user_ids.to_vec())
+        .await?
+        .into_iter()
+        .collect();
+
+    let removed_ids: Vec<i32> = member_ids.difference(&eligible_ids).copied().collect();
+
+    let added_ids: Vec<i32> = if group.group_type == GroupType::Auto {
+        eligible_ids.difference(&member_ids).copied().collect()
+    } else {
+        vec![]
+    };
+
+    if !removed_ids.is_empty() {
+        entity::prelude::AuthGroupUser::delete_many()
+            .filter(entity::auth_group_user::Column::GroupId.eq(group_id))
+            .filter(entity::auth_group_user::Column::UserId.is_in(removed_ids.clone()))
+            .exec(db)
+            .await?;
+
+        record_membership_changes(
+            db,
+            group_id,
+            &removed_ids,
+            GroupMembershipAction::Removed,
+            GroupMembershipReason::Reconciliation,
+            None,
+        )
+        .await?;
+    }
+
+    if !added_ids.is_empty() {
+        let new_members: Vec<entity::auth_group_user::ActiveModel> = added_ids
+            .iter()
+            .map(|user_id| entity::auth_group_user::ActiveModel {
+                group_id: Set(group_id),
+                user_id: Set(*user_id),
+                created: Set(Utc::now().naive_utc()),
+                ..Default::default()
+            })
+            .collect();
+
+        entity::prelude::AuthGroupUser::insert_many(new_members)
+            .on_conflict(
+                OnConflict::columns(vec![
+                    entity::auth_group_user::Column::GroupId,
+                    entity::auth_group_user::Column::UserId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .do_nothing()
+            .exec(db)
+            .await?;
+
+        record_membership_changes(
+            db,
+            group_id,
+            &added_ids,
+            GroupMembershipAction::Joined,
+            GroupMembershipReason::Reconciliation,
+            None,
+        )
+        .await?;
+    }
+
+    Ok(!removed_ids.is_empty() || !added_ids.is_empty())
+}
+
+// Re-evaluates the given users against the given groups, used when data their filters rely on
+// changes, any membership changes are then cascaded to the groups depending on them
+pub async fn reconcile_groups(
+    db: &DatabaseConnection,
+    group_ids: Vec<i32>,
+    user_ids: Vec<i32>,
+) -> Result<(), DbErr> {
+    if user_ids.is_empty() {
+        return Ok(());
+    }
+
+    let mut changed_group_ids = vec![];
+
+    for group_id in group_ids {
+        if reconcile_group(db, group_id, &user_ids).await? {
+            changed_group_ids.push(group_id);
+        }
+    }
+
+    if changed_group_ids.is_empty() {
+        return Ok(());
+    }
+
+    let graph = get_group_graph(db).await?;
+
+    reconcile_dependent_groups(db, &graph, changed_group_ids, user_ids).await
+}
+
+// Re-evaluates the given users against every group whose filters depend on the changed groups,
+// removing members who are no longer eligible & adding newly eligible users to auto groups.
+// Groups are processed in dependency order so a change cascades through chains of groups.
+pub async fn reconcile_dependent_groups(
+    db: &DatabaseConnection,
+    graph: &GroupGraph,
+    changed_group_ids: Vec<i32>,
+    user_ids: Vec<i32>,
+) -> Result<(), DbErr> {
+    if user_ids.is_empty() {
+        return Ok(());
+    }
+
+    let GroupGraph {
+        dependencies,
+        rule_dependencies,
+        inheritance,
+    } = graph;
+
+    // Cycles are rejected when filters are saved, if one exists regardless then
+    // evaluate each group once to avoid reconciling indefinitely
+    let order = get_evaluation_order(dependencies).unwrap_or_else(|_| {
+        let mut group_ids: Vec<i32> = dependencies.keys().copied().collect();
+        group_ids.sort_unstable();
+
+        group_ids
+    });
+
+    let mut changed_group_ids: HashSet<i32> = changed_group_ids.into_iter().collect();
+
+    for group_id in order {
+        let depends_on_changed_group = dependencies
+            .get(&group_id)
+            .is_some_and(|required| !required.is_disjoint(&changed_group_ids));
+
+        if !depends_on_changed_group {
+            continue;
+        }
+
+        // Groups reached only through inheritance have no filters relying on the changed groups,
+        // their direct members stay as they are & the change is only passed along
+        let requires_changed_group = rule_dependencies
+            .get(&group_id)
+            .is_some_and(|required| !required.is_disjoint(&changed_group_ids));
+
+        let members_changed =
+            requires_changed_group && reconcile_group(db, group_id, &user_ids).await?;
+
+        // Memberships inherited from a changed group change the effective members of this group as well
+        let inherits_from_changed_group = inheritance.iter().any(|(id, inheriting_group_ids)| {
+            changed_group_ids.contains(id) && inheriting_group_ids.contains(&group_id)
+        });
+
+        if members_changed || inherits_from_changed_group {
+            changed_group_ids.insert(group_id);
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn join_group(
     db: &DatabaseConnection,
     group_id: i32,
     user_id: i32,
     request_message: Option<String>,
+    answers: Vec<NewGroupApplicationAnswerDto>,
 ) -> Result<Option<GroupApplicationDto>, anyhow::Error> {
     let group = match get_group_by_id(db, group_id).await? {
         Some(group) => group,
@@ -118,7 +390,15 @@ pub async fn join_group(
 
     match group.group_type {
         GroupType::Open | GroupType::Auto => {
-            let result = add_group_members(db, group_id, vec![user_id]).await?;
+            let result = add_group_members(
+                db,
+                group_id,
+                vec![user_id],
+                Some(user_id),
+                None,
+                GroupMembershipReason::Manual,
+            )
+            .await?;
 
             match result {
                 TryInsertResult::Conflicted => Err(anyhow!("Already a member")),
@@ -150,18 +430,31 @@ pub async fn join_group(
                 return Err(anyhow!("Application to join already exists"));
             }
 
-            let application = entity::auth_group_application::ActiveModel {
-                group_id: Set(group_id),
-                user_id: Set(user_id),
-                request_type: Set(GroupApplicationType::Join),
-                request_message: Set(request_message),
-                ..Default::default()
-            };
+            check_application_cooldown(db, &group, user_id).await?;
+
+            let answers = validate_application_answers(db, group_id, answers).await?;
+
+            let application = create_group_application(
+                db,
+                group_id,
+                user_id,
+                GroupApplicationType::Join,
+                request_message,
+            )
+            .await?;
 
-            let application = application.insert(db).await?;
+            create_application_answers(db, application.id, answers).await?;
 
-            let application =
-                get_group_application(db, None, None, Some(application.id), None, None).await?;
+            let application = get_group_application(
+                db,
+                GroupApplicationFilter {
+                    application_id: Some(application.id),
+                    ..Default::default()
+                },
+                None,
+                false,
+            )
+            .await?;
 
             Ok(application.into_iter().next())
         }
@@ -171,33 +464,147 @@ pub async fn delete_group_members(
     db: &DatabaseConnection,
     group_id: i32,
     user_ids: Vec<i32>,
+    removed_by: Option<i32>,
+    reason: GroupMembershipReason,
 ) -> Result<DeleteResult, anyhow::Error> {
     let _ = match get_group_by_id(db, group_id).await? {
         Some(group) => group,
         None => return Err(anyhow!("Group does not exist")),
     };
 
+    let member_ids: Vec<i32> = get_member_ids(db, group_id, user_ids.clone())
+        .await?
+        .into_iter()
+        .collect();
+
     let result = entity::prelude::AuthGroupUser::delete_many()
         .filter(entity::auth_group_user::Column::GroupId.eq(group_id))
-        .filter(entity::auth_group_user::Column::UserId.is_in(user_ids))
+        .filter(entity::auth_group_user::Column::UserId.is_in(member_ids.clone()))
         .exec(db)
         .await?;
 
+    if result.rows_affected > 0 {
+        record_membership_changes(
+            db,
+            group_id,
+            &member_ids,
+            GroupMembershipAction::Removed,
+            reason,
+            removed_by,
+        )
+        .await?;
+
+        let graph = get_group_graph(db).await?;
+
+        reconcile_dependent_groups(db, &graph, vec![group_id], user_ids).await?;
+    }
+
     Ok(result)
 }
 
+pub async fn get_all_member_ids(db: &DatabaseConnection, group_id: i32) -> Result<Vec<i32>, DbErr> {
+    let member_ids = entity::prelude::AuthGroupUser::find()
+        .filter(entity::auth_group_user::Column::GroupId.eq(group_id))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|member| member.user_id)
+        .collect();
+
+    Ok(member_ids)
+}
+
 pub async fn delete_all_group_members(
     db: &DatabaseConnection,
     group_id: i32,
 ) -> Result<DeleteResult, DbErr> {
+    let user_ids = get_all_member_ids(db, group_id).await?;
+
     let result = entity::prelude::AuthGroupUser::delete_many()
         .filter(entity::auth_group_user::Column::GroupId.eq(group_id))
         .exec(db)
         .await?;
 
+    record_membership_changes(
+        db,
+        group_id,
+        &user_ids,
+        GroupMembershipAction::Removed,
+        GroupMembershipReason::GroupDeleted,
+        None,
+    )
+    .await?;
+
+    let graph = get_group_graph(db).await?;
+
+    reconcile_dependent_groups(db, &graph, vec![group_id], user_ids).await?;
+
     Ok(result)
 }
 
+// Removes memberships past their expiry, returns the number of memberships removed
+// Memberships of archived groups are frozen & only expire once the group is restored
+pub async fn remove_expired_members(db: &DatabaseConnection) -> Result<u64, DbErr> {
+    let archived_group_ids: Vec<i32> = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::Archived.is_not_null())
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|group| group.id)
+        .collect();
+
+    let expired = entity::prelude::AuthGroupUser::find()
+        .filter(entity::auth_group_user::Column::Expires.lte(Utc::now().naive_utc()))
+        .filter(entity::auth_group_user::Column::GroupId.is_not_in(archived_group_ids))
+        .all(db)
+        .await?;
+
+    if expired.is_empty() {
+        return Ok(0);
+    }
+
+    let result = entity::prelude::AuthGroupUser::delete_many()
+        .filter(
+            entity::auth_group_user::Column::Id
+                .is_in(expired.iter().map(|member| member.id).collect::<Vec<i32>>()),
+        )
+        .exec(db)
+        .await?;
+
+    let group_ids: HashSet<i32> = expired.iter().map(|member| member.group_id).collect();
+    let user_ids: HashSet<i32> = expired.iter().map(|member| member.user_id).collect();
+
+    for group_id in &group_ids {
+        let expired_user_ids: Vec<i32> = expired
+            .iter()
+            .filter(|member| member.group_id == *group_id)
+            .map(|member| member.user_id)
+            .collect();
+
+        record_membership_changes(
+            db,
+            *group_id,
+            &expired_user_ids,
+            GroupMembershipAction::Removed,
+            GroupMembershipReason::Expiry,
+            None,
+        )
+        .await?;
+    }
+
+    let graph = get_group_graph(db).await?;
+
+    reconcile_dependent_groups(
+        db,
+        &graph,
+        group_ids.into_iter().collect(),
+        user_ids.into_iter().collect(),
+    )
+    .await?;
+
+    Ok(result.rows_affected)
+}
+
 pub async fn leave_group(
     db: &DatabaseConnection,
     group_id: i32,
@@ -237,23 +644,359 @@ pub async fn leave_group(
             return Err(anyhow!("Application to leave already exists"));
         }
 
-        let application = entity::auth_group_application::ActiveModel {
-            group_id: Set(group_id),
-            user_id: Set(user_id),
-            request_type: Set(GroupApplicationType::Leave),
-            request_message: Set(request_message),
-            ..Default::default()
-        };
-
-        let application = application.insert(db).await?;
+        let application = create_group_application(
+            db,
+            group_id,
+            user_id,
+            GroupApplicationType::Leave,
+            request_message,
+        )
+        .await?;
 
-        let application =
-            get_group_application(db, None, None, Some(application.id), None, None).await?;
+        let application = get_group_application(
+            db,
+            GroupApplicationFilter {
+                application_id: Some(application.id),
+                ..Default::default()
+            },
+            None,
+            false,
+        )
+        .await?;
 
         Ok(application.into_iter().next())
     } else {
-        let _ = delete_group_members(db, group_id, vec![user_id]).await?;
+        let _ = delete_group_members(
+            db,
+            group_id,
+            vec![user_id],
+            Some(user_id),
+            GroupMembershipReason::Manual,
+        )
+        .await?;
 
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set, DatabaseConnection, DbErr, EntityTrait};
+
+    use super::{
+        add_group_members, delete_group_members, get_all_member_ids, remove_expired_members,
+    };
+    use crate::{
+        auth::{
+            data::{
+                groups::{create_group, history::get_membership_history},
+                user::{bulk_get_user_groups, create_user},
+            },
+            model::groups::{
+                GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterScope,
+                GroupMembershipAction, GroupMembershipInheritance, GroupMembershipReason,
+                GroupType, NewGroupDto, NewGroupFilterRuleDto,
+            },
+        },
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+
+    // Returns the (group_id, inherited_from) effective memberships of a user, sorted by group_id
+    async fn effective_memberships(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Vec<(i32, Option<i32>)>, DbErr> {
+        let mut memberships: Vec<(i32, Option<i32>)> = bulk_get_user_groups(db, vec![user_id])
+            .await?
+            .into_iter()
+            .flat_map(|user| user.groups)
+            .map(|membership| (membership.group_id, membership.inherited_from))
+            .collect();
+
+        memberships.sort_unstable();
+
+        Ok(memberships)
+    }
+
+    // A group whose filters require membership of another group
+    fn requires_group(name: &str, required_group_id: i32) -> NewGroupDto {
+        NewGroupDto {
+            filter_rules: vec![NewGroupFilterRuleDto {
+                criteria: GroupFilterCriteria::Group,
+                criteria_type: GroupFilterCriteriaType::Is,
+                criteria_value: required_group_id.to_string(),
+                scope: GroupFilterScope::AnyCharacter,
+            }],
+            ..new_group_dto(name)
+        }
+    }
+
+    #[tokio::test]
+    async fn remove_expired_memberships() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_group_dto("Deployment")).await?;
+
+        let now = Utc::now().naive_utc();
+
+        for expires in [
+            Some(now - Duration::days(1)),
+            Some(now + Duration::days(1)),
+            None,
+        ] {
+            let user_id = create_user(&db).await?;
+
+            entity::auth_group_user::ActiveModel {
+                group_id: Set(group.id),
+                user_id: Set(user_id),
+                created: Set(now - Duration::days(30)),
+                expires: Set(expires),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await?;
+        }
+
+        assert_eq!(remove_expired_members(&db).await?, 1);
+
+        let members = entity::prelude::AuthGroupUser::find().all(&db).await?;
+
+        assert_eq!(members.len(), 2);
+        assert!(!members
+            .iter()
+            .any(|member| member.expires.is_some_and(|expires| expires <= now)));
+
+        let history = get_membership_history(&db, Some(group.id), None).await?;
+
+        assert_eq!(history.len(), 1);
+        assert!(history[0].action == GroupMembershipAction::Removed);
+        assert!(history[0].reason == GroupMembershipReason::Expiry);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn removal_cascades_through_dependent_groups() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group_a = create_group(&db, new_group_dto("A")).await?;
+        let group_b = create_group(&db, requires_group("B", group_a.id)).await?;
+        let group_c = create_group(&db, requires_group("C", group_b.id)).await?;
+
+        let user_id = create_user(&db).await?;
+        let other_user_id = create_user(&db).await?;
+
+        for group_id in [group_a.id, group_b.id, group_c.id] {
+            add_group_members(
+                &db,
+                group_id,
+                vec![user_id, other_user_id],
+                None,
+                None,
+                entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+            )
+            .await?;
+
+            assert_eq!(get_all_member_ids(&db, group_id).await?.len(), 2);
+        }
+
+        delete_group_members(
+            &db,
+            group_a.id,
+            vec![user_id],
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        // B requires A & C requires B, both are revoked along with A
+        for group_id in [group_a.id, group_b.id, group_c.id] {
+            assert_eq!(
+                get_all_member_ids(&db, group_id).await?,
+                vec![other_user_id]
+            );
+        }
+
+        for group_id in [group_b.id, group_c.id] {
+            let history = get_membership_history(&db, Some(group_id), Some(user_id)).await?;
+
+            assert!(history.iter().any(|entry| {
+                entry.action == GroupMembershipAction::Removed
+                    && entry.reason == GroupMembershipReason::Reconciliation
+            }));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn child_to_parent_inheritance() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let parent = create_group(&db, new_group_dto("Parent")).await?;
+        let child = create_group(
+            &db,
+            NewGroupDto {
+                parent_id: Some(parent.id),
+                membership_inheritance: GroupMembershipInheritance::ChildToParent,
+                ..new_group_dto("Child")
+            },
+        )
+        .await?;
+
+        let user_id = create_user(&db).await?;
+
+        add_group_members(
+            &db,
+            child.id,
+            vec![user_id],
+            None,
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        assert_eq!(
+            effective_memberships(&db, user_id).await?,
+            vec![(parent.id, Some(child.id)), (child.id, None)]
+        );
+
+        delete_group_members(
+            &db,
+            child.id,
+            vec![user_id],
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        assert!(effective_memberships(&db, user_id).await?.is_empty());
+
+        // Joining the parent directly doesn't grant the subgroup
+        add_group_members(
+            &db,
+            parent.id,
+            vec![user_id],
+            None,
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        assert_eq!(
+            effective_memberships(&db, user_id).await?,
+            vec![(parent.id, None)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parent_to_child_inheritance() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let parent = create_group(&db, new_group_dto("Parent")).await?;
+        let child = create_group(
+            &db,
+            NewGroupDto {
+                parent_id: Some(parent.id),
+                membership_inheritance: GroupMembershipInheritance::ParentToChild,
+                ..new_group_dto("Child")
+            },
+        )
+        .await?;
+
+        let user_id = create_user(&db).await?;
+
+        add_group_members(
+            &db,
+            parent.id,
+            vec![user_id],
+            None,
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        assert_eq!(
+            effective_memberships(&db, user_id).await?,
+            vec![(parent.id, None), (child.id, Some(parent.id))]
+        );
+
+        delete_group_members(
+            &db,
+            parent.id,
+            vec![user_id],
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        assert!(effective_memberships(&db, user_id).await?.is_empty());
+
+        // Joining the subgroup directly doesn't grant the parent
+        add_group_members(
+            &db,
+            child.id,
+            vec![user_id],
+            None,
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        assert_eq!(
+            effective_memberships(&db, user_id).await?,
+            vec![(child.id, None)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn auto_subgroup_gains_no_inherited_members() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let parent = create_group(&db, new_group_dto("Parent")).await?;
+        let child = create_group(
+            &db,
+            NewGroupDto {
+                group_type: GroupType::Auto,
+                parent_id: Some(parent.id),
+                membership_inheritance: GroupMembershipInheritance::ParentToChild,
+                ..new_group_dto("Child")
+            },
+        )
+        .await?;
+
+        let user_id = create_user(&db).await?;
+
+        add_group_members(
+            &db,
+            parent.id,
+            vec![user_id],
+            None,
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        // The subgroup has no filters, its membership is only inherited
+        assert!(get_all_member_ids(&db, child.id).await?.is_empty());
+
+        delete_group_members(
+            &db,
+            parent.id,
+            vec![user_id],
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        assert!(get_all_member_ids(&db, child.id).await?.is_empty());
+        assert!(effective_memberships(&db, user_id).await?.is_empty());
+
+        Ok(())
+    }
+}
@@ -1,18 +1,37 @@
 pub mod applications;
+pub mod categories;
+pub mod dependencies;
 pub mod filters;
+pub mod history;
+pub mod invitations;
 pub mod members;
-
-use std::vec;
+pub mod owners;
+pub mod questionnaires;
+pub mod templates;
+pub mod visibility;
+
+use std::{
+    collections::{HashMap, HashSet},
+    vec,
+};
 
 use anyhow::anyhow;
+use chrono::{DateTime, Duration, Utc};
 use eve_esi::alliance::get_alliance;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
-    PaginatorTrait, QueryFilter,
+    PaginatorTrait, QueryFilter, QueryOrder,
 };
 
 use crate::{
-    auth::model::groups::{GroupDto, GroupOwnerInfo, GroupOwnerType, NewGroupDto, UpdateGroupDto},
+    auth::{
+        data::organizations::get_organization_by_id,
+        model::groups::{
+            ArchivedGroupDto, CloneGroupDto, GroupApplicationSettingsDto, GroupCategoryDto,
+            GroupDto, GroupOwnerInfo, GroupOwnerType, GroupQuestionDto, NewGroupDto,
+            UpdateGroupDto,
+        },
+    },
     eve::{
         data::alliance::AllianceRepository,
         service::{alliance::get_or_create_alliance, corporation::get_or_create_corporation},
@@ -24,11 +43,17 @@ use entity::auth_group::Model as Group;
 use filters::validate_group_filters;
 
 use self::{
+    applications::update_application_settings,
+    categories::get_category_by_id,
+    dependencies::get_group_graph,
     filters::{
         bulk_create_filter_rules, create_filter_groups, delete_filter_groups, delete_filter_rules,
-        update_filter_groups, update_filter_rules,
+        get_group_filters, update_filter_groups, update_filter_rules,
     },
-    members::delete_all_group_members,
+    members::{
+        delete_all_group_members, get_all_member_ids, reconcile_dependent_groups, reconcile_groups,
+    },
+    questionnaires::{get_group_questions, set_group_questions},
 };
 
 async fn validate_group_owner(
@@ -48,6 +73,64 @@ async fn validate_group_owner(
                 get_or_create_corporation(db, corporation_id).await?;
             }
         }
+        GroupOwnerType::Organization => {
+            if let Some(organization_id) = owner_id {
+                if get_organization_by_id(db, organization_id).await?.is_none() {
+                    return Err(anyhow!("Organization not found: {}", organization_id));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Checks the parent group exists & the group isn't nested under one of its own subgroups
+async fn validate_group_parent(
+    db: &DatabaseConnection,
+    group_id: Option<i32>,
+    parent_id: Option<i32>,
+) -> Result<(), anyhow::Error> {
+    let mut parent_id = match parent_id {
+        Some(parent_id) => parent_id,
+        None => return Ok(()),
+    };
+
+    if get_group_by_id(db, parent_id).await?.is_none() {
+        return Err(anyhow!("Parent group not found: {}", parent_id));
+    }
+
+    let mut visited = HashSet::new();
+
+    loop {
+        if Some(parent_id) == group_id {
+            return Err(anyhow!(
+                "Invalid parent group, a group can't be nested under itself or one of its subgroups"
+            ));
+        }
+
+        if !visited.insert(parent_id) {
+            return Ok(());
+        }
+
+        match get_group_by_id(db, parent_id)
+            .await?
+            .and_then(|group| group.parent_id)
+        {
+            Some(id) => parent_id = id,
+            None => return Ok(()),
+        }
+    }
+}
+
+async fn validate_group_category(
+    db: &DatabaseConnection,
+    category_id: Option<i32>,
+) -> Result<(), anyhow::Error> {
+    if let Some(category_id) = category_id {
+        if get_category_by_id(db, category_id).await?.is_none() {
+            return Err(anyhow!("Category not found: {}", category_id));
+        }
     }
 
     Ok(())
@@ -57,7 +140,10 @@ pub async fn create_group(
     db: &DatabaseConnection,
     new_group: NewGroupDto,
 ) -> Result<Group, anyhow::Error> {
-    match validate_group_filters(db, &new_group).await {
+    validate_group_parent(db, None, new_group.parent_id).await?;
+    validate_group_category(db, new_group.category_id).await?;
+
+    match validate_group_filters(db, None, &new_group).await {
         Ok(_) => (),
         Err(err) => return Err(err),
     }
@@ -79,6 +165,9 @@ pub async fn create_group(
         owner_id: Set(owner_id),
         group_type: Set(new_group.group_type.into()),
         filter_type: Set(new_group.filter_type.into()),
+        parent_id: Set(new_group.parent_id),
+        membership_inheritance: Set(new_group.membership_inheritance.into()),
+        category_id: Set(new_group.category_id),
         ..Default::default()
     };
 
@@ -92,30 +181,160 @@ pub async fn create_group(
     Ok(group)
 }
 
+// Copies the group's settings, filters & application questionnaire into a new group, members aren't copied
+// and are instead added by the new group's own filters & applications
+pub async fn clone_group(
+    db: &DatabaseConnection,
+    group_id: i32,
+    overrides: CloneGroupDto,
+) -> Result<Option<Group>, anyhow::Error> {
+    let group = match get_group_by_id(db, group_id).await? {
+        Some(group) => group,
+        None => return Ok(None),
+    };
+
+    let filters = match get_group_filters(db, group_id).await? {
+        Some(filters) => filters,
+        None => return Ok(None),
+    };
+
+    let application_settings = GroupApplicationSettingsDto::from(group.clone());
+
+    let (owner_type, owner_id) = match overrides.owner_type {
+        Some(owner_type) => (owner_type, overrides.owner_id),
+        None => (group.owner_type.into(), group.owner_id),
+    };
+
+    let new_group = NewGroupDto {
+        name: overrides.name.unwrap_or(group.name),
+        description: group.description,
+        confidential: group.confidential,
+        leave_applications: group.leave_applications,
+        owner_type,
+        owner_id,
+        group_type: group.group_type.into(),
+        parent_id: group.parent_id,
+        membership_inheritance: group.membership_inheritance.into(),
+        category_id: group.category_id,
+        filter_type: filters.filter_type,
+        filter_rules: filters
+            .filter_rules
+            .into_iter()
+            .map(|rule| rule.into())
+            .collect(),
+        filter_groups: filters
+            .filter_groups
+            .into_iter()
+            .map(|group| group.into())
+            .collect(),
+    };
+
+    let group = create_group(db, new_group).await?;
+
+    let questions = get_group_questions(db, group_id)
+        .await?
+        .into_iter()
+        .map(|question| GroupQuestionDto::from(question).into())
+        .collect();
+
+    set_group_questions(db, group.id, questions).await?;
+
+    let group = update_application_settings(db, group.id, application_settings)
+        .await?
+        .unwrap_or(group);
+
+    Ok(Some(group))
+}
+
+// Archived groups are treated as if they don't exist
 pub async fn get_group_by_id(db: &DatabaseConnection, id: i32) -> Result<Option<Group>, DbErr> {
     entity::prelude::AuthGroup::find()
         .filter(entity::auth_group::Column::Id.eq(id))
+        .filter(entity::auth_group::Column::Archived.is_null())
         .one(db)
         .await
 }
 
+pub async fn get_archived_group_by_id(
+    db: &DatabaseConnection,
+    id: i32,
+) -> Result<Option<Group>, DbErr> {
+    entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::Id.eq(id))
+        .filter(entity::auth_group::Column::Archived.is_not_null())
+        .one(db)
+        .await
+}
+
+pub async fn get_archived_groups(
+    db: &DatabaseConnection,
+    // Set None to get all archived groups
+    groups: Option<Vec<i32>>,
+) -> Result<Vec<ArchivedGroupDto>, DbErr> {
+    let mut query = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::Archived.is_not_null());
+
+    if let Some(groups) = groups {
+        query = query.filter(entity::auth_group::Column::Id.is_in(groups))
+    }
+
+    let groups = query
+        .order_by_desc(entity::auth_group::Column::Archived)
+        .all(db)
+        .await?;
+
+    Ok(groups
+        .into_iter()
+        .filter_map(|group| {
+            group.archived.map(|archived| ArchivedGroupDto {
+                id: group.id,
+                name: group.name,
+                description: group.description,
+                owner_type: group.owner_type.into(),
+                owner_id: group.owner_id,
+                archived: DateTime::from_naive_utc_and_offset(archived, Utc),
+            })
+        })
+        .collect())
+}
+
 pub async fn get_group_dto(
     db: &DatabaseConnection,
     // Set None to get all groups
     groups: Option<Vec<i32>>,
+    // Set None to include groups of every category
+    category_id: Option<i32>,
 ) -> Result<Vec<GroupDto>, anyhow::Error> {
     use entity::sea_orm_active_enums::GroupOwnerType;
 
     let mut group_results = vec![];
 
-    let mut query = entity::prelude::AuthGroup::find();
+    let mut query =
+        entity::prelude::AuthGroup::find().filter(entity::auth_group::Column::Archived.is_null());
 
     if let Some(groups) = groups {
         query = query.filter(entity::auth_group::Column::Id.is_in(groups))
     }
 
+    if let Some(category_id) = category_id {
+        query = query.filter(entity::auth_group::Column::CategoryId.eq(category_id))
+    }
+
     let groups = query.all(db).await?;
 
+    let category_ids: HashSet<i32> = groups
+        .iter()
+        .filter_map(|group| group.category_id)
+        .collect();
+
+    let categories: HashMap<i32, GroupCategoryDto> = entity::prelude::AuthGroupCategory::find()
+        .filter(entity::auth_group_category::Column::Id.is_in(category_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|category| (category.id, category.into()))
+        .collect();
+
     for group in groups {
         let owner_info: Option<GroupOwnerInfo> = match &group.owner_type {
             GroupOwnerType::Auth => None,
@@ -157,19 +376,31 @@ pub async fn get_group_dto(
                     return Err(anyhow!("Group owner_id for owner type corporation is None"));
                 }
             }
+            GroupOwnerType::Organization => {
+                if let Some(owner_id) = group.owner_id {
+                    match get_organization_by_id(db, owner_id).await? {
+                        Some(organization) => Some(GroupOwnerInfo {
+                            id: organization.id,
+                            name: organization.name,
+                        }),
+                        None => {
+                            return Err(anyhow!("Group owner organization {} not found", owner_id))
+                        }
+                    }
+                } else {
+                    return Err(anyhow!(
+                        "Group owner_id for owner type organization is None"
+                    ));
+                }
+            }
         };
 
         // TODO: move this out of for loop & get the count for all groups at once
-        let member_count = if !group.confidential {
-            let member_count = entity::prelude::AuthGroupUser::find()
-                .filter(entity::auth_group_user::Column::GroupId.eq(group.id))
-                .count(db)
-                .await?;
-
-            Some(member_count)
-        } else {
-            None
-        };
+        // Member counts of confidential groups are hidden from ordinary users by the visibility checks
+        let member_count = entity::prelude::AuthGroupUser::find()
+            .filter(entity::auth_group_user::Column::GroupId.eq(group.id))
+            .count(db)
+            .await?;
 
         let group = GroupDto {
             id: group.id,
@@ -178,7 +409,12 @@ pub async fn get_group_dto(
             group_type: group.group_type.into(),
             owner_type: group.owner_type.into(),
             owner_info,
-            member_count,
+            member_count: Some(member_count),
+            parent_id: group.parent_id,
+            membership_inheritance: group.membership_inheritance.into(),
+            category: group
+                .category_id
+                .and_then(|category_id| categories.get(&category_id).cloned()),
         };
 
         group_results.push(group);
@@ -192,7 +428,10 @@ pub async fn update_group(
     group_id: i32,
     group: UpdateGroupDto,
 ) -> Result<Group, anyhow::Error> {
-    match validate_group_filters(db, &group.clone().into()).await {
+    validate_group_parent(db, Some(group_id), group.parent_id).await?;
+    validate_group_category(db, group.category_id).await?;
+
+    match validate_group_filters(db, Some(group_id), &group.clone().into()).await {
         Ok(_) => (),
         Err(err) => {
             if err.is::<sea_orm::DbErr>() {
@@ -205,6 +444,8 @@ pub async fn update_group(
 
     validate_group_owner(db, &group.owner_type, group.owner_id).await?;
 
+    let stored_group = get_group_by_id(db, group_id).await?;
+
     let owner_id: Option<i32> = if group.owner_type == GroupOwnerType::Auth {
         None
     } else {
@@ -221,6 +462,10 @@ pub async fn update_group(
         owner_id: Set(owner_id),
         group_type: Set(group.group_type.into()),
         filter_type: Set(group.filter_type.into()),
+        parent_id: Set(group.parent_id),
+        membership_inheritance: Set(group.membership_inheritance.into()),
+        category_id: Set(group.category_id),
+        ..Default::default()
     };
 
     let updated_group = updated_group.update(db).await?;
@@ -228,11 +473,87 @@ pub async fn update_group(
     update_filter_groups(db, group_id, group.filter_groups).await?;
     update_filter_rules(db, group_id, None, group.filter_rules).await?;
 
+    // Moving the group or changing its inheritance changes effective memberships, re-evaluate the
+    // groups depending on the group & its old & new parent
+    if let Some(stored_group) = stored_group {
+        if stored_group.parent_id != updated_group.parent_id
+            || stored_group.membership_inheritance != updated_group.membership_inheritance
+        {
+            let mut changed_group_ids = vec![group_id];
+            changed_group_ids.extend(stored_group.parent_id);
+            changed_group_ids.extend(updated_group.parent_id);
+            changed_group_ids.dedup();
+
+            let mut member_ids = vec![];
+
+            for changed_group_id in &changed_group_ids {
+                member_ids.extend(get_all_member_ids(db, *changed_group_id).await?);
+            }
+
+            member_ids.sort_unstable();
+            member_ids.dedup();
+
+            let graph = get_group_graph(db).await?;
+
+            reconcile_dependent_groups(db, &graph, changed_group_ids, member_ids).await?;
+        }
+    }
+
     // Queue update group members task
 
     Ok(updated_group)
 }
 
+// Archived groups are hidden & their membership frozen, the group's members no longer count
+// towards groups depending on it until the group is restored
+pub async fn archive_group(db: &DatabaseConnection, group_id: i32) -> Result<Option<i32>, DbErr> {
+    if get_group_by_id(db, group_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    entity::auth_group::ActiveModel {
+        id: Set(group_id),
+        archived: Set(Some(Utc::now().naive_utc())),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+
+    let member_ids = get_all_member_ids(db, group_id).await?;
+    let graph = get_group_graph(db).await?;
+
+    reconcile_dependent_groups(db, &graph, vec![group_id], member_ids).await?;
+
+    Ok(Some(group_id))
+}
+
+// Restores an archived group along with its filters & members, members who no longer meet the
+// group's requirements are removed
+pub async fn restore_group(db: &DatabaseConnection, group_id: i32) -> Result<Option<Group>, DbErr> {
+    if get_archived_group_by_id(db, group_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    let group = entity::auth_group::ActiveModel {
+        id: Set(group_id),
+        archived: Set(None),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+
+    let member_ids = get_all_member_ids(db, group_id).await?;
+
+    reconcile_groups(db, vec![group_id], member_ids.clone()).await?;
+
+    let graph = get_group_graph(db).await?;
+
+    reconcile_dependent_groups(db, &graph, vec![group_id], member_ids).await?;
+
+    Ok(Some(group))
+}
+
+// Permanently deletes a group along with its filters, members & applications
 pub async fn delete_group(db: &DatabaseConnection, group_id: i32) -> Result<Option<i32>, DbErr> {
     let group = entity::auth_group::ActiveModel {
         id: Set(group_id),
@@ -243,6 +564,16 @@ pub async fn delete_group(db: &DatabaseConnection, group_id: i32) -> Result<Opti
     let _ = delete_filter_groups(db, group_id).await?;
     let _ = delete_all_group_members(db, group_id).await?;
 
+    entity::prelude::AuthGroupApplication::delete_many()
+        .filter(entity::auth_group_application::Column::GroupId.eq(group_id))
+        .exec(db)
+        .await?;
+
+    entity::prelude::AuthGroupInvitation::delete_many()
+        .filter(entity::auth_group_invitation::Column::GroupId.eq(group_id))
+        .exec(db)
+        .await?;
+
     let result = entity::prelude::AuthGroup::delete(group).exec(db).await?;
 
     if result.rows_affected == 1 {
@@ -251,3 +582,259 @@ pub async fn delete_group(db: &DatabaseConnection, group_id: i32) -> Result<Opti
         Ok(None)
     }
 }
+
+// Permanently deletes groups which have been archived for longer than the retention period
+pub async fn purge_archived_groups(
+    db: &DatabaseConnection,
+    retention: Duration,
+) -> Result<u64, DbErr> {
+    let groups = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::Archived.lte(Utc::now().naive_utc() - retention))
+        .all(db)
+        .await?;
+
+    let mut purged = 0;
+
+    for group in groups {
+        if delete_group(db, group.id).await?.is_some() {
+            purged += 1;
+        }
+    }
+
+    Ok(purged)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set, EntityTrait};
+
+    use super::{
+        applications::update_application_settings,
+        archive_group, clone_group, create_group,
+        filters::get_group_filters,
+        get_group_by_id, purge_archived_groups,
+        questionnaires::{get_group_questions, set_group_questions},
+        restore_group, update_group,
+    };
+    use crate::{
+        auth::{
+            data::{
+                groups::members::{add_group_members, get_all_member_ids},
+                user::{bulk_get_user_groups, create_user},
+            },
+            model::groups::{
+                CloneGroupDto, GroupApplicationSettingsDto, GroupFilterCriteria,
+                GroupFilterCriteriaType, GroupFilterScope, GroupFilterType,
+                GroupMembershipInheritance, GroupOwnerType, GroupQuestionType, GroupType,
+                NewGroupDto, NewGroupFilterGroupDto, NewGroupFilterRuleDto, NewGroupQuestionDto,
+                UpdateGroupDto,
+            },
+        },
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+
+    #[tokio::test]
+    async fn archive_restore_and_purge_group() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_group_dto("Capital Pings")).await?;
+
+        let user_id = create_user(&db).await?;
+        entity::auth_group_user::ActiveModel {
+            group_id: Set(group.id),
+            user_id: Set(user_id),
+            created: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+
+        let get_user_group_count = || async {
+            bulk_get_user_groups(&db, vec![user_id])
+                .await
+                .map(|user_groups| user_groups.into_iter().flat_map(|user| user.groups).count())
+        };
+
+        assert_eq!(archive_group(&db, group.id).await?, Some(group.id));
+        assert!(get_group_by_id(&db, group.id).await?.is_none());
+        assert_eq!(get_user_group_count().await?, 0);
+
+        // Memberships are frozen rather than removed while archived
+        assert!(restore_group(&db, group.id).await?.is_some());
+        assert!(get_group_by_id(&db, group.id).await?.is_some());
+        assert_eq!(get_user_group_count().await?, 1);
+
+        // Groups are only purged once archived for longer than the retention period
+        archive_group(&db, group.id).await?;
+
+        assert_eq!(purge_archived_groups(&db, Duration::days(1)).await?, 0);
+        assert_eq!(purge_archived_groups(&db, Duration::zero()).await?, 1);
+        assert!(entity::prelude::AuthGroup::find_by_id(group.id)
+            .one(&db)
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn moving_group_reconciles_dependent_groups() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let parent = create_group(&db, new_group_dto("Fleet")).await?;
+        let dependent = create_group(
+            &db,
+            NewGroupDto {
+                group_type: GroupType::Auto,
+                filter_rules: vec![NewGroupFilterRuleDto {
+                    criteria: GroupFilterCriteria::Group,
+                    criteria_type: GroupFilterCriteriaType::Is,
+                    criteria_value: parent.id.to_string(),
+                    scope: GroupFilterScope::AnyCharacter,
+                }],
+                ..new_group_dto("Fleet Comms")
+            },
+        )
+        .await?;
+        let group = create_group(&db, new_group_dto("Scouts")).await?;
+
+        let user_id = create_user(&db).await?;
+
+        add_group_members(
+            &db,
+            group.id,
+            vec![user_id],
+            None,
+            None,
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual,
+        )
+        .await?;
+
+        assert!(get_all_member_ids(&db, dependent.id).await?.is_empty());
+
+        // Members of the scouts now inherit membership of the fleet, granting the fleet comms
+        update_group(
+            &db,
+            group.id,
+            UpdateGroupDto {
+                name: group.name,
+                description: None,
+                confidential: false,
+                leave_applications: false,
+                owner_type: GroupOwnerType::Auth,
+                owner_id: None,
+                group_type: GroupType::Open,
+                parent_id: Some(parent.id),
+                membership_inheritance: GroupMembershipInheritance::ChildToParent,
+                category_id: None,
+                filter_type: GroupFilterType::All,
+                filter_rules: vec![],
+                filter_groups: vec![],
+            },
+        )
+        .await?;
+
+        assert_eq!(get_all_member_ids(&db, dependent.id).await?, vec![user_id]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn clone_group_with_overrides() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let rule = |criteria: GroupFilterCriteria, criteria_value: &str| NewGroupFilterRuleDto {
+            criteria,
+            criteria_type: GroupFilterCriteriaType::Is,
+            criteria_value: criteria_value.to_string(),
+            scope: GroupFilterScope::AnyCharacter,
+        };
+
+        let group = create_group(
+            &db,
+            NewGroupDto {
+                group_type: GroupType::Apply,
+                filter_rules: vec![rule(GroupFilterCriteria::Corporation, "109299958")],
+                filter_groups: vec![NewGroupFilterGroupDto {
+                    filter_type: GroupFilterType::Any,
+                    rules: vec![rule(GroupFilterCriteria::CorporationTitle, "Recruiter")],
+                    filter_groups: vec![NewGroupFilterGroupDto {
+                        filter_type: GroupFilterType::All,
+                        rules: vec![rule(GroupFilterCriteria::CorporationRole, "Director")],
+                        filter_groups: vec![],
+                    }],
+                }],
+                ..new_group_dto("Recruiters")
+            },
+        )
+        .await?;
+
+        set_group_questions(
+            &db,
+            group.id,
+            vec![NewGroupQuestionDto {
+                id: None,
+                question: "Why do you want to recruit?".to_string(),
+                question_type: GroupQuestionType::Text,
+                choices: vec![],
+                required: true,
+                ordering: 0,
+            }],
+        )
+        .await?;
+
+        update_application_settings(
+            &db,
+            group.id,
+            GroupApplicationSettingsDto {
+                application_expiry_days: Some(14),
+                application_cooldown_days: Some(30),
+            },
+        )
+        .await?;
+
+        let clone = clone_group(
+            &db,
+            group.id,
+            CloneGroupDto {
+                name: Some("Alliance Recruiters".to_string()),
+                owner_type: Some(GroupOwnerType::Alliance),
+                owner_id: Some(434243723),
+            },
+        )
+        .await?
+        .unwrap();
+
+        assert_ne!(clone.id, group.id);
+        assert_eq!(clone.name, "Alliance Recruiters");
+        assert!(GroupOwnerType::from(clone.owner_type) == GroupOwnerType::Alliance);
+        assert_eq!(clone.owner_id, Some(434243723));
+        assert_eq!(clone.application_expiry_days, Some(14));
+        assert_eq!(clone.application_cooldown_days, Some(30));
+
+        let filters = get_group_filters(&db, clone.id).await?.unwrap();
+
+        assert_eq!(filters.filter_rules.len(), 1);
+        assert_eq!(filters.filter_rules[0].criteria_value, "109299958");
+        assert_eq!(filters.filter_groups.len(), 1);
+        assert_eq!(
+            filters.filter_groups[0].rules[0].criteria_value,
+            "Recruiter"
+        );
+        assert_eq!(
+            filters.filter_groups[0].filter_groups[0].rules[0].criteria_value,
+            "Director"
+        );
+
+        let questions = get_group_questions(&db, clone.id).await?;
+
+        assert_eq!(questions.len(), 1);
+        assert_eq!(questions[0].question, "Why do you want to recruit?");
+
+        // The source group is left as it was
+        assert_eq!(get_group_questions(&db, group.id).await?.len(), 1);
+
+        Ok(())
+    }
+}
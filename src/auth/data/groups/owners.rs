@@ -0,0 +1,293 @@
+use anyhow::anyhow;
+use migration::OnConflict;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+};
+
+use crate::auth::{
+    data::user::{get_user, get_user_character_ownerships},
+    model::groups::{GroupOwnerDto, GroupOwnerType},
+};
+
+// Gets the corporations a user is the CEO of & the alliances whose executor corporation they're the CEO of
+async fn get_led_owners(
+    db: &DatabaseConnection,
+    user_id: i32,
+) -> Result<Vec<GroupOwnerDto>, DbErr> {
+    let character_ids: Vec<i32> = get_user_character_ownerships(db, user_id)
+        .await?
+        .into_iter()
+        .map(|ownership| ownership.character_id)
+        .collect();
+
+    let corporation_ids: Vec<i32> = entity::prelude::EveCorporation::find()
+        .filter(entity::eve_corporation::Column::Ceo.is_in(character_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|corporation| corporation.corporation_id)
+        .collect();
+
+    let alliance_ids: Vec<i32> = entity::prelude::EveAlliance::find()
+        .filter(entity::eve_alliance::Column::Executor.is_in(corporation_ids.clone()))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|alliance| alliance.alliance_id)
+        .collect();
+
+    let corporations = corporation_ids.into_iter().map(|id| GroupOwnerDto {
+        owner_type: GroupOwnerType::Corporation,
+        owner_id: id,
+        leader: true,
+    });
+
+    let alliances = alliance_ids.into_iter().map(|id| GroupOwnerDto {
+        owner_type: GroupOwnerType::Alliance,
+        owner_id: id,
+        leader: true,
+    });
+
+    Ok(corporations.chain(alliances).collect())
+}
+
+// Gets the corporations & alliances whose groups a user can manage, either as their leader or
+// as a delegate named by the leader
+pub async fn get_managed_owners(
+    db: &DatabaseConnection,
+    user_id: i32,
+) -> Result<Vec<GroupOwnerDto>, DbErr> {
+    let mut owners = get_led_owners(db, user_id).await?;
+
+    let delegations = entity::prelude::AuthGroupOwnerDelegate::find()
+        .filter(entity::auth_group_owner_delegate::Column::UserId.eq(user_id))
+        .all(db)
+        .await?;
+
+    for delegation in delegations {
+        let owner_type: GroupOwnerType = delegation.owner_type.into();
+
+        let already_led = owners
+            .iter()
+            .any(|owner| owner.owner_type == owner_type && owner.owner_id == delegation.owner_id);
+
+        if !already_led {
+            owners.push(GroupOwnerDto {
+                owner_type,
+                owner_id: delegation.owner_id,
+                leader: false,
+            });
+        }
+    }
+
+    Ok(owners)
+}
+
+pub async fn is_owner_leader(
+    db: &DatabaseConnection,
+    user_id: i32,
+    owner_type: &GroupOwnerType,
+    owner_id: i32,
+) -> Result<bool, DbErr> {
+    let owners = get_led_owners(db, user_id).await?;
+
+    Ok(owners
+        .iter()
+        .any(|owner| &owner.owner_type == owner_type && owner.owner_id == owner_id))
+}
+
+// Groups owned by auth or an organization can only be managed by admins
+pub async fn can_manage_owner(
+    db: &DatabaseConnection,
+    user_id: i32,
+    owner_type: &GroupOwnerType,
+    owner_id: Option<i32>,
+) -> Result<bool, DbErr> {
+    let owner_id = match (owner_type, owner_id) {
+        (GroupOwnerType::Corporation | GroupOwnerType::Alliance, Some(owner_id)) => owner_id,
+        _ => return Ok(false),
+    };
+
+    let owners = get_managed_owners(db, user_id).await?;
+
+    Ok(owners
+        .iter()
+        .any(|owner| &owner.owner_type == owner_type && owner.owner_id == owner_id))
+}
+
+// Gets the ids of the groups owned by any of the given owners
+pub async fn get_owned_group_ids(
+    db: &DatabaseConnection,
+    owners: &[GroupOwnerDto],
+) -> Result<Vec<i32>, DbErr> {
+    if owners.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut condition = Condition::any();
+
+    for owner in owners {
+        let owner_type: entity::sea_orm_active_enums::GroupOwnerType =
+            owner.owner_type.clone().into();
+
+        condition = condition.add(
+            Condition::all()
+                .add(entity::auth_group::Column::OwnerType.eq(owner_type))
+                .add(entity::auth_group::Column::OwnerId.eq(owner.owner_id)),
+        );
+    }
+
+    let groups = entity::prelude::AuthGroup::find()
+        .filter(condition)
+        .all(db)
+        .await?;
+
+    Ok(groups.into_iter().map(|group| group.id).collect())
+}
+
+pub async fn get_owner_delegates(
+    db: &DatabaseConnection,
+    owner_type: GroupOwnerType,
+    owner_id: i32,
+) -> Result<Vec<i32>, DbErr> {
+    let owner_type: entity::sea_orm_active_enums::GroupOwnerType = owner_type.into();
+
+    let delegates = entity::prelude::AuthGroupOwnerDelegate::find()
+        .filter(entity::auth_group_owner_delegate::Column::OwnerType.eq(owner_type))
+        .filter(entity::auth_group_owner_delegate::Column::OwnerId.eq(owner_id))
+        .all(db)
+        .await?;
+
+    Ok(delegates
+        .into_iter()
+        .map(|delegate| delegate.user_id)
+        .collect())
+}
+
+pub async fn add_owner_delegate(
+    db: &DatabaseConnection,
+    owner_type: GroupOwnerType,
+    owner_id: i32,
+    user_id: i32,
+) -> Result<(), anyhow::Error> {
+    if owner_type != GroupOwnerType::Corporation && owner_type != GroupOwnerType::Alliance {
+        return Err(anyhow!(
+            "Delegates can only be named for corporation or alliance owned groups"
+        ));
+    }
+
+    if get_user(db, user_id).await?.is_none() {
+        return Err(anyhow!("User does not exist"));
+    }
+
+    let delegate = entity::auth_group_owner_delegate::ActiveModel {
+        owner_type: Set(owner_type.into()),
+        owner_id: Set(owner_id),
+        user_id: Set(user_id),
+        ..Default::default()
+    };
+
+    entity::prelude::AuthGroupOwnerDelegate::insert(delegate)
+        .on_conflict(
+            OnConflict::columns(vec![
+                entity::auth_group_owner_delegate::Column::OwnerType,
+                entity::auth_group_owner_delegate::Column::OwnerId,
+                entity::auth_group_owner_delegate::Column::UserId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .do_nothing()
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn delete_owner_delegate(
+    db: &DatabaseConnection,
+    owner_type: GroupOwnerType,
+    owner_id: i32,
+    user_id: i32,
+) -> Result<Option<i32>, DbErr> {
+    let owner_type: entity::sea_orm_active_enums::GroupOwnerType = owner_type.into();
+
+    let result = entity::prelude::AuthGroupOwnerDelegate::delete_many()
+        .filter(entity::auth_group_owner_delegate::Column::OwnerType.eq(owner_type))
+        .filter(entity::auth_group_owner_delegate::Column::OwnerId.eq(owner_id))
+        .filter(entity::auth_group_owner_delegate::Column::UserId.eq(user_id))
+        .exec(db)
+        .await?;
+
+    if result.rows_affected == 1 {
+        Ok(Some(user_id))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_owner_delegate, can_manage_owner, get_managed_owners};
+    use crate::{
+        auth::{data::user::create_user, model::groups::GroupOwnerType},
+        eve::service::{alliance::get_or_create_alliance, character::get_or_create_character},
+        test_utils::{create_user_with_main, initialize_test_db},
+    };
+
+    #[tokio::test]
+    async fn managed_owners_leaders_and_delegates() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        // The mocked character is the CEO of its corporation which is the alliance's executor
+        let character = get_or_create_character(&db, 180548812).await?;
+        let alliance = get_or_create_alliance(&db, 434243723).await?;
+
+        let leader_id = create_user_with_main(&db, character.character_id).await?;
+        let delegate_id = create_user(&db).await?;
+
+        let owners = get_managed_owners(&db, leader_id).await?;
+
+        assert_eq!(owners.len(), 2);
+        assert!(owners.iter().all(|owner| owner.leader));
+        assert!(owners
+            .iter()
+            .any(|owner| owner.owner_type == GroupOwnerType::Alliance
+                && owner.owner_id == alliance.alliance_id));
+
+        assert!(
+            !can_manage_owner(
+                &db,
+                delegate_id,
+                &GroupOwnerType::Corporation,
+                Some(character.corporation_id)
+            )
+            .await?
+        );
+
+        add_owner_delegate(
+            &db,
+            GroupOwnerType::Corporation,
+            character.corporation_id,
+            delegate_id,
+        )
+        .await?;
+
+        let owners = get_managed_owners(&db, delegate_id).await?;
+
+        assert_eq!(owners.len(), 1);
+        assert!(!owners[0].leader);
+        assert!(
+            can_manage_owner(
+                &db,
+                delegate_id,
+                &GroupOwnerType::Corporation,
+                Some(character.corporation_id)
+            )
+            .await?
+        );
+        assert!(!can_manage_owner(&db, delegate_id, &GroupOwnerType::Auth, None).await?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,446 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::anyhow;
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue::{Set, Unchanged},
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+
+use crate::auth::model::groups::{
+    GroupQuestionType, NewGroupApplicationAnswerDto, NewGroupQuestionDto,
+};
+
+use entity::auth_group_application_answer::Model as GroupApplicationAnswer;
+use entity::auth_group_question::Model as GroupQuestion;
+
+use super::get_group_by_id;
+
+pub async fn get_group_questions(
+    db: &DatabaseConnection,
+    group_id: i32,
+) -> Result<Vec<GroupQuestion>, DbErr> {
+    entity::prelude::AuthGroupQuestion::find()
+        .filter(entity::auth_group_question::Column::GroupId.eq(group_id))
+        .order_by_asc(entity::auth_group_question::Column::Ordering)
+        .order_by_asc(entity::auth_group_question::Column::Id)
+        .all(db)
+        .await
+}
+
+fn validate_questions(questions: &[NewGroupQuestionDto]) -> Result<(), anyhow::Error> {
+    for question in questions {
+        if question.question.trim().is_empty() {
+            return Err(anyhow!("Question can't be empty"));
+        }
+
+        match question.question_type {
+            GroupQuestionType::Choice => {
+                if question.choices.is_empty() {
+                    return Err(anyhow!(
+                        "Choice question requires at least one choice: {}",
+                        question.question
+                    ));
+                }
+            }
+            _ => {
+                if !question.choices.is_empty() {
+                    return Err(anyhow!(
+                        "Only choice questions can have choices: {}",
+                        question.question
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Replaces the group's questionnaire, questions with an id are updated in place so answers keep
+// referencing them. Answers to removed questions are kept on past applications along with a copy
+// of the question.
+pub async fn set_group_questions(
+    db: &DatabaseConnection,
+    group_id: i32,
+    questions: Vec<NewGroupQuestionDto>,
+) -> Result<Option<Vec<GroupQuestion>>, anyhow::Error> {
+    if get_group_by_id(db, group_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    validate_questions(&questions)?;
+
+    let existing_question_ids: HashSet<i32> = get_group_questions(db, group_id)
+        .await?
+        .into_iter()
+        .map(|question| question.id)
+        .collect();
+
+    let mut kept_question_ids: HashSet<i32> = HashSet::new();
+
+    for question_id in questions.iter().filter_map(|question| question.id) {
+        if !existing_question_ids.contains(&question_id) {
+            return Err(anyhow!(
+                "Question {} is not part of the group's questionnaire",
+                question_id
+            ));
+        }
+
+        if !kept_question_ids.insert(question_id) {
+            return Err(anyhow!(
+                "Question {} was included more than once",
+                question_id
+            ));
+        }
+    }
+
+    // A failed insert or update mustn't leave the questionnaire partially replaced
+    let txn = db.begin().await?;
+
+    let removed_question_ids: Vec<i32> = existing_question_ids
+        .difference(&kept_question_ids)
+        .copied()
+        .collect();
+
+    if !removed_question_ids.is_empty() {
+        entity::prelude::AuthGroupQuestion::delete_many()
+            .filter(entity::auth_group_question::Column::Id.is_in(removed_question_ids))
+            .exec(&txn)
+            .await?;
+    }
+
+    for question in questions {
+        let choices = match question.question_type {
+            GroupQuestionType::Choice => Some(serde_json::to_value(question.choices)?),
+            _ => None,
+        };
+
+        let mut model = entity::auth_group_question::ActiveModel {
+            group_id: Set(group_id),
+            question: Set(question.question),
+            question_type: Set(question.question_type.into()),
+            choices: Set(choices),
+            required: Set(question.required),
+            ordering: Set(question.ordering),
+            ..Default::default()
+        };
+
+        match question.id {
+            Some(question_id) => {
+                model.id = Unchanged(question_id);
+                model.update(&txn).await?;
+            }
+            None => {
+                model.insert(&txn).await?;
+            }
+        }
+    }
+
+    txn.commit().await?;
+
+    let questions = get_group_questions(db, group_id).await?;
+
+    Ok(Some(questions))
+}
+
+fn is_valid_answer(question: &GroupQuestion, answer: &str) -> bool {
+    match question.question_type {
+        entity::sea_orm_active_enums::GroupQuestionType::Text => true,
+        entity::sea_orm_active_enums::GroupQuestionType::Choice => {
+            let choices: Vec<String> = question
+                .choices
+                .clone()
+                .and_then(|choices| serde_json::from_value(choices).ok())
+                .unwrap_or_default();
+
+            choices.iter().any(|choice| choice == answer)
+        }
+        entity::sea_orm_active_enums::GroupQuestionType::Boolean => {
+            answer == "true" || answer == "false"
+        }
+        entity::sea_orm_active_enums::GroupQuestionType::Number => {
+            answer.parse::<f64>().is_ok_and(|number| number.is_finite())
+        }
+    }
+}
+
+// Checks the answers against the group's questionnaire, returning each answered question
+// alongside its answer. Blank answers to optional questions are dropped.
+pub async fn validate_application_answers(
+    db: &DatabaseConnection,
+    group_id: i32,
+    answers: Vec<NewGroupApplicationAnswerDto>,
+) -> Result<Vec<(GroupQuestion, String)>, anyhow::Error> {
+    let questions = get_group_questions(db, group_id).await?;
+
+    let mut answers_map: HashMap<i32, String> = HashMap::new();
+
+    for answer in answers {
+        if !questions
+            .iter()
+            .any(|question| question.id == answer.question_id)
+        {
+            return Err(anyhow!(
+                "Invalid application: question {} is not part of the group's questionnaire",
+                answer.question_id
+            ));
+        }
+
+        if answers_map
+            .insert(answer.question_id, answer.answer.trim().to_string())
+            .is_some()
+        {
+            return Err(anyhow!(
+                "Invalid application: question {} was answered more than once",
+                answer.question_id
+            ));
+        }
+    }
+
+    let mut validated = vec![];
+
+    for question in questions {
+        let answer = match answers_map.remove(&question.id) {
+            Some(answer) if !answer.is_empty() => answer,
+            _ => {
+                if question.required {
+                    return Err(anyhow!(
+                        "Invalid application: missing answer to required question: {}",
+                        question.question
+                    ));
+                }
+
+                continue;
+            }
+        };
+
+        if !is_valid_answer(&question, &answer) {
+            return Err(anyhow!(
+                "Invalid application: invalid answer to question: {}",
+                question.question
+            ));
+        }
+
+        validated.push((question, answer));
+    }
+
+    Ok(validated)
+}
+
+pub async fn create_application_answers(
+    db: &DatabaseConnection,
+    application_id: i32,
+    answers: Vec<(GroupQuestion, String)>,
+) -> Result<(), DbErr> {
+    if answers.is_empty() {
+        return Ok(());
+    }
+
+    let answers = answers.into_iter().map(|(question, answer)| {
+        entity::auth_group_application_answer::ActiveModel {
+            application_id: Set(application_id),
+            question_id: Set(Some(question.id)),
+            question: Set(question.question),
+            answer: Set(answer),
+            ..Default::default()
+        }
+    });
+
+    entity::prelude::AuthGroupApplicationAnswer::insert_many(answers)
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_application_answers(
+    db: &DatabaseConnection,
+    application_ids: Vec<i32>,
+) -> Result<Vec<GroupApplicationAnswer>, DbErr> {
+    entity::prelude::AuthGroupApplicationAnswer::find()
+        .filter(entity::auth_group_application_answer::Column::ApplicationId.is_in(application_ids))
+        .order_by_asc(entity::auth_group_application_answer::Column::Id)
+        .all(db)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_application_answers, set_group_questions, validate_application_answers};
+    use crate::{
+        auth::{
+            data::{
+                groups::{create_group, members::join_group},
+                user::create_user,
+            },
+            model::groups::{
+                GroupQuestionType, GroupType, NewGroupApplicationAnswerDto, NewGroupDto,
+                NewGroupQuestionDto,
+            },
+        },
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+
+    #[tokio::test]
+    async fn validate_questionnaire_answers() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(
+            &db,
+            NewGroupDto {
+                group_type: GroupType::Apply,
+                ..new_group_dto("Recruitment")
+            },
+        )
+        .await?;
+
+        let new_question =
+            |question: &str, question_type: GroupQuestionType, choices: Vec<&str>| {
+                NewGroupQuestionDto {
+                    id: None,
+                    question: question.to_string(),
+                    question_type,
+                    choices: choices
+                        .into_iter()
+                        .map(|choice| choice.to_string())
+                        .collect(),
+                    required: true,
+                    ordering: 0,
+                }
+            };
+
+        // Choices are only allowed on choice questions
+        assert!(set_group_questions(
+            &db,
+            group.id,
+            vec![new_question(
+                "How many SP?",
+                GroupQuestionType::Number,
+                vec!["1"]
+            )],
+        )
+        .await
+        .is_err());
+
+        let questions = set_group_questions(
+            &db,
+            group.id,
+            vec![
+                new_question("How many SP?", GroupQuestionType::Number, vec![]),
+                new_question("Timezone?", GroupQuestionType::Choice, vec!["EU", "US"]),
+                new_question("Agree to rules?", GroupQuestionType::Boolean, vec![]),
+            ],
+        )
+        .await?
+        .unwrap();
+
+        let answers = |values: [&str; 3]| {
+            questions
+                .iter()
+                .zip(values)
+                .map(|(question, answer)| NewGroupApplicationAnswerDto {
+                    question_id: question.id,
+                    answer: answer.to_string(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let validated =
+            validate_application_answers(&db, group.id, answers(["45000000", "EU", "true"]))
+                .await?;
+        assert_eq!(validated.len(), 3);
+
+        for invalid in [
+            ["many", "EU", "true"],
+            ["45000000", "AU", "true"],
+            ["45000000", "EU", "yes"],
+            ["45000000", "EU", ""],
+        ] {
+            assert!(
+                validate_application_answers(&db, group.id, answers(invalid))
+                    .await
+                    .is_err()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_questions_in_place() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(
+            &db,
+            NewGroupDto {
+                group_type: GroupType::Apply,
+                ..new_group_dto("Recruitment")
+            },
+        )
+        .await?;
+
+        let new_question = |id: Option<i32>, question: &str| NewGroupQuestionDto {
+            id,
+            question: question.to_string(),
+            question_type: GroupQuestionType::Text,
+            choices: vec![],
+            required: false,
+            ordering: 0,
+        };
+
+        let questions = set_group_questions(
+            &db,
+            group.id,
+            vec![new_question(None, "Main?"), new_question(None, "Alts?")],
+        )
+        .await?
+        .unwrap();
+
+        let user_id = create_user(&db).await?;
+        let application = join_group(
+            &db,
+            group.id,
+            user_id,
+            None,
+            vec![NewGroupApplicationAnswerDto {
+                question_id: questions[0].id,
+                answer: "Hyziri".to_string(),
+            }],
+        )
+        .await?
+        .unwrap();
+
+        // Questions of other groups can't be updated through this group's questionnaire
+        assert!(
+            set_group_questions(&db, group.id, vec![new_question(Some(-1), "Main?")])
+                .await
+                .is_err()
+        );
+
+        let updated = set_group_questions(
+            &db,
+            group.id,
+            vec![
+                new_question(Some(questions[0].id), "Main character?"),
+                new_question(None, "Timezone?"),
+            ],
+        )
+        .await?
+        .unwrap();
+
+        assert_eq!(updated.len(), 2);
+        assert_eq!(updated[0].id, questions[0].id);
+        assert_eq!(updated[0].question, "Main character?");
+        assert!(updated
+            .iter()
+            .all(|question| question.id != questions[1].id));
+
+        let answers = get_application_answers(&db, vec![application.id]).await?;
+
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].question_id, Some(questions[0].id));
+
+        Ok(())
+    }
+}
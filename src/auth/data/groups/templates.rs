@@ -0,0 +1,360 @@
+use anyhow::anyhow;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::{
+    auth::model::groups::{
+        GroupOwnerType, GroupTemplateDefinition, InstantiateGroupTemplateDto, NewGroupDto,
+        NewGroupFilterGroupDto, NewGroupFilterRuleDto, NewGroupTemplateDto,
+    },
+    eve::service::corporation::get_or_create_corporation,
+};
+
+use entity::auth_group::Model as Group;
+use entity::auth_group_template::Model as GroupTemplate;
+
+use super::create_group;
+
+pub async fn create_template(
+    db: &DatabaseConnection,
+    template: NewGroupTemplateDto,
+) -> Result<GroupTemplate, anyhow::Error> {
+    let template = entity::auth_group_template::ActiveModel {
+        name: Set(template.name),
+        description: Set(template.description),
+        definition: Set(serde_json::to_value(template.definition)?),
+        created: Set(Utc::now().naive_utc()),
+        ..Default::default()
+    };
+
+    Ok(template.insert(db).await?)
+}
+
+pub async fn get_templates(db: &DatabaseConnection) -> Result<Vec<GroupTemplate>, DbErr> {
+    entity::prelude::AuthGroupTemplate::find()
+        .order_by_asc(entity::auth_group_template::Column::Name)
+        .all(db)
+        .await
+}
+
+pub async fn get_template_by_id(
+    db: &DatabaseConnection,
+    id: i32,
+) -> Result<Option<GroupTemplate>, DbErr> {
+    entity::prelude::AuthGroupTemplate::find()
+        .filter(entity::auth_group_template::Column::Id.eq(id))
+        .one(db)
+        .await
+}
+
+pub async fn update_template(
+    db: &DatabaseConnection,
+    template_id: i32,
+    template: NewGroupTemplateDto,
+) -> Result<Option<GroupTemplate>, anyhow::Error> {
+    if get_template_by_id(db, template_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    let template = entity::auth_group_template::ActiveModel {
+        id: Set(template_id),
+        name: Set(template.name),
+        description: Set(template.description),
+        definition: Set(serde_json::to_value(template.definition)?),
+        ..Default::default()
+    };
+
+    let template = template.update(db).await?;
+
+    Ok(Some(template))
+}
+
+pub async fn delete_template(
+    db: &DatabaseConnection,
+    template_id: i32,
+) -> Result<Option<i32>, DbErr> {
+    let result = entity::prelude::AuthGroupTemplate::delete_by_id(template_id)
+        .exec(db)
+        .await?;
+
+    if result.rows_affected == 1 {
+        Ok(Some(template_id))
+    } else {
+        Ok(None)
+    }
+}
+
+// Values substituted for each placeholder, None if it can't be resolved for the owner
+struct TemplatePlaceholders {
+    owner_id: Option<i32>,
+    corporation_id: Option<i32>,
+    alliance_id: Option<i32>,
+}
+
+async fn get_template_placeholders(
+    db: &DatabaseConnection,
+    owner_type: &GroupOwnerType,
+    owner_id: Option<i32>,
+) -> Result<TemplatePlaceholders, anyhow::Error> {
+    let placeholders = match (owner_type, owner_id) {
+        (GroupOwnerType::Corporation, Some(corporation_id)) => {
+            let corporation = get_or_create_corporation(db, corporation_id).await?;
+
+            TemplatePlaceholders {
+                owner_id,
+                corporation_id: Some(corporation_id),
+                alliance_id: corporation.alliance_id,
+            }
+        }
+        (GroupOwnerType::Alliance, Some(alliance_id)) => TemplatePlaceholders {
+            owner_id,
+            corporation_id: None,
+            alliance_id: Some(alliance_id),
+        },
+        _ => TemplatePlaceholders {
+            owner_id,
+            corporation_id: None,
+            alliance_id: None,
+        },
+    };
+
+    Ok(placeholders)
+}
+
+fn resolve_placeholders(
+    value: String,
+    placeholders: &TemplatePlaceholders,
+) -> Result<String, anyhow::Error> {
+    let mut value = value;
+
+    for (placeholder, resolved) in [
+        ("{owner_id}", placeholders.owner_id),
+        ("{corporation_id}", placeholders.corporation_id),
+        ("{alliance_id}", placeholders.alliance_id),
+    ] {
+        if !value.contains(placeholder) {
+            continue;
+        }
+
+        match resolved {
+            Some(id) => value = value.replace(placeholder, &id.to_string()),
+            None => {
+                return Err(anyhow!(
+                    "Template placeholder {} can't be resolved for the selected owner",
+                    placeholder
+                ))
+            }
+        }
+    }
+
+    if value.contains('{') || value.contains('}') {
+        return Err(anyhow!(
+            "Unknown template placeholder in criteria value: {}",
+            value
+        ));
+    }
+
+    Ok(value)
+}
+
+fn resolve_filter_rules(
+    rules: Vec<NewGroupFilterRuleDto>,
+    placeholders: &TemplatePlaceholders,
+) -> Result<Vec<NewGroupFilterRuleDto>, anyhow::Error> {
+    rules
+        .into_iter()
+        .map(|rule| {
+            Ok(NewGroupFilterRuleDto {
+                criteria_value: resolve_placeholders(rule.criteria_value, placeholders)?,
+                ..rule
+            })
+        })
+        .collect()
+}
+
+fn resolve_filter_groups(
+    filter_groups: Vec<NewGroupFilterGroupDto>,
+    placeholders: &TemplatePlaceholders,
+) -> Result<Vec<NewGroupFilterGroupDto>, anyhow::Error> {
+    filter_groups
+        .into_iter()
+        .map(|group| {
+            Ok(NewGroupFilterGroupDto {
+                filter_type: group.filter_type,
+                rules: resolve_filter_rules(group.rules, placeholders)?,
+                filter_groups: resolve_filter_groups(group.filter_groups, placeholders)?,
+            })
+        })
+        .collect()
+}
+
+// Creates a group from the template for the given owner, resolving any placeholders in the
+// template's filter rules from the owner
+pub async fn instantiate_template(
+    db: &DatabaseConnection,
+    template_id: i32,
+    instance: InstantiateGroupTemplateDto,
+) -> Result<Option<Group>, anyhow::Error> {
+    let template = match get_template_by_id(db, template_id).await? {
+        Some(template) => template,
+        None => return Ok(None),
+    };
+
+    let definition: GroupTemplateDefinition = serde_json::from_value(template.definition)?;
+
+    let placeholders =
+        get_template_placeholders(db, &instance.owner_type, instance.owner_id).await?;
+
+    let new_group = NewGroupDto {
+        name: instance.name.unwrap_or(definition.name),
+        description: definition.description,
+        confidential: definition.confidential,
+        leave_applications: definition.leave_applications,
+        owner_type: instance.owner_type,
+        owner_id: instance.owner_id,
+        group_type: definition.group_type,
+        parent_id: None,
+        membership_inheritance: Default::default(),
+        category_id: definition.category_id,
+        filter_type: definition.filter_type,
+        filter_rules: resolve_filter_rules(definition.filter_rules, &placeholders)?,
+        filter_groups: resolve_filter_groups(definition.filter_groups, &placeholders)?,
+    };
+
+    let group = create_group(db, new_group).await?;
+
+    Ok(Some(group))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        create_template, instantiate_template, resolve_placeholders, TemplatePlaceholders,
+    };
+    use crate::{
+        auth::{
+            data::groups::filters::get_group_filters,
+            model::groups::{
+                GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterScope, GroupFilterType,
+                GroupOwnerType, GroupTemplateDefinition, GroupType, InstantiateGroupTemplateDto,
+                NewGroupFilterGroupDto, NewGroupFilterRuleDto, NewGroupTemplateDto,
+            },
+        },
+        eve::{
+            data::corporation::CorporationRepository, service::alliance::get_or_create_alliance,
+        },
+        test_utils::initialize_test_db,
+    };
+
+    #[test]
+    fn resolve_template_placeholders() {
+        let placeholders = TemplatePlaceholders {
+            owner_id: Some(109299958),
+            corporation_id: Some(109299958),
+            alliance_id: None,
+        };
+
+        let value = resolve_placeholders("{corporation_id}".to_string(), &placeholders).unwrap();
+        assert_eq!(value, "109299958");
+
+        let value = resolve_placeholders("1234".to_string(), &placeholders).unwrap();
+        assert_eq!(value, "1234");
+
+        // The corporation isn't in an alliance
+        assert!(resolve_placeholders("{alliance_id}".to_string(), &placeholders).is_err());
+
+        assert!(resolve_placeholders("{character_id}".to_string(), &placeholders).is_err());
+    }
+
+    #[tokio::test]
+    async fn instantiate_template_for_corporation() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let alliance = get_or_create_alliance(&db, 434243723).await?;
+        let corporation = CorporationRepository::new(&db)
+            .create(
+                98000001,
+                "Black Rose Logistics".to_string(),
+                Some(alliance.alliance_id),
+                180548812,
+            )
+            .await?;
+
+        let rule = |criteria: GroupFilterCriteria, criteria_value: &str| NewGroupFilterRuleDto {
+            criteria,
+            criteria_type: GroupFilterCriteriaType::Is,
+            criteria_value: criteria_value.to_string(),
+            scope: GroupFilterScope::AnyCharacter,
+        };
+
+        let template = create_template(
+            &db,
+            NewGroupTemplateDto {
+                name: "Corporation Members".to_string(),
+                description: None,
+                definition: GroupTemplateDefinition {
+                    name: "Members".to_string(),
+                    description: None,
+                    confidential: false,
+                    leave_applications: false,
+                    group_type: GroupType::Auto,
+                    category_id: None,
+                    filter_type: GroupFilterType::All,
+                    filter_rules: vec![rule(GroupFilterCriteria::Corporation, "{corporation_id}")],
+                    filter_groups: vec![NewGroupFilterGroupDto {
+                        filter_type: GroupFilterType::Any,
+                        rules: vec![rule(GroupFilterCriteria::Alliance, "{alliance_id}")],
+                        filter_groups: vec![],
+                    }],
+                },
+            },
+        )
+        .await?;
+
+        let group = instantiate_template(
+            &db,
+            template.id,
+            InstantiateGroupTemplateDto {
+                name: Some("Logistics Members".to_string()),
+                owner_type: GroupOwnerType::Corporation,
+                owner_id: Some(corporation.corporation_id),
+            },
+        )
+        .await?
+        .unwrap();
+
+        assert_eq!(group.name, "Logistics Members");
+        assert!(GroupOwnerType::from(group.owner_type) == GroupOwnerType::Corporation);
+        assert_eq!(group.owner_id, Some(corporation.corporation_id));
+
+        let filters = get_group_filters(&db, group.id).await?.unwrap();
+
+        assert_eq!(
+            filters.filter_rules[0].criteria_value,
+            corporation.corporation_id.to_string()
+        );
+        assert_eq!(
+            filters.filter_groups[0].rules[0].criteria_value,
+            alliance.alliance_id.to_string()
+        );
+
+        // An alliance owner can't resolve a corporation placeholder
+        let result = instantiate_template(
+            &db,
+            template.id,
+            InstantiateGroupTemplateDto {
+                name: None,
+                owner_type: GroupOwnerType::Alliance,
+                owner_id: Some(alliance.alliance_id),
+            },
+        )
+        .await;
+
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
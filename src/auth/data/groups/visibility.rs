@@ -0,0 +1,385 @@
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder};
+
+use entity::auth_group::Model as Group;
+use entity::auth_user::Model as User;
+use entity::sea_orm_active_enums::{GroupApplicationStatus, GroupType};
+
+use crate::auth::{
+    data::{groups::owners::get_managed_owners, user::bulk_get_user_groups},
+    model::groups::{AvailableGroupDto, GroupDto, GroupOwnerType, PendingGroupApplicationDto},
+};
+
+use super::{filters::get_eligible_group_ids, get_group_by_id, get_group_dto};
+
+#[derive(PartialEq, Debug)]
+pub enum GroupAccess {
+    // Admins & users managing the group's owner
+    Manager,
+    // Direct or inherited members
+    Member,
+    // Users who aren't members of a group which isn't hidden
+    Visible,
+    // Users who aren't members of a hidden group, the group is treated as if it doesn't exist
+    Hidden,
+}
+
+// Gets how much of each group a user is able to see
+pub async fn get_group_access(
+    db: &DatabaseConnection,
+    user: &User,
+    groups: &[Group],
+) -> Result<HashMap<i32, GroupAccess>, DbErr> {
+    if user.admin {
+        return Ok(groups
+            .iter()
+            .map(|group| (group.id, GroupAccess::Manager))
+            .collect());
+    }
+
+    let owners = get_managed_owners(db, user.id).await?;
+
+    let member_group_ids: HashSet<i32> = bulk_get_user_groups(db, vec![user.id])
+        .await?
+        .into_iter()
+        .flat_map(|user_groups| user_groups.groups)
+        .map(|membership| membership.group_id)
+        .collect();
+
+    let access = groups
+        .iter()
+        .map(|group| {
+            let owner_type: GroupOwnerType = group.owner_type.clone().into();
+
+            let manages_owner = owners.iter().any(|owner| {
+                owner.owner_type == owner_type && Some(owner.owner_id) == group.owner_id
+            });
+
+            let access = if manages_owner {
+                GroupAccess::Manager
+            } else if member_group_ids.contains(&group.id) {
+                GroupAccess::Member
+            } else if group.group_type == GroupType::Hidden {
+                GroupAccess::Hidden
+            } else {
+                GroupAccess::Visible
+            };
+
+            (group.id, access)
+        })
+        .collect();
+
+    Ok(access)
+}
+
+// The member list & member count of confidential groups are only shown to managers
+pub fn can_view_members(group: &Group, access: &GroupAccess) -> bool {
+    match access {
+        GroupAccess::Manager => true,
+        GroupAccess::Member | GroupAccess::Visible => !group.confidential,
+        GroupAccess::Hidden => false,
+    }
+}
+
+// Gets the groups a user is able to see, hiding member counts they aren't allowed to see
+pub async fn get_visible_group_dto(
+    db: &DatabaseConnection,
+    user: &User,
+    group_ids: Vec<i32>,
+) -> Result<Vec<GroupDto>, anyhow::Error> {
+    let groups = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::Id.is_in(group_ids))
+        .all(db)
+        .await?;
+
+    let access = get_group_access(db, user, &groups).await?;
+
+    let visible_groups: Vec<&Group> = groups
+        .iter()
+        .filter(|group| access.get(&group.id) != Some(&GroupAccess::Hidden))
+        .collect();
+
+    let hidden_member_counts: HashSet<i32> = visible_groups
+        .iter()
+        .filter(|group| {
+            !access
+                .get(&group.id)
+                .is_some_and(|access| can_view_members(group, access))
+        })
+        .map(|group| group.id)
+        .collect();
+
+    let visible_group_ids = visible_groups.iter().map(|group| group.id).collect();
+
+    let mut groups = get_group_dto(db, Some(visible_group_ids), None).await?;
+
+    for group in groups.iter_mut() {
+        if hidden_member_counts.contains(&group.id) {
+            group.member_count = None;
+        }
+    }
+
+    Ok(groups)
+}
+
+// Gets a single group along with the user's access to it, returns None if the group doesn't exist
+pub async fn get_user_group_access(
+    db: &DatabaseConnection,
+    user: &User,
+    group_id: i32,
+) -> Result<Option<(Group, GroupAccess)>, DbErr> {
+    let group = match get_group_by_id(db, group_id).await? {
+        Some(group) => group,
+        None => return Ok(None),
+    };
+
+    let access = get_group_access(db, user, std::slice::from_ref(&group))
+        .await?
+        .remove(&group.id)
+        .unwrap_or(GroupAccess::Hidden);
+
+    Ok(Some((group, access)))
+}
+
+// Gets the open & apply groups a user is able to see along with their eligibility, membership & pending applications
+pub async fn get_available_groups(
+    db: &DatabaseConnection,
+    user: &User,
+    category_id: Option<i32>,
+) -> Result<Vec<AvailableGroupDto>, anyhow::Error> {
+    let mut query = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::GroupType.is_in([GroupType::Open, GroupType::Apply]))
+        .filter(entity::auth_group::Column::Archived.is_null());
+
+    if let Some(category_id) = category_id {
+        query = query.filter(entity::auth_group::Column::CategoryId.eq(category_id));
+    }
+
+    let group_ids: Vec<i32> = query
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|group| group.id)
+        .collect();
+
+    let groups = get_visible_group_dto(db, user, group_ids).await?;
+
+    let member_group_ids: HashSet<i32> = bulk_get_user_groups(db, vec![user.id])
+        .await?
+        .into_iter()
+        .flat_map(|user_groups| user_groups.groups)
+        .map(|membership| membership.group_id)
+        .collect();
+
+    let applications = entity::prelude::AuthGroupApplication::find()
+        .filter(entity::auth_group_application::Column::UserId.eq(user.id))
+        .filter(
+            entity::auth_group_application::Column::Status.eq(GroupApplicationStatus::Outstanding),
+        )
+        .order_by_asc(entity::auth_group_application::Column::Created)
+        .order_by_asc(entity::auth_group_application::Column::Id)
+        .all(db)
+        .await?;
+
+    let mut pending_applications: HashMap<i32, Vec<PendingGroupApplicationDto>> = HashMap::new();
+
+    for application in applications {
+        pending_applications
+            .entry(application.group_id)
+            .or_default()
+            .push(PendingGroupApplicationDto {
+                id: application.id,
+                request_type: application.request_type.into(),
+                created: DateTime::from_naive_utc_and_offset(application.created, Utc),
+            });
+    }
+
+    let group_ids: Vec<i32> = groups.iter().map(|group| group.id).collect();
+
+    let eligible_group_ids = get_eligible_group_ids(db, &group_ids, user.id).await?;
+
+    let available_groups = groups
+        .into_iter()
+        .map(|group| AvailableGroupDto {
+            eligible: eligible_group_ids.contains(&group.id),
+            member: member_group_ids.contains(&group.id),
+            pending_applications: pending_applications.remove(&group.id).unwrap_or_default(),
+            group,
+        })
+        .collect();
+
+    Ok(available_groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set};
+
+    use super::{get_available_groups, get_visible_group_dto};
+    use crate::{
+        auth::{
+            data::{
+                groups::{applications::create_group_application, create_group},
+                user::{create_user, get_user},
+            },
+            model::groups::{
+                GroupApplicationType as GroupApplicationTypeDto, GroupFilterCriteria,
+                GroupFilterCriteriaType, GroupFilterScope, GroupType, NewGroupDto,
+                NewGroupFilterRuleDto,
+            },
+        },
+        test_utils::{initialize_test_db, new_group_dto},
+    };
+    use entity::sea_orm_active_enums::GroupApplicationType;
+
+    #[tokio::test]
+    async fn hidden_and_confidential_groups() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let new_group = |name: &str, group_type: GroupType, confidential: bool| NewGroupDto {
+            confidential,
+            group_type,
+            ..new_group_dto(name)
+        };
+
+        let hidden = create_group(&db, new_group("Hidden", GroupType::Hidden, false)).await?;
+        let confidential =
+            create_group(&db, new_group("Confidential", GroupType::Open, true)).await?;
+
+        let user_id = create_user(&db).await?;
+        let user = get_user(&db, user_id).await?.unwrap();
+
+        let group_ids = vec![hidden.id, confidential.id];
+
+        let groups = get_visible_group_dto(&db, &user, group_ids.clone()).await?;
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].id, confidential.id);
+        assert_eq!(groups[0].member_count, None);
+
+        // Members of a hidden group are able to see it
+        entity::auth_group_user::ActiveModel {
+            group_id: Set(hidden.id),
+            user_id: Set(user_id),
+            created: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+
+        let groups = get_visible_group_dto(&db, &user, group_ids.clone()).await?;
+
+        assert_eq!(groups.len(), 2);
+
+        // Admins are able to see the member count of confidential groups
+        let mut admin = user.clone();
+        admin.admin = true;
+
+        let groups = get_visible_group_dto(&db, &admin, vec![confidential.id]).await?;
+
+        assert_eq!(groups[0].member_count, Some(0));
+
+        // Hidden groups are never listed as available, even to their members
+        let available = get_available_groups(&db, &user, None).await?;
+
+        assert_eq!(available.len(), 1);
+        assert_eq!(available[0].group.id, confidential.id);
+        assert!(available[0].eligible);
+        assert!(!available[0].member);
+        assert!(available[0].pending_applications.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn available_group_eligibility() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let requires_group = |name: &str, required_group_id: i32| NewGroupDto {
+            filter_rules: vec![NewGroupFilterRuleDto {
+                criteria: GroupFilterCriteria::Group,
+                criteria_type: GroupFilterCriteriaType::Is,
+                criteria_value: required_group_id.to_string(),
+                scope: GroupFilterScope::AnyCharacter,
+            }],
+            ..new_group_dto(name)
+        };
+
+        let member_of = create_group(&db, new_group_dto("Member")).await?;
+        let not_member_of = create_group(&db, new_group_dto("Not member")).await?;
+        let eligible = create_group(&db, requires_group("Eligible", member_of.id)).await?;
+        let ineligible = create_group(&db, requires_group("Ineligible", not_member_of.id)).await?;
+
+        let user_id = create_user(&db).await?;
+        let user = get_user(&db, user_id).await?.unwrap();
+
+        entity::auth_group_user::ActiveModel {
+            group_id: Set(member_of.id),
+            user_id: Set(user_id),
+            created: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+
+        let available = get_available_groups(&db, &user, None).await?;
+
+        let is_eligible = |group_id: i32| {
+            available
+                .iter()
+                .find(|available| available.group.id == group_id)
+                .map(|available| available.eligible)
+        };
+
+        assert_eq!(available.len(), 4);
+        assert_eq!(is_eligible(member_of.id), Some(true));
+        assert_eq!(is_eligible(not_member_of.id), Some(true));
+        assert_eq!(is_eligible(eligible.id), Some(true));
+        assert_eq!(is_eligible(ineligible.id), Some(false));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn available_group_pending_applications() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(
+            &db,
+            NewGroupDto {
+                group_type: GroupType::Apply,
+                ..new_group_dto("Recruitment")
+            },
+        )
+        .await?;
+
+        let user_id = create_user(&db).await?;
+        let user = get_user(&db, user_id).await?.unwrap();
+
+        for request_type in [GroupApplicationType::Join, GroupApplicationType::Leave] {
+            create_group_application(&db, group.id, user_id, request_type, None).await?;
+        }
+
+        let available = get_available_groups(&db, &user, None).await?;
+
+        assert_eq!(available.len(), 1);
+
+        assert!(matches!(
+            available[0]
+                .pending_applications
+                .iter()
+                .map(|application| &application.request_type)
+                .collect::<Vec<_>>()
+                .as_slice(),
+            [
+                GroupApplicationTypeDto::Join,
+                GroupApplicationTypeDto::Leave
+            ]
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,431 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::anyhow;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter,
+};
+
+use crate::{
+    auth::{
+        data::groups::members::reconcile_groups,
+        model::organizations::{
+            NewOrganizationDto, OrganizationDto, OrganizationMemberDto, OrganizationMemberType,
+        },
+    },
+    eve::service::{alliance::get_or_create_alliance, corporation::get_or_create_corporation},
+};
+
+use entity::auth_organization::Model as Organization;
+
+async fn validate_organization_members(
+    db: &DatabaseConnection,
+    members: &[OrganizationMemberDto],
+) -> Result<(), anyhow::Error> {
+    for member in members {
+        match member.member_type {
+            OrganizationMemberType::Alliance => {
+                get_or_create_alliance(db, member.member_id).await?;
+            }
+            OrganizationMemberType::Corporation => {
+                get_or_create_corporation(db, member.member_id).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn create_organization_members(
+    db: &DatabaseConnection,
+    organization_id: i32,
+    members: Vec<OrganizationMemberDto>,
+) -> Result<(), DbErr> {
+    let new_members: Vec<entity::auth_organization_member::ActiveModel> = members
+        .into_iter()
+        .map(|member| entity::auth_organization_member::ActiveModel {
+            organization_id: Set(organization_id),
+            member_type: Set(member.member_type.into()),
+            member_id: Set(member.member_id),
+            ..Default::default()
+        })
+        .collect();
+
+    entity::prelude::AuthOrganizationMember::insert_many(new_members)
+        .on_empty_do_nothing()
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn create_organization(
+    db: &DatabaseConnection,
+    organization: NewOrganizationDto,
+) -> Result<Organization, anyhow::Error> {
+    validate_organization_members(db, &organization.members).await?;
+
+    let new_organization = entity::auth_organization::ActiveModel {
+        name: Set(organization.name),
+        description: Set(organization.description),
+        ..Default::default()
+    };
+
+    let new_organization = new_organization.insert(db).await?;
+
+    let members: HashSet<OrganizationMemberDto> = organization.members.into_iter().collect();
+
+    create_organization_members(db, new_organization.id, members.into_iter().collect()).await?;
+
+    Ok(new_organization)
+}
+
+pub async fn get_organization_by_id(
+    db: &DatabaseConnection,
+    id: i32,
+) -> Result<Option<Organization>, DbErr> {
+    entity::prelude::AuthOrganization::find()
+        .filter(entity::auth_organization::Column::Id.eq(id))
+        .one(db)
+        .await
+}
+
+pub async fn get_organization_members(
+    db: &DatabaseConnection,
+    organization_ids: Vec<i32>,
+) -> Result<Vec<entity::auth_organization_member::Model>, DbErr> {
+    entity::prelude::AuthOrganizationMember::find()
+        .filter(entity::auth_organization_member::Column::OrganizationId.is_in(organization_ids))
+        .all(db)
+        .await
+}
+
+pub async fn get_organization_dto(
+    db: &DatabaseConnection,
+    // Set None to get all organizations
+    organizations: Option<Vec<i32>>,
+) -> Result<Vec<OrganizationDto>, DbErr> {
+    let mut query = entity::prelude::AuthOrganization::find();
+
+    if let Some(organizations) = organizations {
+        query = query.filter(entity::auth_organization::Column::Id.is_in(organizations))
+    }
+
+    let organizations = query.all(db).await?;
+
+    let organization_ids: Vec<i32> = organizations
+        .iter()
+        .map(|organization| organization.id)
+        .collect();
+
+    let mut members: HashMap<i32, Vec<OrganizationMemberDto>> = HashMap::new();
+
+    for member in get_organization_members(db, organization_ids).await? {
+        members
+            .entry(member.organization_id)
+            .or_default()
+            .push(OrganizationMemberDto {
+                member_type: member.member_type.into(),
+                member_id: member.member_id,
+            });
+    }
+
+    let organizations = organizations
+        .into_iter()
+        .map(|organization| OrganizationDto {
+            members: members.remove(&organization.id).unwrap_or_default(),
+            id: organization.id,
+            name: organization.name,
+            description: organization.description,
+        })
+        .collect();
+
+    Ok(organizations)
+}
+
+pub async fn update_organization(
+    db: &DatabaseConnection,
+    organization_id: i32,
+    organization: NewOrganizationDto,
+) -> Result<Option<Organization>, anyhow::Error> {
+    if get_organization_by_id(db, organization_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    validate_organization_members(db, &organization.members).await?;
+
+    let updated_organization = entity::auth_organization::ActiveModel {
+        id: Set(organization_id),
+        name: Set(organization.name),
+        description: Set(organization.description),
+    };
+
+    let updated_organization = updated_organization.update(db).await?;
+
+    let current_members: HashSet<OrganizationMemberDto> =
+        get_organization_members(db, vec![organization_id])
+            .await?
+            .into_iter()
+            .map(|member| OrganizationMemberDto {
+                member_type: member.member_type.into(),
+                member_id: member.member_id,
+            })
+            .collect();
+
+    let members: HashSet<OrganizationMemberDto> = organization.members.into_iter().collect();
+
+    let added_members: Vec<OrganizationMemberDto> =
+        members.difference(&current_members).cloned().collect();
+    let removed_members: Vec<OrganizationMemberDto> =
+        current_members.difference(&members).cloned().collect();
+
+    for member in &removed_members {
+        let member_type: entity::sea_orm_active_enums::OrganizationMemberType =
+            member.member_type.clone().into();
+
+        entity::prelude::AuthOrganizationMember::delete_many()
+            .filter(entity::auth_organization_member::Column::OrganizationId.eq(organization_id))
+            .filter(entity::auth_organization_member::Column::MemberType.eq(member_type))
+            .filter(entity::auth_organization_member::Column::MemberId.eq(member.member_id))
+            .exec(db)
+            .await?;
+    }
+
+    create_organization_members(db, organization_id, added_members.clone()).await?;
+
+    let changed_members: Vec<OrganizationMemberDto> =
+        added_members.into_iter().chain(removed_members).collect();
+
+    reconcile_organization_groups(db, organization_id, changed_members).await?;
+
+    Ok(Some(updated_organization))
+}
+
+pub async fn delete_organization(
+    db: &DatabaseConnection,
+    organization_id: i32,
+) -> Result<Option<i32>, anyhow::Error> {
+    let owned_group = entity::prelude::AuthGroup::find()
+        .filter(
+            entity::auth_group::Column::OwnerType
+                .eq(entity::sea_orm_active_enums::GroupOwnerType::Organization),
+        )
+        .filter(entity::auth_group::Column::OwnerId.eq(organization_id))
+        .one(db)
+        .await?;
+
+    if let Some(group) = owned_group {
+        return Err(anyhow!(
+            "Organization is the owner of group {} and can't be deleted",
+            group.id
+        ));
+    }
+
+    let filter_rule = get_organization_filter_rules(db, organization_id)
+        .await?
+        .pop();
+
+    if let Some(rule) = filter_rule {
+        return Err(anyhow!(
+            "Organization is used by the filters of group {} and can't be deleted",
+            rule.group_id
+        ));
+    }
+
+    entity::prelude::AuthOrganizationMember::delete_many()
+        .filter(entity::auth_organization_member::Column::OrganizationId.eq(organization_id))
+        .exec(db)
+        .await?;
+
+    let result = entity::prelude::AuthOrganization::delete_by_id(organization_id)
+        .exec(db)
+        .await?;
+
+    if result.rows_affected == 1 {
+        Ok(Some(organization_id))
+    } else {
+        Ok(None)
+    }
+}
+
+async fn get_organization_filter_rules(
+    db: &DatabaseConnection,
+    organization_id: i32,
+) -> Result<Vec<entity::auth_group_filter_rule::Model>, DbErr> {
+    entity::prelude::AuthGroupFilterRule::find()
+        .filter(
+            entity::auth_group_filter_rule::Column::Criteria
+                .eq(entity::sea_orm_active_enums::GroupFilterCriteria::Organization),
+        )
+        .filter(
+            entity::auth_group_filter_rule::Column::CriteriaValue.eq(organization_id.to_string()),
+        )
+        .all(db)
+        .await
+}
+
+// Re-evaluates the groups filtering on an organization for the users whose characters are in
+// the alliances & corporations which were added to or removed from the organization
+async fn reconcile_organization_groups(
+    db: &DatabaseConnection,
+    organization_id: i32,
+    changed_members: Vec<OrganizationMemberDto>,
+) -> Result<(), DbErr> {
+    if changed_members.is_empty() {
+        return Ok(());
+    }
+
+    let group_ids: HashSet<i32> = get_organization_filter_rules(db, organization_id)
+        .await?
+        .into_iter()
+        .map(|rule| rule.group_id)
+        .collect();
+
+    if group_ids.is_empty() {
+        return Ok(());
+    }
+
+    let mut corporation_ids: HashSet<i32> = HashSet::new();
+    let mut alliance_ids: Vec<i32> = vec![];
+
+    for member in changed_members {
+        match member.member_type {
+            OrganizationMemberType::Alliance => alliance_ids.push(member.member_id),
+            OrganizationMemberType::Corporation => {
+                corporation_ids.insert(member.member_id);
+            }
+        }
+    }
+
+    let alliance_corporations = entity::prelude::EveCorporation::find()
+        .filter(entity::eve_corporation::Column::AllianceId.is_in(alliance_ids))
+        .all(db)
+        .await?;
+
+    corporation_ids.extend(
+        alliance_corporations
+            .iter()
+            .map(|corporation| corporation.corporation_id),
+    );
+
+    let character_ids: Vec<i32> = entity::prelude::EveCharacter::find()
+        .filter(entity::eve_character::Column::CorporationId.is_in(corporation_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|character| character.character_id)
+        .collect();
+
+    let user_ids: HashSet<i32> = entity::prelude::AuthUserCharacterOwnership::find()
+        .filter(entity::auth_user_character_ownership::Column::CharacterId.is_in(character_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|ownership| ownership.user_id)
+        .collect();
+
+    let mut group_ids: Vec<i32> = group_ids.into_iter().collect();
+    group_ids.sort_unstable();
+
+    reconcile_groups(db, group_ids, user_ids.into_iter().collect()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+    use super::{create_organization, update_organization};
+    use crate::{
+        auth::{
+            data::groups::create_group,
+            model::{
+                groups::{
+                    GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterScope, GroupOwnerType,
+                    GroupType, NewGroupDto, NewGroupFilterRuleDto,
+                },
+                organizations::{
+                    NewOrganizationDto, OrganizationMemberDto, OrganizationMemberType,
+                },
+            },
+        },
+        eve::service::character::get_or_create_character,
+        test_utils::{create_user_with_main, initialize_test_db, new_group_dto},
+    };
+
+    #[tokio::test]
+    async fn update_organization_reconciles_groups() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        create_user_with_main(&db, 180548812).await?;
+        let character = get_or_create_character(&db, 180548812).await?;
+
+        let organization = create_organization(
+            &db,
+            NewOrganizationDto {
+                name: "Coalition".to_string(),
+                description: None,
+                members: vec![],
+            },
+        )
+        .await?;
+
+        let group = create_group(
+            &db,
+            NewGroupDto {
+                owner_type: GroupOwnerType::Organization,
+                owner_id: Some(organization.id),
+                group_type: GroupType::Auto,
+                filter_rules: vec![NewGroupFilterRuleDto {
+                    criteria: GroupFilterCriteria::Organization,
+                    criteria_type: GroupFilterCriteriaType::Is,
+                    criteria_value: organization.id.to_string(),
+                    scope: GroupFilterScope::AnyCharacter,
+                }],
+                ..new_group_dto("Coalition Members")
+            },
+        )
+        .await?;
+
+        let get_member_count = || async {
+            entity::prelude::AuthGroupUser::find()
+                .filter(entity::auth_group_user::Column::GroupId.eq(group.id))
+                .all(&db)
+                .await
+                .map(|members| members.len())
+        };
+
+        let corporation_member = OrganizationMemberDto {
+            member_type: OrganizationMemberType::Corporation,
+            member_id: character.corporation_id,
+        };
+
+        // Adding the character's corporation makes the user eligible for the auto group
+        update_organization(
+            &db,
+            organization.id,
+            NewOrganizationDto {
+                name: "Coalition".to_string(),
+                description: None,
+                members: vec![corporation_member],
+            },
+        )
+        .await?;
+
+        assert_eq!(get_member_count().await?, 1);
+
+        update_organization(
+            &db,
+            organization.id,
+            NewOrganizationDto {
+                name: "Coalition".to_string(),
+                description: None,
+                members: vec![],
+            },
+        )
+        .await?;
+
+        assert_eq!(get_member_count().await?, 0);
+
+        Ok(())
+    }
+}
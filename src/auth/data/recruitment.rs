@@ -0,0 +1,1123 @@
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::anyhow;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::{
+    auth::{
+        data::{
+            groups::{
+                applications::{
+                    check_application_cooldown, create_group_application, get_application_by_id,
+                    update_group_application, withdraw_group_application,
+                },
+                filters::validate_group_members,
+                get_group_by_id,
+                members::insert_group_members,
+                questionnaires::{create_application_answers, validate_application_answers},
+            },
+            user::get_character_ownership,
+        },
+        model::recruitment::{NewRecruitmentDto, NewRecruitmentPipelineDto, RecruitmentDto},
+    },
+    eve::service::{
+        affiliation::get_character_affiliations, corporation::get_or_create_corporation,
+    },
+};
+
+use entity::sea_orm_active_enums::{
+    GroupApplicationStatus, GroupApplicationType, GroupMembershipReason, RecruitmentStatus,
+};
+
+use entity::auth_recruitment::Model as Recruitment;
+use entity::auth_recruitment_pipeline::Model as RecruitmentPipeline;
+
+// Filters for getting recruitments, unset filters match every recruitment
+#[derive(Default)]
+pub struct RecruitmentFilter {
+    pub pipeline_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub status: Option<RecruitmentStatus>,
+}
+
+// Recruitments which haven't yet been completed, rejected or withdrawn
+pub fn open_recruitment_statuses() -> Vec<RecruitmentStatus> {
+    vec![
+        RecruitmentStatus::AwaitingScopes,
+        RecruitmentStatus::Vetting,
+        RecruitmentStatus::Approved,
+    ]
+}
+
+// Scopes are stored space separated, sorted & without duplicates
+fn normalize_scopes<S: AsRef<str>>(scopes: &[S]) -> String {
+    scopes
+        .iter()
+        .map(|scope| scope.as_ref().trim())
+        .filter(|scope| !scope.is_empty())
+        .collect::<BTreeSet<&str>>()
+        .into_iter()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn get_missing_scopes(pipeline: &RecruitmentPipeline, granted_scopes: Option<&str>) -> Vec<String> {
+    let granted_scopes: Vec<&str> = granted_scopes
+        .map(|scopes| scopes.split_whitespace().collect())
+        .unwrap_or_default();
+
+    pipeline
+        .required_scopes
+        .split_whitespace()
+        .filter(|scope| !granted_scopes.contains(scope))
+        .map(String::from)
+        .collect()
+}
+
+async fn validate_recruitment_pipeline(
+    db: &DatabaseConnection,
+    pipeline: &NewRecruitmentPipelineDto,
+) -> Result<(), anyhow::Error> {
+    if pipeline.name.trim().is_empty() {
+        return Err(anyhow!("Invalid recruitment pipeline name"));
+    }
+
+    if get_group_by_id(db, pipeline.group_id).await?.is_none() {
+        return Err(anyhow!("Group does not exist"));
+    }
+
+    get_or_create_corporation(db, pipeline.corporation_id).await?;
+
+    Ok(())
+}
+
+pub async fn create_recruitment_pipeline(
+    db: &DatabaseConnection,
+    pipeline: NewRecruitmentPipelineDto,
+) -> Result<RecruitmentPipeline, anyhow::Error> {
+    validate_recruitment_pipeline(db, &pipeline).await?;
+
+    let pipeline = entity::auth_recruitment_pipeline::ActiveModel {
+        name: Set(pipeline.name),
+        corporation_id: Set(pipeline.corporation_id),
+        group_id: Set(pipeline.group_id),
+        required_scopes: Set(normalize_scopes(&pipeline.required_scopes)),
+        open: Set(pipeline.open),
+        created: Set(Utc::now().naive_utc()),
+        ..Default::default()
+    };
+
+    let pipeline = pipeline.insert(db).await?;
+
+    Ok(pipeline)
+}
+
+pub async fn get_pipeline_by_id(
+    db: &DatabaseConnection,
+    pipeline_id: i32,
+) -> Result<Option<RecruitmentPipeline>, DbErr> {
+    entity::prelude::AuthRecruitmentPipeline::find()
+        .filter(entity::auth_recruitment_pipeline::Column::Id.eq(pipeline_id))
+        .one(db)
+        .await
+}
+
+pub async fn get_recruitment_pipelines(
+    db: &DatabaseConnection,
+    include_closed: bool,
+) -> Result<Vec<RecruitmentPipeline>, DbErr> {
+    let mut query = entity::prelude::AuthRecruitmentPipeline::find();
+
+    if !include_closed {
+        query = query.filter(entity::auth_recruitment_pipeline::Column::Open.eq(true))
+    }
+
+    query
+        .order_by_asc(entity::auth_recruitment_pipeline::Column::Name)
+        .all(db)
+        .await
+}
+
+// Changing the required scopes doesn't affect recruitments already being vetted
+pub async fn update_recruitment_pipeline(
+    db: &DatabaseConnection,
+    pipeline_id: i32,
+    pipeline: NewRecruitmentPipelineDto,
+) -> Result<Option<RecruitmentPipeline>, anyhow::Error> {
+    if get_pipeline_by_id(db, pipeline_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    validate_recruitment_pipeline(db, &pipeline).await?;
+
+    let pipeline = entity::auth_recruitment_pipeline::ActiveModel {
+        id: Set(pipeline_id),
+        name: Set(pipeline.name),
+        corporation_id: Set(pipeline.corporation_id),
+        group_id: Set(pipeline.group_id),
+        required_scopes: Set(normalize_scopes(&pipeline.required_scopes)),
+        open: Set(pipeline.open),
+        ..Default::default()
+    };
+
+    let pipeline = pipeline.update(db).await?;
+
+    Ok(Some(pipeline))
+}
+
+pub async fn get_recruitment_by_id(
+    db: &DatabaseConnection,
+    recruitment_id: i32,
+) -> Result<Option<Recruitment>, DbErr> {
+    entity::prelude::AuthRecruitment::find()
+        .filter(entity::auth_recruitment::Column::Id.eq(recruitment_id))
+        .one(db)
+        .await
+}
+
+pub async fn get_recruitments(
+    db: &DatabaseConnection,
+    filter: RecruitmentFilter,
+) -> Result<Vec<RecruitmentDto>, DbErr> {
+    let mut query = entity::prelude::AuthRecruitment::find();
+
+    if let Some(pipeline_id) = filter.pipeline_id {
+        query = query.filter(entity::auth_recruitment::Column::PipelineId.eq(pipeline_id))
+    }
+
+    if let Some(user_id) = filter.user_id {
+        query = query.filter(entity::auth_recruitment::Column::UserId.eq(user_id))
+    }
+
+    if let Some(status) = filter.status {
+        query = query.filter(entity::auth_recruitment::Column::Status.eq(status))
+    }
+
+    let recruitments = query
+        .order_by_desc(entity::auth_recruitment::Column::Created)
+        .order_by_desc(entity::auth_recruitment::Column::Id)
+        .all(db)
+        .await?;
+
+    recruitments_to_dtos(db, recruitments).await
+}
+
+pub async fn get_recruitment_dto_by_id(
+    db: &DatabaseConnection,
+    recruitment_id: i32,
+) -> Result<Option<RecruitmentDto>, DbErr> {
+    let recruitment = match get_recruitment_by_id(db, recruitment_id).await? {
+        Some(recruitment) => recruitment,
+        None => return Ok(None),
+    };
+
+    Ok(recruitments_to_dtos(db, vec![recruitment]).await?.pop())
+}
+
+async fn recruitments_to_dtos(
+    db: &DatabaseConnection,
+    recruitments: Vec<Recruitment>,
+) -> Result<Vec<RecruitmentDto>, DbErr> {
+    let pipeline_ids: Vec<i32> = recruitments
+        .iter()
+        .map(|recruitment| recruitment.pipeline_id)
+        .collect();
+    let character_ids: Vec<i32> = recruitments
+        .iter()
+        .map(|recruitment| recruitment.character_id)
+        .collect();
+
+    let pipelines: HashMap<i32, RecruitmentPipeline> =
+        entity::prelude::AuthRecruitmentPipeline::find()
+            .filter(entity::auth_recruitment_pipeline::Column::Id.is_in(pipeline_ids))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|pipeline| (pipeline.id, pipeline))
+            .collect();
+
+    let mut affiliations: HashMap<i32, _> = get_character_affiliations(db, character_ids)
+        .await?
+        .into_iter()
+        .map(|affiliation| (affiliation.character_id, affiliation))
+        .collect();
+
+    let recruitments = recruitments
+        .into_iter()
+        .map(|recruitment| RecruitmentDto {
+            id: recruitment.id,
+            pipeline_id: recruitment.pipeline_id,
+            user_id: recruitment.user_id,
+            character_id: recruitment.character_id,
+            character_info: affiliations.remove(&recruitment.character_id),
+            application_id: recruitment.application_id,
+            status: recruitment.status.into(),
+            granted_scopes: recruitment
+                .granted_scopes
+                .as_deref()
+                .map(|scopes| scopes.split_whitespace().map(String::from).collect())
+                .unwrap_or_default(),
+            missing_scopes: pipelines
+                .get(&recruitment.pipeline_id)
+                .map(|pipeline| get_missing_scopes(pipeline, recruitment.granted_scopes.as_deref()))
+                .unwrap_or_default(),
+            recruiter_id: recruitment.recruiter_id,
+            response_message: recruitment.response_message,
+            created: DateTime::from_naive_utc_and_offset(recruitment.created, Utc),
+            last_updated: DateTime::from_naive_utc_and_offset(recruitment.last_updated, Utc),
+            joined: recruitment
+                .joined
+                .map(|joined| DateTime::from_naive_utc_and_offset(joined, Utc)),
+        })
+        .collect();
+
+    Ok(recruitments)
+}
+
+// Submits the recruitment form as an application to the pipeline's group, the group's filters
+// aren't checked until hand off as prospects usually only meet them once in the corporation
+pub async fn submit_recruitment(
+    db: &DatabaseConnection,
+    user_id: i32,
+    recruitment: NewRecruitmentDto,
+) -> Result<Recruitment, anyhow::Error> {
+    let pipeline = match get_pipeline_by_id(db, recruitment.pipeline_id).await? {
+        Some(pipeline) => pipeline,
+        None => return Err(anyhow!("Recruitment pipeline not found")),
+    };
+
+    if !pipeline.open {
+        return Err(anyhow!("Recruitment pipeline is closed"));
+    }
+
+    let group = match get_group_by_id(db, pipeline.group_id).await? {
+        Some(group) => group,
+        None => return Err(anyhow!("Group does not exist")),
+    };
+
+    match get_character_ownership(db, recruitment.character_id).await? {
+        Some(ownership) if ownership.user_id == user_id => (),
+        _ => return Err(anyhow!("Character is not owned by user")),
+    }
+
+    let character = entity::prelude::EveCharacter::find()
+        .filter(entity::eve_character::Column::CharacterId.eq(recruitment.character_id))
+        .one(db)
+        .await?;
+
+    if character.is_some_and(|character| character.corporation_id == pipeline.corporation_id) {
+        return Err(anyhow!("Character is already in the corporation"));
+    }
+
+    let open_recruitment = entity::prelude::AuthRecruitment::find()
+        .filter(entity::auth_recruitment::Column::PipelineId.eq(pipeline.id))
+        .filter(entity::auth_recruitment::Column::UserId.eq(user_id))
+        .filter(entity::auth_recruitment::Column::Status.is_in(open_recruitment_statuses()))
+        .one(db)
+        .await?;
+
+    if open_recruitment.is_some() {
+        return Err(anyhow!("Recruitment already in progress"));
+    }
+
+    let duplicate_application = entity::prelude::AuthGroupApplication::find()
+        .filter(entity::auth_group_application::Column::GroupId.eq(group.id))
+        .filter(entity::auth_group_application::Column::UserId.eq(user_id))
+        .filter(
+            entity::auth_group_application::Column::Status.eq(GroupApplicationStatus::Outstanding),
+        )
+        .filter(entity::auth_group_application::Column::RequestType.eq(GroupApplicationType::Join))
+        .one(db)
+        .await?;
+
+    if duplicate_application.is_some() {
+        return Err(anyhow!("Application to join already exists"));
+    }
+
+    check_application_cooldown(db, &group, user_id).await?;
+
+    let answers = validate_application_answers(db, group.id, recruitment.answers).await?;
+
+    let application = create_group_application(
+        db,
+        group.id,
+        user_id,
+        GroupApplicationType::Join,
+        recruitment.request_message,
+    )
+    .await?;
+
+    create_application_answers(db, application.id, answers).await?;
+
+    let status = if get_missing_scopes(&pipeline, None).is_empty() {
+        RecruitmentStatus::Vetting
+    } else {
+        RecruitmentStatus::AwaitingScopes
+    };
+
+    let now = Utc::now().naive_utc();
+
+    let recruitment = entity::auth_recruitment::ActiveModel {
+        pipeline_id: Set(pipeline.id),
+        user_id: Set(user_id),
+        character_id: Set(recruitment.character_id),
+        application_id: Set(application.id),
+        status: Set(status),
+        created: Set(now),
+        last_updated: Set(now),
+        ..Default::default()
+    };
+
+    let recruitment = recruitment.insert(db).await?;
+
+    Ok(recruitment)
+}
+
+// Scopes the prospect needs to grant when logging in for the recruitment
+pub async fn get_recruitment_login_scopes(
+    db: &DatabaseConnection,
+    recruitment_id: i32,
+    user_id: i32,
+) -> Result<Vec<String>, anyhow::Error> {
+    let recruitment = match get_recruitment_by_id(db, recruitment_id).await? {
+        Some(recruitment) if recruitment.user_id == user_id => recruitment,
+        _ => return Err(anyhow!("Recruitment not found")),
+    };
+
+    match get_pipeline_by_id(db, recruitment.pipeline_id).await? {
+        Some(pipeline) => Ok(pipeline
+            .required_scopes
+            .split_whitespace()
+            .map(String::from)
+            .collect()),
+        None => Err(anyhow!("Recruitment pipeline not found")),
+    }
+}
+
+// Records the scopes granted when the prospect logged in for the recruitment, moving it between
+// awaiting scopes & vetting depending on whether every required scope was granted
+pub async fn record_recruitment_scopes(
+    db: &DatabaseConnection,
+    recruitment_id: i32,
+    character_id: i32,
+    scopes: &[&str],
+) -> Result<Recruitment, anyhow::Error> {
+    let recruitment = match get_recruitment_by_id(db, recruitment_id).await? {
+        Some(recruitment) => recruitment,
+        None => return Err(anyhow!("Recruitment not found")),
+    };
+
+    if recruitment.character_id != character_id {
+        return Err(anyhow!(
+            "Logged in character does not match the recruitment"
+        ));
+    }
+
+    if !open_recruitment_statuses().contains(&recruitment.status) {
+        return Err(anyhow!("Recruitment is already complete"));
+    }
+
+    let pipeline = match get_pipeline_by_id(db, recruitment.pipeline_id).await? {
+        Some(pipeline) => pipeline,
+        None => return Err(anyhow!("Recruitment pipeline not found")),
+    };
+
+    let granted_scopes = normalize_scopes(scopes);
+    let missing_scopes = get_missing_scopes(&pipeline, Some(&granted_scopes));
+
+    let status = match recruitment.status.clone() {
+        RecruitmentStatus::AwaitingScopes if missing_scopes.is_empty() => {
+            RecruitmentStatus::Vetting
+        }
+        RecruitmentStatus::Vetting if !missing_scopes.is_empty() => {
+            RecruitmentStatus::AwaitingScopes
+        }
+        status => status,
+    };
+
+    let mut recruitment: entity::auth_recruitment::ActiveModel = recruitment.into();
+
+    recruitment.granted_scopes = Set(Some(granted_scopes));
+    recruitment.status = Set(status);
+    recruitment.last_updated = Set(Utc::now().naive_utc());
+
+    let recruitment = recruitment.update(db).await?;
+
+    Ok(recruitment)
+}
+
+// The application is only closed while still outstanding as reviewers may have already
+// responded to it through the group's applications
+async fn close_recruitment_application(
+    db: &DatabaseConnection,
+    application_id: i32,
+    status: GroupApplicationStatus,
+    responder: Option<i32>,
+    response_message: Option<String>,
+) -> Result<(), anyhow::Error> {
+    let application = match get_application_by_id(db, application_id).await? {
+        Some(application) => application,
+        None => return Ok(()),
+    };
+
+    if application.status != GroupApplicationStatus::Outstanding {
+        return Ok(());
+    }
+
+    if status == GroupApplicationStatus::Withdrawn {
+        withdraw_group_application(db, application_id).await?;
+    } else {
+        update_group_application(
+            db,
+            application_id,
+            None,
+            response_message,
+            Some(status),
+            responder,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn update_recruitment_status(
+    db: &DatabaseConnection,
+    recruitment: Recruitment,
+    status: RecruitmentStatus,
+) -> Result<Recruitment, DbErr> {
+    let now = Utc::now().naive_utc();
+
+    let mut recruitment: entity::auth_recruitment::ActiveModel = recruitment.into();
+
+    if status == RecruitmentStatus::Joined {
+        recruitment.joined = Set(Some(now));
+    }
+
+    recruitment.status = Set(status);
+    recruitment.last_updated = Set(now);
+
+    recruitment.update(db).await
+}
+
+// Hands the recruit off to the pipeline's group once their character is seen in the
+// corporation, the application is accepted on behalf of the recruiter who approved them.
+// Returns None if the recruit can't be handed off yet.
+async fn hand_off_recruitment(
+    db: &DatabaseConnection,
+    recruitment: Recruitment,
+    pipeline: &RecruitmentPipeline,
+) -> Result<Option<Recruitment>, anyhow::Error> {
+    let character = entity::prelude::EveCharacter::find()
+        .filter(entity::eve_character::Column::CharacterId.eq(recruitment.character_id))
+        .one(db)
+        .await?;
+
+    let in_corporation =
+        character.is_some_and(|character| character.corporation_id == pipeline.corporation_id);
+
+    if !in_corporation {
+        return Ok(None);
+    }
+
+    // Recruits are handed off once the archived group is restored
+    if get_group_by_id(db, pipeline.group_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    let application_status = get_application_by_id(db, recruitment.application_id)
+        .await?
+        .map(|application| application.status);
+
+    let status = match application_status {
+        Some(GroupApplicationStatus::Outstanding) => {
+            let eligible_user_ids =
+                validate_group_members(db, pipeline.group_id, vec![recruitment.user_id]).await?;
+
+            // Retried until the recruit meets the group's requirements
+            if !eligible_user_ids.contains(&recruitment.user_id) {
+                return Ok(None);
+            }
+
+            insert_group_members(
+                db,
+                pipeline.group_id,
+                eligible_user_ids,
+                recruitment.recruiter_id,
+                None,
+                GroupMembershipReason::Application,
+            )
+            .await?;
+
+            update_group_application(
+                db,
+                recruitment.application_id,
+                None,
+                None,
+                Some(GroupApplicationStatus::Accepted),
+                recruitment.recruiter_id,
+            )
+            .await?;
+
+            RecruitmentStatus::Joined
+        }
+        Some(GroupApplicationStatus::Accepted) => RecruitmentStatus::Joined,
+        Some(GroupApplicationStatus::Withdrawn) => RecruitmentStatus::Withdrawn,
+        // Rejected or expired through the group's applications
+        _ => RecruitmentStatus::Rejected,
+    };
+
+    let recruitment = update_recruitment_status(db, recruitment, status).await?;
+
+    Ok(Some(recruitment))
+}
+
+// Approves or rejects a recruitment being vetted, approved recruits already in the corporation
+// are handed off straight away
+pub async fn respond_to_recruitment(
+    db: &DatabaseConnection,
+    recruitment_id: i32,
+    recruiter_id: i32,
+    approve: bool,
+    response_message: Option<String>,
+) -> Result<Recruitment, anyhow::Error> {
+    let recruitment = match get_recruitment_by_id(db, recruitment_id).await? {
+        Some(recruitment) => recruitment,
+        None => return Err(anyhow!("Recruitment not found")),
+    };
+
+    match recruitment.status {
+        RecruitmentStatus::Vetting => (),
+        RecruitmentStatus::AwaitingScopes => {
+            return Err(anyhow!("Required scopes have not been granted"))
+        }
+        _ => return Err(anyhow!("Recruitment is not being vetted")),
+    }
+
+    if !approve {
+        close_recruitment_application(
+            db,
+            recruitment.application_id,
+            GroupApplicationStatus::Rejected,
+            Some(recruiter_id),
+            response_message.clone(),
+        )
+        .await?;
+    }
+
+    let mut recruitment: entity::auth_recruitment::ActiveModel = recruitment.into();
+
+    recruitment.status = Set(if approve {
+        RecruitmentStatus::Approved
+    } else {
+        RecruitmentStatus::Rejected
+    });
+    recruitment.recruiter_id = Set(Some(recruiter_id));
+    recruitment.response_message = Set(response_message);
+    recruitment.last_updated = Set(Utc::now().naive_utc());
+
+    let recruitment = recruitment.update(db).await?;
+
+    if recruitment.status != RecruitmentStatus::Approved {
+        return Ok(recruitment);
+    }
+
+    // The approval is already saved, a failed hand off is logged & retried by the hand off job
+    let hand_off = async {
+        match get_pipeline_by_id(db, recruitment.pipeline_id).await? {
+            Some(pipeline) => hand_off_recruitment(db, recruitment.clone(), &pipeline).await,
+            None => Ok(None),
+        }
+    };
+
+    match hand_off.await {
+        Ok(Some(recruitment)) => Ok(recruitment),
+        Ok(None) => Ok(recruitment),
+        Err(err) => {
+            println!("Error handing off recruitment {}: {}", recruitment.id, err);
+
+            Ok(recruitment)
+        }
+    }
+}
+
+pub async fn withdraw_recruitment(
+    db: &DatabaseConnection,
+    recruitment_id: i32,
+    user_id: i32,
+) -> Result<Recruitment, anyhow::Error> {
+    let recruitment = match get_recruitment_by_id(db, recruitment_id).await? {
+        Some(recruitment) if recruitment.user_id == user_id => recruitment,
+        _ => return Err(anyhow!("Recruitment not found")),
+    };
+
+    if !open_recruitment_statuses().contains(&recruitment.status) {
+        return Err(anyhow!("Recruitment is already complete"));
+    }
+
+    close_recruitment_application(
+        db,
+        recruitment.application_id,
+        GroupApplicationStatus::Withdrawn,
+        Some(user_id),
+        None,
+    )
+    .await?;
+
+    let recruitment =
+        update_recruitment_status(db, recruitment, RecruitmentStatus::Withdrawn).await?;
+
+    Ok(recruitment)
+}
+
+// Characters of approved recruits whose affiliations need refreshing to detect them joining
+pub async fn get_approved_recruitment_character_ids(
+    db: &DatabaseConnection,
+) -> Result<Vec<i32>, DbErr> {
+    let recruitments = entity::prelude::AuthRecruitment::find()
+        .filter(entity::auth_recruitment::Column::Status.eq(RecruitmentStatus::Approved))
+        .all(db)
+        .await?;
+
+    Ok(recruitments
+        .into_iter()
+        .map(|recruitment| recruitment.character_id)
+        .collect())
+}
+
+// Hands off every approved recruit whose character has joined their pipeline's corporation
+pub async fn hand_off_recruitments(db: &DatabaseConnection) -> Result<u64, anyhow::Error> {
+    let recruitments = entity::prelude::AuthRecruitment::find()
+        .filter(entity::auth_recruitment::Column::Status.eq(RecruitmentStatus::Approved))
+        .all(db)
+        .await?;
+
+    let pipelines: HashMap<i32, RecruitmentPipeline> =
+        entity::prelude::AuthRecruitmentPipeline::find()
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|pipeline| (pipeline.id, pipeline))
+            .collect();
+
+    let mut joined = 0;
+
+    for recruitment in recruitments {
+        let pipeline = match pipelines.get(&recruitment.pipeline_id) {
+            Some(pipeline) => pipeline,
+            None => continue,
+        };
+
+        let recruitment_id = recruitment.id;
+
+        // A recruit failing to be handed off shouldn't hold up the remaining recruits
+        match hand_off_recruitment(db, recruitment, pipeline).await {
+            Ok(Some(recruitment)) if recruitment.status == RecruitmentStatus::Joined => joined += 1,
+            Ok(_) => (),
+            Err(err) => println!("Error handing off recruitment {}: {}", recruitment_id, err),
+        }
+    }
+
+    Ok(joined)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use sea_orm::{
+        ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+        QueryFilter,
+    };
+
+    use super::{
+        create_recruitment_pipeline, get_recruitments, hand_off_recruitments,
+        record_recruitment_scopes, respond_to_recruitment, submit_recruitment,
+        withdraw_recruitment, Recruitment, RecruitmentFilter, RecruitmentPipeline,
+    };
+    use crate::{
+        auth::{
+            data::{groups::create_group, user::create_user},
+            model::{
+                groups::{
+                    GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterScope, GroupType,
+                    NewGroupDto, NewGroupFilterRuleDto,
+                },
+                recruitment::{NewRecruitmentDto, NewRecruitmentPipelineDto},
+            },
+        },
+        test_utils::{create_user_with_main, initialize_test_db, new_group_dto},
+    };
+    use entity::sea_orm_active_enums::{GroupApplicationStatus, RecruitmentStatus};
+
+    const REQUIRED_SCOPES: [&str; 2] = [
+        "esi-wallet.read_character_wallet.v1",
+        "esi-assets.read_assets.v1",
+    ];
+
+    fn new_recruitment_group(name: &str) -> NewGroupDto {
+        NewGroupDto {
+            group_type: GroupType::Apply,
+            ..new_group_dto(name)
+        }
+    }
+
+    // The corporation is created from the mocked ESI response
+    async fn create_pipeline(
+        db: &DatabaseConnection,
+        group_id: i32,
+    ) -> Result<RecruitmentPipeline, anyhow::Error> {
+        create_recruitment_pipeline(
+            db,
+            NewRecruitmentPipelineDto {
+                name: "Black Rose".to_string(),
+                corporation_id: 98785281,
+                group_id,
+                required_scopes: REQUIRED_SCOPES
+                    .iter()
+                    .map(|scope| scope.to_string())
+                    .collect(),
+                open: true,
+            },
+        )
+        .await
+    }
+
+    // The mocked character is outside of the pipeline's corporation
+    async fn create_prospect(db: &DatabaseConnection) -> Result<i32, anyhow::Error> {
+        Ok(create_user_with_main(db, 180548812).await?)
+    }
+
+    fn new_recruitment(pipeline_id: i32) -> NewRecruitmentDto {
+        NewRecruitmentDto {
+            pipeline_id,
+            character_id: 180548812,
+            request_message: Some("Looking for a home".to_string()),
+            answers: vec![],
+        }
+    }
+
+    // Submits a recruitment for the prospect and grants every required scope
+    async fn submit_vetted_recruitment(
+        db: &DatabaseConnection,
+        pipeline_id: i32,
+        user_id: i32,
+    ) -> Result<Recruitment, anyhow::Error> {
+        let recruitment = submit_recruitment(db, user_id, new_recruitment(pipeline_id)).await?;
+
+        record_recruitment_scopes(db, recruitment.id, 180548812, &REQUIRED_SCOPES).await
+    }
+
+    async fn join_pipeline_corporation(db: &DatabaseConnection) -> Result<(), DbErr> {
+        let mut character: entity::eve_character::ActiveModel =
+            entity::prelude::EveCharacter::find()
+                .filter(entity::eve_character::Column::CharacterId.eq(180548812))
+                .one(db)
+                .await?
+                .unwrap()
+                .into();
+
+        character.corporation_id = Set(98785281);
+        character.update(db).await?;
+
+        Ok(())
+    }
+
+    async fn get_application_status(
+        db: &DatabaseConnection,
+        application_id: i32,
+    ) -> Result<GroupApplicationStatus, DbErr> {
+        let application = entity::prelude::AuthGroupApplication::find_by_id(application_id)
+            .one(db)
+            .await?
+            .unwrap();
+
+        Ok(application.status)
+    }
+
+    async fn is_group_member(
+        db: &DatabaseConnection,
+        group_id: i32,
+        user_id: i32,
+    ) -> Result<bool, DbErr> {
+        let membership = entity::prelude::AuthGroupUser::find()
+            .filter(entity::auth_group_user::Column::GroupId.eq(group_id))
+            .filter(entity::auth_group_user::Column::UserId.eq(user_id))
+            .one(db)
+            .await?;
+
+        Ok(membership.is_some())
+    }
+
+    #[tokio::test]
+    async fn recruitment_is_handed_off_once_in_corporation() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_recruitment_group("Members")).await?;
+        let pipeline = create_pipeline(&db, group.id).await?;
+
+        let recruiter_id = create_user(&db).await?;
+        let user_id = create_prospect(&db).await?;
+
+        // Prospects can only recruit characters they own
+        let result = submit_recruitment(&db, recruiter_id, new_recruitment(pipeline.id)).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Character is not owned by user")
+        );
+
+        let recruitment = submit_recruitment(&db, user_id, new_recruitment(pipeline.id)).await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::AwaitingScopes);
+
+        let result = submit_recruitment(&db, user_id, new_recruitment(pipeline.id)).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Recruitment already in progress")
+        );
+
+        // Recruiters can't vet prospects until every required scope is granted
+        let result = respond_to_recruitment(&db, recruitment.id, recruiter_id, true, None).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Required scopes have not been granted")
+        );
+
+        let recruitment =
+            record_recruitment_scopes(&db, recruitment.id, 180548812, &REQUIRED_SCOPES).await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::Vetting);
+
+        // The character isn't in the corporation yet so the recruit waits to be handed off
+        let recruitment =
+            respond_to_recruitment(&db, recruitment.id, recruiter_id, true, None).await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::Approved);
+        assert_eq!(hand_off_recruitments(&db).await?, 0);
+
+        join_pipeline_corporation(&db).await?;
+
+        assert_eq!(hand_off_recruitments(&db).await?, 1);
+
+        let recruitments = get_recruitments(
+            &db,
+            RecruitmentFilter {
+                user_id: Some(user_id),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        assert_eq!(recruitments.len(), 1);
+        assert!(recruitments[0].joined.is_some());
+        assert!(recruitments[0].missing_scopes.is_empty());
+
+        assert!(is_group_member(&db, group.id, user_id).await?);
+        assert_eq!(
+            get_application_status(&db, recruitment.application_id).await?,
+            GroupApplicationStatus::Accepted
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn recruitment_moves_between_awaiting_scopes_and_vetting() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_recruitment_group("Members")).await?;
+        let pipeline = create_pipeline(&db, group.id).await?;
+
+        let user_id = create_prospect(&db).await?;
+
+        let recruitment = submit_recruitment(&db, user_id, new_recruitment(pipeline.id)).await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::AwaitingScopes);
+
+        // Scopes must be granted by the recruited character
+        let result =
+            record_recruitment_scopes(&db, recruitment.id, 2114794365, &REQUIRED_SCOPES).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Logged in character does not match the recruitment")
+        );
+
+        let recruitment = record_recruitment_scopes(
+            &db,
+            recruitment.id,
+            180548812,
+            &["esi-assets.read_assets.v1"],
+        )
+        .await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::AwaitingScopes);
+        assert_eq!(
+            recruitment.granted_scopes.as_deref(),
+            Some("esi-assets.read_assets.v1")
+        );
+
+        // Scopes are matched regardless of the order they were granted in
+        let recruitment = record_recruitment_scopes(
+            &db,
+            recruitment.id,
+            180548812,
+            &[
+                "esi-assets.read_assets.v1",
+                "esi-wallet.read_character_wallet.v1",
+            ],
+        )
+        .await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::Vetting);
+
+        // Logging in again without every scope sends the recruitment back to awaiting scopes
+        let recruitment = record_recruitment_scopes(&db, recruitment.id, 180548812, &[]).await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::AwaitingScopes);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejected_recruitment_is_not_handed_off() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_recruitment_group("Members")).await?;
+        let pipeline = create_pipeline(&db, group.id).await?;
+
+        let recruiter_id = create_user(&db).await?;
+        let user_id = create_prospect(&db).await?;
+
+        let recruitment = submit_vetted_recruitment(&db, pipeline.id, user_id).await?;
+
+        let recruitment = respond_to_recruitment(
+            &db,
+            recruitment.id,
+            recruiter_id,
+            false,
+            Some("Not a good fit".to_string()),
+        )
+        .await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::Rejected);
+        assert_eq!(recruitment.recruiter_id, Some(recruiter_id));
+        assert_eq!(
+            recruitment.response_message.as_deref(),
+            Some("Not a good fit")
+        );
+
+        let application =
+            entity::prelude::AuthGroupApplication::find_by_id(recruitment.application_id)
+                .one(&db)
+                .await?
+                .unwrap();
+        assert_eq!(application.status, GroupApplicationStatus::Rejected);
+        assert_eq!(application.responder, Some(recruiter_id));
+
+        // Rejected recruitments are complete and can't be vetted again
+        let result = respond_to_recruitment(&db, recruitment.id, recruiter_id, true, None).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Recruitment is not being vetted")
+        );
+
+        join_pipeline_corporation(&db).await?;
+
+        assert_eq!(hand_off_recruitments(&db).await?, 0);
+        assert!(!is_group_member(&db, group.id, user_id).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn withdrawn_recruitment_withdraws_application() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let group = create_group(&db, new_recruitment_group("Members")).await?;
+        let pipeline = create_pipeline(&db, group.id).await?;
+
+        let other_user_id = create_user(&db).await?;
+        let user_id = create_prospect(&db).await?;
+
+        let recruitment = submit_vetted_recruitment(&db, pipeline.id, user_id).await?;
+
+        // Only the prospect can withdraw their recruitment
+        let result = withdraw_recruitment(&db, recruitment.id, other_user_id).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Recruitment not found")
+        );
+
+        let recruitment = withdraw_recruitment(&db, recruitment.id, user_id).await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::Withdrawn);
+        assert_eq!(
+            get_application_status(&db, recruitment.application_id).await?,
+            GroupApplicationStatus::Withdrawn
+        );
+
+        let result = withdraw_recruitment(&db, recruitment.id, user_id).await;
+        assert_eq!(
+            result.err().map(|err| err.to_string()).as_deref(),
+            Some("Recruitment is already complete")
+        );
+
+        // The prospect may start a new recruitment once the previous one is withdrawn
+        let recruitment = submit_recruitment(&db, user_id, new_recruitment(pipeline.id)).await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::AwaitingScopes);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hand_off_retried_until_recruit_meets_group_filters() -> Result<(), anyhow::Error> {
+        let db = initialize_test_db().await?;
+
+        let prerequisite = create_group(&db, new_group_dto("Vetted")).await?;
+        let group = create_group(
+            &db,
+            NewGroupDto {
+                filter_rules: vec![NewGroupFilterRuleDto {
+                    criteria: GroupFilterCriteria::Group,
+                    criteria_type: GroupFilterCriteriaType::Is,
+                    criteria_value: prerequisite.id.to_string(),
+                    scope: GroupFilterScope::AnyCharacter,
+                }],
+                ..new_recruitment_group("Members")
+            },
+        )
+        .await?;
+        let pipeline = create_pipeline(&db, group.id).await?;
+
+        let recruiter_id = create_user(&db).await?;
+        let user_id = create_prospect(&db).await?;
+
+        let recruitment = submit_vetted_recruitment(&db, pipeline.id, user_id).await?;
+
+        join_pipeline_corporation(&db).await?;
+
+        // The recruit is in the corporation but doesn't yet meet the group's filters
+        let recruitment =
+            respond_to_recruitment(&db, recruitment.id, recruiter_id, true, None).await?;
+        assert_eq!(recruitment.status, RecruitmentStatus::Approved);
+        assert_eq!(hand_off_recruitments(&db).await?, 0);
+
+        assert!(!is_group_member(&db, group.id, user_id).await?);
+        assert_eq!(
+            get_application_status(&db, recruitment.application_id).await?,
+            GroupApplicationStatus::Outstanding
+        );
+
+        entity::auth_group_user::ActiveModel {
+            group_id: Set(prerequisite.id),
+            user_id: Set(user_id),
+            created: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+
+        assert_eq!(hand_off_recruitments(&db).await?, 1);
+        assert!(is_group_member(&db, group.id, user_id).await?);
+        assert_eq!(
+            get_application_status(&db, recruitment.application_id).await?,
+            GroupApplicationStatus::Accepted
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,17 @@
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
 use sea_orm::DbErr;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use entity::auth_user::Model as User;
 use entity::auth_user_character_ownership::Model as UserCharacterOwnership;
 
-use crate::auth::model::user::{UserAffiliations, UserGroups};
+use crate::auth::data::groups::dependencies::get_group_inheritance;
+use crate::auth::model::user::{
+    UserAffiliations, UserCharacterAffiliation, UserGroupMembership, UserGroups,
+};
 use crate::eve::service::affiliation::get_character_affiliations;
 
 pub async fn create_user(db: &DatabaseConnection) -> Result<i32, DbErr> {
@@ -170,21 +173,18 @@ pub async fn bulk_get_user_affiliations(
             .or_insert(UserAffiliations {
                 user_id: ownership.user_id,
                 characters: Vec::new(),
-                corporations: Vec::new(),
-                alliances: Vec::new(),
             });
     }
 
     for affiliation in &affiliations {
         if let Some(ownership) = ownerships_map.get(&affiliation.character_id) {
             if let Some(user_affiliation) = user_affiliations.get_mut(&ownership.user_id) {
-                user_affiliation.characters.push(affiliation.character_id);
-                user_affiliation
-                    .corporations
-                    .push(affiliation.corporation_id);
-                if let Some(alliance_id) = affiliation.alliance_id {
-                    user_affiliation.alliances.push(alliance_id);
-                }
+                user_affiliation.characters.push(UserCharacterAffiliation {
+                    character_id: affiliation.character_id,
+                    corporation_id: affiliation.corporation_id,
+                    alliance_id: affiliation.alliance_id,
+                    main: ownership.main,
+                });
             }
         }
     }
@@ -194,29 +194,97 @@ pub async fn bulk_get_user_affiliations(
     Ok(user_affiliations)
 }
 
+// Gets the effective group memberships of users, memberships are inherited between subgroups &
+// their parent group in the direction set on the subgroup
 pub async fn bulk_get_user_groups(
     db: &DatabaseConnection,
     user_ids: Vec<i32>,
 ) -> Result<Vec<UserGroups>, DbErr> {
+    // Memberships of archived groups are frozen & don't count until the group is restored
+    let archived_group_ids: HashSet<i32> = entity::prelude::AuthGroup::find()
+        .filter(entity::auth_group::Column::Archived.is_not_null())
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|group| group.id)
+        .collect();
+
     let user_groups: Vec<entity::auth_group_user::Model> = entity::prelude::AuthGroupUser::find()
-        .filter(entity::auth_group_user::Column::UserId.is_in(user_ids))
+        .filter(entity::auth_group_user::Column::UserId.is_in(user_ids.clone()))
         .all(db)
-        .await?;
+        .await?
+        .into_iter()
+        .filter(|user_group| !archived_group_ids.contains(&user_group.group_id))
+        .collect();
 
-    let mut user_groups_map: HashMap<i32, UserGroups> = HashMap::new();
+    let inheritance = get_group_inheritance(db).await?;
 
-    for user_group in user_groups {
+    // Users without any memberships are included so group filter rules evaluate them as well
+    let mut user_groups_map: HashMap<i32, HashMap<i32, Option<i32>>> = user_ids
+        .into_iter()
+        .map(|user_id| (user_id, HashMap::new()))
+        .collect();
+
+    // Inherited memberships expire along with the direct membership they're inherited from
+    let expiries: HashMap<(i32, i32), Option<NaiveDateTime>> = user_groups
+        .iter()
+        .map(|user_group| {
+            (
+                (user_group.user_id, user_group.group_id),
+                user_group.expires,
+            )
+        })
+        .collect();
+
+    for user_group in &user_groups {
         user_groups_map
             .entry(user_group.user_id)
-            .or_insert_with(|| UserGroups {
-                user_id: user_group.user_id,
-                groups: Vec::new(),
-            })
-            .groups
-            .push(user_group.group_id);
+            .or_default()
+            .insert(user_group.group_id, None);
     }
 
-    let user_groups: Vec<UserGroups> = user_groups_map.into_values().collect();
+    for user_group in user_groups {
+        let memberships = user_groups_map.entry(user_group.user_id).or_default();
+
+        let mut queue = vec![user_group.group_id];
+        let mut visited = HashSet::new();
+
+        while let Some(group_id) = queue.pop() {
+            if !visited.insert(group_id) {
+                continue;
+            }
+
+            for inherited_group_id in inheritance.get(&group_id).into_iter().flatten() {
+                if archived_group_ids.contains(inherited_group_id) {
+                    continue;
+                }
+
+                memberships
+                    .entry(*inherited_group_id)
+                    .or_insert(Some(user_group.group_id));
+
+                queue.push(*inherited_group_id);
+            }
+        }
+    }
+
+    let user_groups: Vec<UserGroups> = user_groups_map
+        .into_iter()
+        .map(|(user_id, memberships)| UserGroups {
+            user_id,
+            groups: memberships
+                .into_iter()
+                .map(|(group_id, inherited_from)| UserGroupMembership {
+                    group_id,
+                    inherited_from,
+                    expires: expiries
+                        .get(&(user_id, inherited_from.unwrap_or(group_id)))
+                        .copied()
+                        .flatten(),
+                })
+                .collect(),
+        })
+        .collect();
 
     Ok(user_groups)
 }
@@ -0,0 +1,137 @@
+use sea_orm::DatabaseConnection;
+use std::{env, time::Duration};
+
+use crate::{
+    auth::data::{
+        groups::{
+            applications::expire_applications, members::remove_expired_members,
+            purge_archived_groups,
+        },
+        recruitment::{get_approved_recruitment_character_ids, hand_off_recruitments},
+    },
+    eve::service::{
+        affiliation::{update_affiliation, update_stale_affiliations},
+        roles::refresh_stored_character_roles,
+    },
+};
+
+const MEMBERSHIP_EXPIRY_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const ARCHIVED_GROUP_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const APPLICATION_EXPIRY_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const RECRUITMENT_HANDOFF_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const AFFILIATION_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+const DEFAULT_GROUP_ARCHIVE_RETENTION_DAYS: i64 = 30;
+
+// Periodically removes group memberships which have passed their expiry
+pub fn spawn_membership_expiry_job(db: DatabaseConnection) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(MEMBERSHIP_EXPIRY_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match remove_expired_members(&db).await {
+                Ok(0) => (),
+                Ok(removed) => println!("Removed {} expired group memberships", removed),
+                Err(err) => println!("Error removing expired group memberships: {}", err),
+            }
+        }
+    });
+}
+
+// Periodically deletes groups which have been archived for longer than GROUP_ARCHIVE_RETENTION_DAYS
+pub fn spawn_archived_group_purge_job(db: DatabaseConnection) {
+    let retention_days = env::var("GROUP_ARCHIVE_RETENTION_DAYS")
+        .ok()
+        .and_then(|days| days.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_GROUP_ARCHIVE_RETENTION_DAYS);
+
+    let retention = chrono::Duration::try_days(retention_days)
+        .unwrap_or_else(|| chrono::Duration::days(DEFAULT_GROUP_ARCHIVE_RETENTION_DAYS));
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ARCHIVED_GROUP_PURGE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match purge_archived_groups(&db, retention).await {
+                Ok(0) => (),
+                Ok(purged) => println!("Purged {} archived groups", purged),
+                Err(err) => println!("Error purging archived groups: {}", err),
+            }
+        }
+    });
+}
+
+// Periodically expires outstanding applications for groups with an application expiry set
+pub fn spawn_application_expiry_job(db: DatabaseConnection) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(APPLICATION_EXPIRY_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match expire_applications(&db).await {
+                Ok(0) => (),
+                Ok(expired) => println!("Expired {} group applications", expired),
+                Err(err) => println!("Error expiring group applications: {}", err),
+            }
+        }
+    });
+}
+
+// Periodically refreshes the affiliations of approved recruits & hands off those who joined
+pub fn spawn_recruitment_handoff_job(db: DatabaseConnection) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(RECRUITMENT_HANDOFF_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match get_approved_recruitment_character_ids(&db).await {
+                Ok(character_ids) if character_ids.is_empty() => continue,
+                Ok(character_ids) => {
+                    if let Err(err) = update_affiliation(&db, character_ids).await {
+                        println!("Error updating recruit affiliations: {}", err);
+                    }
+                }
+                Err(err) => println!("Error getting approved recruits: {}", err),
+            }
+
+            match hand_off_recruitments(&db).await {
+                Ok(0) => (),
+                Ok(joined) => println!("Handed off {} recruits", joined),
+                Err(err) => println!("Error handing off recruits: {}", err),
+            }
+        }
+    });
+}
+
+// Periodically refreshes the affiliations of user owned characters followed by the corporation
+// roles & titles of characters with a stored refresh token
+pub fn spawn_affiliation_refresh_job(db: DatabaseConnection) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(AFFILIATION_REFRESH_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            // Affiliations are refreshed first as a corporation change clears the stored roles
+            let max_age = chrono::Duration::from_std(AFFILIATION_REFRESH_INTERVAL).unwrap();
+
+            match update_stale_affiliations(&db, max_age).await {
+                Ok(0) => (),
+                Ok(updated) => println!("Updated {} character affiliations", updated),
+                Err(err) => println!("Error updating character affiliations: {}", err),
+            }
+
+            match refresh_stored_character_roles(&db).await {
+                Ok(0) => (),
+                Ok(refreshed) => println!("Refreshed roles of {} characters", refreshed),
+                Err(err) => println!("Error refreshing character roles: {}", err),
+            }
+        }
+    });
+}
@@ -1,4 +1,5 @@
 pub mod data;
+pub mod jobs;
 pub mod model;
 pub mod permissions;
 pub mod route;
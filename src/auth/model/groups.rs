@@ -61,12 +61,18 @@ impl From<entity::sea_orm_active_enums::GroupFilterType> for GroupFilterType {
     }
 }
 
-#[derive(Serialize, Deserialize, ToSchema, Copy, Clone)]
+#[derive(Serialize, Deserialize, ToSchema, Copy, Clone, PartialEq)]
 pub enum GroupFilterCriteria {
     Group,
     Corporation,
     Alliance,
     Role,
+    // In-game corporation role as provided by ESI, e.g. Director or Personnel_Manager
+    CorporationRole,
+    // In-game corporation title name
+    CorporationTitle,
+    // Id of an organization, matches characters in any of its alliances or corporations
+    Organization,
 }
 
 impl From<GroupFilterCriteria> for entity::sea_orm_active_enums::GroupFilterCriteria {
@@ -80,6 +86,15 @@ impl From<GroupFilterCriteria> for entity::sea_orm_active_enums::GroupFilterCrit
                 entity::sea_orm_active_enums::GroupFilterCriteria::Alliance
             }
             GroupFilterCriteria::Role => entity::sea_orm_active_enums::GroupFilterCriteria::Role,
+            GroupFilterCriteria::CorporationRole => {
+                entity::sea_orm_active_enums::GroupFilterCriteria::CorporationRole
+            }
+            GroupFilterCriteria::CorporationTitle => {
+                entity::sea_orm_active_enums::GroupFilterCriteria::CorporationTitle
+            }
+            GroupFilterCriteria::Organization => {
+                entity::sea_orm_active_enums::GroupFilterCriteria::Organization
+            }
         }
     }
 }
@@ -95,6 +110,15 @@ impl From<entity::sea_orm_active_enums::GroupFilterCriteria> for GroupFilterCrit
                 GroupFilterCriteria::Alliance
             }
             entity::sea_orm_active_enums::GroupFilterCriteria::Role => GroupFilterCriteria::Role,
+            entity::sea_orm_active_enums::GroupFilterCriteria::CorporationRole => {
+                GroupFilterCriteria::CorporationRole
+            }
+            entity::sea_orm_active_enums::GroupFilterCriteria::CorporationTitle => {
+                GroupFilterCriteria::CorporationTitle
+            }
+            entity::sea_orm_active_enums::GroupFilterCriteria::Organization => {
+                GroupFilterCriteria::Organization
+            }
         }
     }
 }
@@ -145,6 +169,85 @@ impl From<entity::sea_orm_active_enums::GroupFilterCriteriaType> for GroupFilter
     }
 }
 
+// Which of a user's characters a filter rule is checked against
+// The criteria type is applied after the scope, e.g. IsNot with AnyCharacter only passes if none of the characters match
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Default)]
+pub enum GroupFilterScope {
+    #[default]
+    AnyCharacter,
+    MainCharacter,
+    AllCharacters,
+}
+
+impl From<GroupFilterScope> for entity::sea_orm_active_enums::GroupFilterScope {
+    fn from(item: GroupFilterScope) -> Self {
+        match item {
+            GroupFilterScope::AnyCharacter => {
+                entity::sea_orm_active_enums::GroupFilterScope::AnyCharacter
+            }
+            GroupFilterScope::MainCharacter => {
+                entity::sea_orm_active_enums::GroupFilterScope::MainCharacter
+            }
+            GroupFilterScope::AllCharacters => {
+                entity::sea_orm_active_enums::GroupFilterScope::AllCharacters
+            }
+        }
+    }
+}
+
+impl From<entity::sea_orm_active_enums::GroupFilterScope> for GroupFilterScope {
+    fn from(item: entity::sea_orm_active_enums::GroupFilterScope) -> Self {
+        match item {
+            entity::sea_orm_active_enums::GroupFilterScope::AnyCharacter => {
+                GroupFilterScope::AnyCharacter
+            }
+            entity::sea_orm_active_enums::GroupFilterScope::MainCharacter => {
+                GroupFilterScope::MainCharacter
+            }
+            entity::sea_orm_active_enums::GroupFilterScope::AllCharacters => {
+                GroupFilterScope::AllCharacters
+            }
+        }
+    }
+}
+
+// Direction membership is inherited between a subgroup & its parent group, set on the subgroup
+// Inherited memberships don't require the user to pass the filters of the group they're inherited into
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Default)]
+pub enum GroupMembershipInheritance {
+    // Members of the subgroup are also members of the parent group
+    #[default]
+    ChildToParent,
+    // Members of the parent group are also members of the subgroup
+    ParentToChild,
+}
+
+impl From<GroupMembershipInheritance> for entity::sea_orm_active_enums::GroupMembershipInheritance {
+    fn from(item: GroupMembershipInheritance) -> Self {
+        match item {
+            GroupMembershipInheritance::ChildToParent => {
+                entity::sea_orm_active_enums::GroupMembershipInheritance::ChildToParent
+            }
+            GroupMembershipInheritance::ParentToChild => {
+                entity::sea_orm_active_enums::GroupMembershipInheritance::ParentToChild
+            }
+        }
+    }
+}
+
+impl From<entity::sea_orm_active_enums::GroupMembershipInheritance> for GroupMembershipInheritance {
+    fn from(item: entity::sea_orm_active_enums::GroupMembershipInheritance) -> Self {
+        match item {
+            entity::sea_orm_active_enums::GroupMembershipInheritance::ChildToParent => {
+                GroupMembershipInheritance::ChildToParent
+            }
+            entity::sea_orm_active_enums::GroupMembershipInheritance::ParentToChild => {
+                GroupMembershipInheritance::ParentToChild
+            }
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq)]
 pub enum GroupApplicationType {
     Join,
@@ -178,6 +281,8 @@ pub enum GroupApplicationStatus {
     Outstanding,
     Accepted,
     Rejected,
+    Expired,
+    Withdrawn,
 }
 
 impl From<GroupApplicationStatus> for entity::sea_orm_active_enums::GroupApplicationStatus {
@@ -192,6 +297,12 @@ impl From<GroupApplicationStatus> for entity::sea_orm_active_enums::GroupApplica
             GroupApplicationStatus::Rejected => {
                 entity::sea_orm_active_enums::GroupApplicationStatus::Rejected
             }
+            GroupApplicationStatus::Expired => {
+                entity::sea_orm_active_enums::GroupApplicationStatus::Expired
+            }
+            GroupApplicationStatus::Withdrawn => {
+                entity::sea_orm_active_enums::GroupApplicationStatus::Withdrawn
+            }
         }
     }
 }
@@ -208,6 +319,183 @@ impl From<entity::sea_orm_active_enums::GroupApplicationStatus> for GroupApplica
             entity::sea_orm_active_enums::GroupApplicationStatus::Rejected => {
                 GroupApplicationStatus::Rejected
             }
+            entity::sea_orm_active_enums::GroupApplicationStatus::Expired => {
+                GroupApplicationStatus::Expired
+            }
+            entity::sea_orm_active_enums::GroupApplicationStatus::Withdrawn => {
+                GroupApplicationStatus::Withdrawn
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+pub enum GroupInvitationStatus {
+    Pending,
+    Accepted,
+    Declined,
+    Revoked,
+}
+
+impl From<GroupInvitationStatus> for entity::sea_orm_active_enums::GroupInvitationStatus {
+    fn from(item: GroupInvitationStatus) -> Self {
+        match item {
+            GroupInvitationStatus::Pending => {
+                entity::sea_orm_active_enums::GroupInvitationStatus::Pending
+            }
+            GroupInvitationStatus::Accepted => {
+                entity::sea_orm_active_enums::GroupInvitationStatus::Accepted
+            }
+            GroupInvitationStatus::Declined => {
+                entity::sea_orm_active_enums::GroupInvitationStatus::Declined
+            }
+            GroupInvitationStatus::Revoked => {
+                entity::sea_orm_active_enums::GroupInvitationStatus::Revoked
+            }
+        }
+    }
+}
+
+impl From<entity::sea_orm_active_enums::GroupInvitationStatus> for GroupInvitationStatus {
+    fn from(item: entity::sea_orm_active_enums::GroupInvitationStatus) -> Self {
+        match item {
+            entity::sea_orm_active_enums::GroupInvitationStatus::Pending => {
+                GroupInvitationStatus::Pending
+            }
+            entity::sea_orm_active_enums::GroupInvitationStatus::Accepted => {
+                GroupInvitationStatus::Accepted
+            }
+            entity::sea_orm_active_enums::GroupInvitationStatus::Declined => {
+                GroupInvitationStatus::Declined
+            }
+            entity::sea_orm_active_enums::GroupInvitationStatus::Revoked => {
+                GroupInvitationStatus::Revoked
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+pub enum GroupMembershipAction {
+    Joined,
+    Removed,
+}
+
+impl From<GroupMembershipAction> for entity::sea_orm_active_enums::GroupMembershipAction {
+    fn from(item: GroupMembershipAction) -> Self {
+        match item {
+            GroupMembershipAction::Joined => {
+                entity::sea_orm_active_enums::GroupMembershipAction::Joined
+            }
+            GroupMembershipAction::Removed => {
+                entity::sea_orm_active_enums::GroupMembershipAction::Removed
+            }
+        }
+    }
+}
+
+impl From<entity::sea_orm_active_enums::GroupMembershipAction> for GroupMembershipAction {
+    fn from(item: entity::sea_orm_active_enums::GroupMembershipAction) -> Self {
+        match item {
+            entity::sea_orm_active_enums::GroupMembershipAction::Joined => {
+                GroupMembershipAction::Joined
+            }
+            entity::sea_orm_active_enums::GroupMembershipAction::Removed => {
+                GroupMembershipAction::Removed
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+pub enum GroupMembershipReason {
+    // Added or removed by an admin or group manager, or the user joining or leaving themselves
+    Manual,
+    Application,
+    // Added or removed by the group's filters being re-evaluated
+    Reconciliation,
+    Expiry,
+    GroupDeleted,
+    // Joined by accepting an invitation from a group manager
+    Invitation,
+}
+
+impl From<GroupMembershipReason> for entity::sea_orm_active_enums::GroupMembershipReason {
+    fn from(item: GroupMembershipReason) -> Self {
+        match item {
+            GroupMembershipReason::Manual => {
+                entity::sea_orm_active_enums::GroupMembershipReason::Manual
+            }
+            GroupMembershipReason::Application => {
+                entity::sea_orm_active_enums::GroupMembershipReason::Application
+            }
+            GroupMembershipReason::Reconciliation => {
+                entity::sea_orm_active_enums::GroupMembershipReason::Reconciliation
+            }
+            GroupMembershipReason::Expiry => {
+                entity::sea_orm_active_enums::GroupMembershipReason::Expiry
+            }
+            GroupMembershipReason::GroupDeleted => {
+                entity::sea_orm_active_enums::GroupMembershipReason::GroupDeleted
+            }
+            GroupMembershipReason::Invitation => {
+                entity::sea_orm_active_enums::GroupMembershipReason::Invitation
+            }
+        }
+    }
+}
+
+impl From<entity::sea_orm_active_enums::GroupMembershipReason> for GroupMembershipReason {
+    fn from(item: entity::sea_orm_active_enums::GroupMembershipReason) -> Self {
+        match item {
+            entity::sea_orm_active_enums::GroupMembershipReason::Manual => {
+                GroupMembershipReason::Manual
+            }
+            entity::sea_orm_active_enums::GroupMembershipReason::Application => {
+                GroupMembershipReason::Application
+            }
+            entity::sea_orm_active_enums::GroupMembershipReason::Reconciliation => {
+                GroupMembershipReason::Reconciliation
+            }
+            entity::sea_orm_active_enums::GroupMembershipReason::Expiry => {
+                GroupMembershipReason::Expiry
+            }
+            entity::sea_orm_active_enums::GroupMembershipReason::GroupDeleted => {
+                GroupMembershipReason::GroupDeleted
+            }
+            entity::sea_orm_active_enums::GroupMembershipReason::Invitation => {
+                GroupMembershipReason::Invitation
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+pub enum GroupQuestionType {
+    Text,
+    Choice,
+    Boolean,
+    Number,
+}
+
+impl From<GroupQuestionType> for entity::sea_orm_active_enums::GroupQuestionType {
+    fn from(item: GroupQuestionType) -> Self {
+        match item {
+            GroupQuestionType::Text => entity::sea_orm_active_enums::GroupQuestionType::Text,
+            GroupQuestionType::Choice => entity::sea_orm_active_enums::GroupQuestionType::Choice,
+            GroupQuestionType::Boolean => entity::sea_orm_active_enums::GroupQuestionType::Boolean,
+            GroupQuestionType::Number => entity::sea_orm_active_enums::GroupQuestionType::Number,
+        }
+    }
+}
+
+impl From<entity::sea_orm_active_enums::GroupQuestionType> for GroupQuestionType {
+    fn from(item: entity::sea_orm_active_enums::GroupQuestionType) -> Self {
+        match item {
+            entity::sea_orm_active_enums::GroupQuestionType::Text => GroupQuestionType::Text,
+            entity::sea_orm_active_enums::GroupQuestionType::Choice => GroupQuestionType::Choice,
+            entity::sea_orm_active_enums::GroupQuestionType::Boolean => GroupQuestionType::Boolean,
+            entity::sea_orm_active_enums::GroupQuestionType::Number => GroupQuestionType::Number,
         }
     }
 }
@@ -217,6 +505,7 @@ pub enum GroupOwnerType {
     Auth,
     Corporation,
     Alliance,
+    Organization,
 }
 
 impl From<GroupOwnerType> for entity::sea_orm_active_enums::GroupOwnerType {
@@ -227,6 +516,9 @@ impl From<GroupOwnerType> for entity::sea_orm_active_enums::GroupOwnerType {
                 entity::sea_orm_active_enums::GroupOwnerType::Corporation
             }
             GroupOwnerType::Alliance => entity::sea_orm_active_enums::GroupOwnerType::Alliance,
+            GroupOwnerType::Organization => {
+                entity::sea_orm_active_enums::GroupOwnerType::Organization
+            }
         }
     }
 }
@@ -239,6 +531,9 @@ impl From<entity::sea_orm_active_enums::GroupOwnerType> for GroupOwnerType {
                 GroupOwnerType::Corporation
             }
             entity::sea_orm_active_enums::GroupOwnerType::Alliance => GroupOwnerType::Alliance,
+            entity::sea_orm_active_enums::GroupOwnerType::Organization => {
+                GroupOwnerType::Organization
+            }
         }
     }
 }
@@ -249,6 +544,33 @@ pub struct GroupOwnerInfo {
     pub name: String,
 }
 
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+pub struct GroupOwnerDto {
+    pub owner_type: GroupOwnerType,
+    pub owner_id: i32,
+    // Corporation CEO or CEO of the alliance's executor corporation, only leaders can name delegates
+    pub leader: bool,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+pub struct GroupCategoryDto {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub ordering: i32,
+}
+
+impl From<entity::auth_group_category::Model> for GroupCategoryDto {
+    fn from(model: entity::auth_group_category::Model) -> Self {
+        GroupCategoryDto {
+            id: model.id,
+            name: model.name,
+            description: model.description,
+            ordering: model.ordering,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct GroupDto {
     pub id: i32,
@@ -258,9 +580,75 @@ pub struct GroupDto {
     pub owner_type: GroupOwnerType,
     pub owner_info: Option<GroupOwnerInfo>,
     pub member_count: Option<u64>,
+    pub parent_id: Option<i32>,
+    pub membership_inheritance: GroupMembershipInheritance,
+    pub category: Option<GroupCategoryDto>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ArchivedGroupDto {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub owner_type: GroupOwnerType,
+    pub owner_id: Option<i32>,
+    pub archived: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GroupMemberDto {
+    pub id: i32,
+    pub character_id: i32,
+    pub character_name: String,
+    pub created: DateTime<Utc>,
+    pub expires: Option<DateTime<Utc>>,
+    // User who added the member or accepted their application
+    pub added_by: Option<i32>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
+pub struct GroupMembershipHistoryDto {
+    pub id: i32,
+    pub group_id: i32,
+    pub user_id: i32,
+    pub action: GroupMembershipAction,
+    pub reason: GroupMembershipReason,
+    pub actor_id: Option<i32>,
+    pub created: DateTime<Utc>,
+}
+
+impl From<entity::auth_group_membership_history::Model> for GroupMembershipHistoryDto {
+    fn from(model: entity::auth_group_membership_history::Model) -> Self {
+        GroupMembershipHistoryDto {
+            id: model.id,
+            group_id: model.group_id,
+            user_id: model.user_id,
+            action: model.action.into(),
+            reason: model.reason.into(),
+            actor_id: model.actor_id,
+            created: DateTime::from_naive_utc_and_offset(model.created, Utc),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PendingGroupApplicationDto {
+    pub id: i32,
+    pub request_type: GroupApplicationType,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AvailableGroupDto {
+    pub group: GroupDto,
+    // Whether the user currently passes the group's filters
+    pub eligible: bool,
+    pub member: bool,
+    // Oldest first, a member can have both a join & a leave application outstanding
+    pub pending_applications: Vec<PendingGroupApplicationDto>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
 pub struct GroupFiltersDto {
     pub id: i32,
     pub filter_type: GroupFilterType,
@@ -268,11 +656,12 @@ pub struct GroupFiltersDto {
     pub filter_groups: Vec<GroupFilterGroupDto>,
 }
 
-#[derive(Serialize, Deserialize, ToSchema)]
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
 pub struct GroupFilterGroupDto {
     pub id: i32,
     pub filter_type: GroupFilterType,
     pub rules: Vec<GroupFilterRuleDto>,
+    pub filter_groups: Vec<GroupFilterGroupDto>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema, Clone)]
@@ -281,6 +670,7 @@ pub struct GroupFilterRuleDto {
     pub criteria: GroupFilterCriteria,
     pub criteria_type: GroupFilterCriteriaType,
     pub criteria_value: String,
+    pub scope: GroupFilterScope,
 }
 
 impl From<entity::auth_group_filter_rule::Model> for GroupFilterRuleDto {
@@ -290,6 +680,7 @@ impl From<entity::auth_group_filter_rule::Model> for GroupFilterRuleDto {
             criteria: model.criteria.into(),
             criteria_type: model.criteria_type.into(),
             criteria_value: model.criteria_value,
+            scope: model.scope.into(),
         }
     }
 }
@@ -299,14 +690,271 @@ pub struct GroupApplicationDto {
     pub id: i32,
     pub group_id: i32,
     pub user_id: i32,
-    pub applicant_info: CharacterAffiliationDto,
+    // None if the applicant has no main character or its data is missing
+    pub applicant_info: Option<CharacterAffiliationDto>,
     pub responder_info: Option<CharacterAffiliationDto>,
+    // Reviewer who claimed or was assigned the application
+    pub assignee_id: Option<i32>,
+    pub assignee_info: Option<CharacterAffiliationDto>,
     pub request_type: GroupApplicationType,
     pub status: GroupApplicationStatus,
     pub request_message: Option<String>,
     pub response_message: Option<String>,
     pub created: DateTime<Utc>,
     pub last_updated: DateTime<Utc>,
+    pub answers: Vec<GroupApplicationAnswerDto>,
+    pub comments: Vec<GroupApplicationCommentDto>,
+    pub history: Vec<GroupApplicationHistoryDto>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GroupApplicationHistoryDto {
+    pub id: i32,
+    pub application_id: i32,
+    // None for changes made by the auth itself such as expiry
+    pub actor_id: Option<i32>,
+    // None for the entry recorded when the application was created
+    pub old_status: Option<GroupApplicationStatus>,
+    pub new_status: GroupApplicationStatus,
+    pub message: Option<String>,
+    pub created: DateTime<Utc>,
+}
+
+impl From<entity::auth_group_application_history::Model> for GroupApplicationHistoryDto {
+    fn from(model: entity::auth_group_application_history::Model) -> Self {
+        GroupApplicationHistoryDto {
+            id: model.id,
+            application_id: model.application_id,
+            actor_id: model.actor_id,
+            old_status: model.old_status.map(|status| status.into()),
+            new_status: model.new_status.into(),
+            message: model.message,
+            created: DateTime::from_naive_utc_and_offset(model.created, Utc),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+pub struct GroupApplicationAnswerDto {
+    // None if the question has since been removed from the group's questionnaire
+    pub question_id: Option<i32>,
+    pub question: String,
+    pub answer: String,
+}
+
+impl From<entity::auth_group_application_answer::Model> for GroupApplicationAnswerDto {
+    fn from(model: entity::auth_group_application_answer::Model) -> Self {
+        GroupApplicationAnswerDto {
+            question_id: model.question_id,
+            question: model.question,
+            answer: model.answer,
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewGroupApplicationAnswerDto {
+    pub question_id: i32,
+    // Booleans are answered with true or false, numbers with their decimal representation
+    pub answer: String,
+}
+
+#[derive(Deserialize, ToSchema, Default)]
+pub struct JoinGroupDto {
+    pub request_message: Option<String>,
+    // Answers to the group's questionnaire, ignored for groups which don't take applications
+    #[serde(default)]
+    pub answers: Vec<NewGroupApplicationAnswerDto>,
+}
+
+// Clients written before questionnaires send the request message as a plain string
+#[derive(Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum JoinGroupRequest {
+    Message(String),
+    Application(JoinGroupDto),
+}
+
+impl From<JoinGroupRequest> for JoinGroupDto {
+    fn from(request: JoinGroupRequest) -> Self {
+        match request {
+            JoinGroupRequest::Message(request_message) => JoinGroupDto {
+                request_message: Some(request_message),
+                answers: vec![],
+            },
+            JoinGroupRequest::Application(application) => application,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GroupQuestionDto {
+    pub id: i32,
+    pub group_id: i32,
+    pub question: String,
+    pub question_type: GroupQuestionType,
+    pub choices: Vec<String>,
+    pub required: bool,
+    pub ordering: i32,
+}
+
+impl From<entity::auth_group_question::Model> for GroupQuestionDto {
+    fn from(model: entity::auth_group_question::Model) -> Self {
+        GroupQuestionDto {
+            id: model.id,
+            group_id: model.group_id,
+            question: model.question,
+            question_type: model.question_type.into(),
+            choices: model
+                .choices
+                .and_then(|choices| serde_json::from_value(choices).ok())
+                .unwrap_or_default(),
+            required: model.required,
+            ordering: model.ordering,
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewGroupQuestionDto {
+    // Id of an existing question of the group to update in place, questions without one are added
+    #[serde(default)]
+    pub id: Option<i32>,
+    pub question: String,
+    pub question_type: GroupQuestionType,
+    // Options to pick from, only used for choice questions
+    #[serde(default)]
+    pub choices: Vec<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub ordering: i32,
+}
+
+impl From<GroupQuestionDto> for NewGroupQuestionDto {
+    fn from(model: GroupQuestionDto) -> Self {
+        // Copied to another group as a new question
+        NewGroupQuestionDto {
+            id: None,
+            question: model.question,
+            question_type: model.question_type,
+            choices: model.choices,
+            required: model.required,
+            ordering: model.ordering,
+        }
+    }
+}
+
+// Outcome of accepting or rejecting a single application as part of a bulk action
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ApplicationActionResultDto {
+    pub application_id: i32,
+    // Status the application was updated to, None if the action failed
+    pub status: Option<GroupApplicationStatus>,
+    pub error: Option<String>,
+}
+
+// Summary of a group's applications, outstanding applications are also counted by age so
+// reviewers can see how far behind the queue is
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GroupApplicationQueueDto {
+    pub group_id: i32,
+    pub outstanding: u64,
+    pub accepted: u64,
+    pub rejected: u64,
+    pub expired: u64,
+    pub withdrawn: u64,
+    // Outstanding applications nobody has claimed or been assigned
+    pub unassigned: u64,
+    pub outstanding_under_1_day: u64,
+    pub outstanding_1_to_7_days: u64,
+    pub outstanding_over_7_days: u64,
+    pub oldest_outstanding: Option<DateTime<Utc>>,
+}
+
+// Applicant's membership of the group after their application was accepted or rejected
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ApplicationMembershipDto {
+    pub application_id: i32,
+    pub group_id: i32,
+    pub user_id: i32,
+    pub status: GroupApplicationStatus,
+    pub member: bool,
+    pub expires: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GroupInvitationDto {
+    pub id: i32,
+    pub group_id: i32,
+    pub user_id: i32,
+    // None if the manager who sent the invitation has since been deleted
+    pub invited_by: Option<i32>,
+    pub status: GroupInvitationStatus,
+    pub message: Option<String>,
+    pub expires: Option<DateTime<Utc>>,
+    pub created: DateTime<Utc>,
+}
+
+impl From<entity::auth_group_invitation::Model> for GroupInvitationDto {
+    fn from(model: entity::auth_group_invitation::Model) -> Self {
+        GroupInvitationDto {
+            id: model.id,
+            group_id: model.group_id,
+            user_id: model.user_id,
+            invited_by: model.invited_by,
+            status: model.status.into(),
+            message: model.message,
+            expires: model
+                .expires
+                .map(|expires| DateTime::from_naive_utc_and_offset(expires, Utc)),
+            created: DateTime::from_naive_utc_and_offset(model.created, Utc),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewGroupInvitationDto {
+    pub user_id: i32,
+    pub message: Option<String>,
+    // Invitations without an expiry stay pending until accepted, declined or revoked
+    pub expires_in_days: Option<i64>,
+}
+
+// Unset values disable expiry & the cooldown after a rejection
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+pub struct GroupApplicationSettingsDto {
+    pub application_expiry_days: Option<i32>,
+    pub application_cooldown_days: Option<i32>,
+}
+
+impl From<entity::auth_group::Model> for GroupApplicationSettingsDto {
+    fn from(model: entity::auth_group::Model) -> Self {
+        GroupApplicationSettingsDto {
+            application_expiry_days: model.application_expiry_days,
+            application_cooldown_days: model.application_cooldown_days,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+pub struct GroupApplicationCommentDto {
+    pub id: i32,
+    pub application_id: i32,
+    pub author_id: i32,
+    // None if the author no longer has a main character
+    pub author_info: Option<CharacterAffiliationDto>,
+    pub body: String,
+    // Reviewer notes which aren't shown to the applicant
+    pub internal: bool,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewGroupApplicationCommentDto {
+    pub body: String,
+    #[serde(default)]
+    pub internal: bool,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -318,22 +966,126 @@ pub struct NewGroupDto {
     pub owner_type: GroupOwnerType,
     pub owner_id: Option<i32>,
     pub group_type: GroupType,
+    pub parent_id: Option<i32>,
+    #[serde(default)]
+    pub membership_inheritance: GroupMembershipInheritance,
+    pub category_id: Option<i32>,
     pub filter_type: GroupFilterType,
     pub filter_rules: Vec<NewGroupFilterRuleDto>,
     pub filter_groups: Vec<NewGroupFilterGroupDto>,
 }
 
+#[derive(Deserialize, ToSchema)]
+pub struct NewGroupCategoryDto {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub ordering: i32,
+}
+
 #[derive(Serialize, Deserialize, ToSchema, Clone)]
 pub struct NewGroupFilterRuleDto {
     pub criteria: GroupFilterCriteria,
     pub criteria_type: GroupFilterCriteriaType,
     pub criteria_value: String,
+    #[serde(default)]
+    pub scope: GroupFilterScope,
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct NewGroupFilterGroupDto {
     pub filter_type: GroupFilterType,
     pub rules: Vec<NewGroupFilterRuleDto>,
+    // Nested filter groups, evaluated alongside the rules using this group's filter_type
+    #[serde(default)]
+    pub filter_groups: Vec<NewGroupFilterGroupDto>,
+}
+
+impl From<GroupFilterRuleDto> for NewGroupFilterRuleDto {
+    fn from(model: GroupFilterRuleDto) -> Self {
+        NewGroupFilterRuleDto {
+            criteria: model.criteria,
+            criteria_type: model.criteria_type,
+            criteria_value: model.criteria_value,
+            scope: model.scope,
+        }
+    }
+}
+
+impl From<GroupFilterGroupDto> for NewGroupFilterGroupDto {
+    fn from(model: GroupFilterGroupDto) -> Self {
+        NewGroupFilterGroupDto {
+            filter_type: model.filter_type,
+            rules: model.rules.into_iter().map(|rule| rule.into()).collect(),
+            filter_groups: model
+                .filter_groups
+                .into_iter()
+                .map(|group| group.into())
+                .collect(),
+        }
+    }
+}
+
+// Overrides applied to the copy, the source group's values are used for any left unset
+#[derive(Deserialize, ToSchema)]
+pub struct CloneGroupDto {
+    pub name: Option<String>,
+    pub owner_type: Option<GroupOwnerType>,
+    pub owner_id: Option<i32>,
+}
+
+// Group settings stored by a template, criteria values may contain the placeholders
+// {owner_id}, {corporation_id} & {alliance_id} which are resolved from the owner on instantiation
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GroupTemplateDefinition {
+    pub name: String,
+    pub description: Option<String>,
+    pub confidential: bool,
+    pub leave_applications: bool,
+    pub group_type: GroupType,
+    pub category_id: Option<i32>,
+    pub filter_type: GroupFilterType,
+    pub filter_rules: Vec<NewGroupFilterRuleDto>,
+    #[serde(default)]
+    pub filter_groups: Vec<NewGroupFilterGroupDto>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GroupTemplateDto {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub definition: GroupTemplateDefinition,
+    pub created: DateTime<Utc>,
+}
+
+impl TryFrom<entity::auth_group_template::Model> for GroupTemplateDto {
+    type Error = serde_json::Error;
+
+    fn try_from(model: entity::auth_group_template::Model) -> Result<Self, Self::Error> {
+        Ok(GroupTemplateDto {
+            id: model.id,
+            name: model.name,
+            description: model.description,
+            definition: serde_json::from_value(model.definition)?,
+            created: DateTime::from_naive_utc_and_offset(model.created, Utc),
+        })
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewGroupTemplateDto {
+    pub name: String,
+    pub description: Option<String>,
+    pub definition: GroupTemplateDefinition,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct InstantiateGroupTemplateDto {
+    // Overrides the group name stored in the template
+    pub name: Option<String>,
+    pub owner_type: GroupOwnerType,
+    pub owner_id: Option<i32>,
 }
 
 #[derive(Deserialize, ToSchema, Clone)]
@@ -345,6 +1097,10 @@ pub struct UpdateGroupDto {
     pub owner_type: GroupOwnerType,
     pub owner_id: Option<i32>,
     pub group_type: GroupType,
+    pub parent_id: Option<i32>,
+    #[serde(default)]
+    pub membership_inheritance: GroupMembershipInheritance,
+    pub category_id: Option<i32>,
     pub filter_type: GroupFilterType,
     pub filter_rules: Vec<UpdateGroupFilterRuleDto>,
     pub filter_groups: Vec<UpdateGroupFilterGroupDto>,
@@ -372,6 +1128,9 @@ impl From<UpdateGroupDto> for NewGroupDto {
             owner_type: model.owner_type,
             owner_id: model.owner_id,
             group_type: model.group_type,
+            parent_id: model.parent_id,
+            membership_inheritance: model.membership_inheritance,
+            category_id: model.category_id,
             filter_type: model.filter_type,
             filter_groups: new_groups,
             filter_rules: new_rules,
@@ -384,6 +1143,8 @@ pub struct UpdateGroupFilterGroupDto {
     pub id: Option<i32>,
     pub filter_type: GroupFilterType,
     pub rules: Vec<UpdateGroupFilterRuleDto>,
+    #[serde(default)]
+    pub filter_groups: Vec<UpdateGroupFilterGroupDto>,
 }
 
 impl From<UpdateGroupFilterGroupDto> for NewGroupFilterGroupDto {
@@ -391,9 +1152,16 @@ impl From<UpdateGroupFilterGroupDto> for NewGroupFilterGroupDto {
         let new_rules: Vec<NewGroupFilterRuleDto> =
             model.rules.into_iter().map(|rule| rule.into()).collect();
 
+        let new_groups: Vec<NewGroupFilterGroupDto> = model
+            .filter_groups
+            .into_iter()
+            .map(|group| group.into())
+            .collect();
+
         NewGroupFilterGroupDto {
             filter_type: model.filter_type,
             rules: new_rules,
+            filter_groups: new_groups,
         }
     }
 }
@@ -404,6 +1172,8 @@ pub struct UpdateGroupFilterRuleDto {
     pub criteria: GroupFilterCriteria,
     pub criteria_type: GroupFilterCriteriaType,
     pub criteria_value: String,
+    #[serde(default)]
+    pub scope: GroupFilterScope,
 }
 
 impl From<UpdateGroupFilterRuleDto> for NewGroupFilterRuleDto {
@@ -412,6 +1182,7 @@ impl From<UpdateGroupFilterRuleDto> for NewGroupFilterRuleDto {
             criteria: model.criteria,
             criteria_type: model.criteria_type,
             criteria_value: model.criteria_value,
+            scope: model.scope,
         }
     }
 }
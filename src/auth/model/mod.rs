@@ -1,2 +1,4 @@
 pub mod groups;
+pub mod organizations;
+pub mod recruitment;
 pub mod user;
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Eq, Hash)]
+pub enum OrganizationMemberType {
+    Alliance,
+    Corporation,
+}
+
+impl From<OrganizationMemberType> for entity::sea_orm_active_enums::OrganizationMemberType {
+    fn from(item: OrganizationMemberType) -> Self {
+        match item {
+            OrganizationMemberType::Alliance => {
+                entity::sea_orm_active_enums::OrganizationMemberType::Alliance
+            }
+            OrganizationMemberType::Corporation => {
+                entity::sea_orm_active_enums::OrganizationMemberType::Corporation
+            }
+        }
+    }
+}
+
+impl From<entity::sea_orm_active_enums::OrganizationMemberType> for OrganizationMemberType {
+    fn from(item: entity::sea_orm_active_enums::OrganizationMemberType) -> Self {
+        match item {
+            entity::sea_orm_active_enums::OrganizationMemberType::Alliance => {
+                OrganizationMemberType::Alliance
+            }
+            entity::sea_orm_active_enums::OrganizationMemberType::Corporation => {
+                OrganizationMemberType::Corporation
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Eq, Hash)]
+pub struct OrganizationMemberDto {
+    pub member_type: OrganizationMemberType,
+    // Alliance or corporation id depending on the member type
+    pub member_id: i32,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct OrganizationDto {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub members: Vec<OrganizationMemberDto>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewOrganizationDto {
+    pub name: String,
+    pub description: Option<String>,
+    pub members: Vec<OrganizationMemberDto>,
+}
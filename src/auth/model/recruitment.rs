@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::model::groups::NewGroupApplicationAnswerDto,
+    eve::model::character::CharacterAffiliationDto,
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+pub enum RecruitmentStatus {
+    // Form submitted, waiting on the prospect to login with the pipeline's required scopes
+    AwaitingScopes,
+    Vetting,
+    // Approved by a recruiter, waiting on the character to appear in the corporation
+    Approved,
+    // Character joined the corporation & was handed off to the pipeline's group
+    Joined,
+    Rejected,
+    Withdrawn,
+}
+
+impl From<RecruitmentStatus> for entity::sea_orm_active_enums::RecruitmentStatus {
+    fn from(item: RecruitmentStatus) -> Self {
+        match item {
+            RecruitmentStatus::AwaitingScopes => {
+                entity::sea_orm_active_enums::RecruitmentStatus::AwaitingScopes
+            }
+            RecruitmentStatus::Vetting => entity::sea_orm_active_enums::RecruitmentStatus::Vetting,
+            RecruitmentStatus::Approved => {
+                entity::sea_orm_active_enums::RecruitmentStatus::Approved
+            }
+            RecruitmentStatus::Joined => entity::sea_orm_active_enums::RecruitmentStatus::Joined,
+            RecruitmentStatus::Rejected => {
+                entity::sea_orm_active_enums::RecruitmentStatus::Rejected
+            }
+            RecruitmentStatus::Withdrawn => {
+                entity::sea_orm_active_enums::RecruitmentStatus::Withdrawn
+            }
+        }
+    }
+}
+
+impl From<entity::sea_orm_active_enums::RecruitmentStatus> for RecruitmentStatus {
+    fn from(item: entity::sea_orm_active_enums::RecruitmentStatus) -> Self {
+        match item {
+            entity::sea_orm_active_enums::RecruitmentStatus::AwaitingScopes => {
+                RecruitmentStatus::AwaitingScopes
+            }
+            entity::sea_orm_active_enums::RecruitmentStatus::Vetting => RecruitmentStatus::Vetting,
+            entity::sea_orm_active_enums::RecruitmentStatus::Approved => {
+                RecruitmentStatus::Approved
+            }
+            entity::sea_orm_active_enums::RecruitmentStatus::Joined => RecruitmentStatus::Joined,
+            entity::sea_orm_active_enums::RecruitmentStatus::Rejected => {
+                RecruitmentStatus::Rejected
+            }
+            entity::sea_orm_active_enums::RecruitmentStatus::Withdrawn => {
+                RecruitmentStatus::Withdrawn
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RecruitmentPipelineDto {
+    pub id: i32,
+    pub name: String,
+    pub corporation_id: i32,
+    // Membership group recruits are handed off to, its questionnaire is the recruitment form
+    pub group_id: i32,
+    pub required_scopes: Vec<String>,
+    pub open: bool,
+    pub created: DateTime<Utc>,
+}
+
+impl From<entity::auth_recruitment_pipeline::Model> for RecruitmentPipelineDto {
+    fn from(model: entity::auth_recruitment_pipeline::Model) -> Self {
+        RecruitmentPipelineDto {
+            id: model.id,
+            name: model.name,
+            corporation_id: model.corporation_id,
+            group_id: model.group_id,
+            required_scopes: model
+                .required_scopes
+                .split_whitespace()
+                .map(String::from)
+                .collect(),
+            open: model.open,
+            created: DateTime::from_naive_utc_and_offset(model.created, Utc),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewRecruitmentPipelineDto {
+    pub name: String,
+    pub corporation_id: i32,
+    pub group_id: i32,
+    #[serde(default)]
+    pub required_scopes: Vec<String>,
+    pub open: bool,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RecruitmentDto {
+    pub id: i32,
+    pub pipeline_id: i32,
+    pub user_id: i32,
+    pub character_id: i32,
+    // None if the character's info couldn't be found
+    pub character_info: Option<CharacterAffiliationDto>,
+    // Application to the pipeline's group holding the form answers, comments & history
+    pub application_id: i32,
+    pub status: RecruitmentStatus,
+    pub granted_scopes: Vec<String>,
+    // Required scopes the prospect has yet to grant
+    pub missing_scopes: Vec<String>,
+    pub recruiter_id: Option<i32>,
+    pub response_message: Option<String>,
+    pub created: DateTime<Utc>,
+    pub last_updated: DateTime<Utc>,
+    pub joined: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewRecruitmentDto {
+    pub pipeline_id: i32,
+    pub character_id: i32,
+    pub request_message: Option<String>,
+    // Answers to the questionnaire of the pipeline's group
+    #[serde(default)]
+    pub answers: Vec<NewGroupApplicationAnswerDto>,
+}
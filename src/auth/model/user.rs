@@ -1,6 +1,9 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use super::groups::GroupDto;
+
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct UserDto {
     pub id: i32,
@@ -10,12 +13,32 @@ pub struct UserDto {
 
 pub struct UserAffiliations {
     pub user_id: i32,
-    pub characters: Vec<i32>,
-    pub corporations: Vec<i32>,
-    pub alliances: Vec<i32>,
+    pub characters: Vec<UserCharacterAffiliation>,
+}
+
+pub struct UserCharacterAffiliation {
+    pub character_id: i32,
+    pub corporation_id: i32,
+    pub alliance_id: Option<i32>,
+    pub main: bool,
 }
 
 pub struct UserGroups {
     pub user_id: i32,
-    pub groups: Vec<i32>,
+    // Effective memberships, including those inherited through subgroups
+    pub groups: Vec<UserGroupMembership>,
+}
+
+pub struct UserGroupMembership {
+    pub group_id: i32,
+    // None for direct memberships, otherwise the group the membership is inherited from
+    pub inherited_from: Option<i32>,
+    pub expires: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UserGroupMembershipDto {
+    pub group: GroupDto,
+    pub inherited_from: Option<i32>,
+    pub expires: Option<DateTime<Utc>>,
 }
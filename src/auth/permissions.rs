@@ -4,12 +4,13 @@ use axum::response::Response;
 use sea_orm::DatabaseConnection;
 use tower_sessions::Session;
 
+use entity::auth_user::Model as User;
+
 use crate::auth::data;
+use crate::auth::data::groups::owners::{can_manage_owner, get_managed_owners, is_owner_leader};
+use crate::auth::model::groups::GroupOwnerType;
 
-pub async fn require_permissions(
-    db: &DatabaseConnection,
-    session: Session,
-) -> Result<i32, Response> {
+pub async fn get_session_user(db: &DatabaseConnection, session: Session) -> Result<User, Response> {
     let user: Option<String> = session.get("user").await.unwrap_or(None);
     let user_id: Option<i32> = user.map(|user| user.parse::<i32>().unwrap());
 
@@ -20,21 +21,125 @@ pub async fn require_permissions(
 
     match data::user::get_user(db, user_id).await {
         Ok(user) => match user {
-            Some(user) => {
-                if user.admin {
-                    return Ok(user_id);
-                }
-            }
-            None => return Err((StatusCode::NOT_FOUND, "User not found").into_response()),
+            Some(user) => Ok(user),
+            None => Err((StatusCode::NOT_FOUND, "User not found").into_response()),
         },
+        Err(_) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "There was an issue getting user info",
+        )
+            .into_response()),
+    }
+}
+
+pub async fn require_permissions(
+    db: &DatabaseConnection,
+    session: Session,
+) -> Result<i32, Response> {
+    let user = get_session_user(db, session).await?;
+
+    if user.admin {
+        return Ok(user.id);
+    }
+
+    Err((StatusCode::FORBIDDEN, "Insufficient permissions").into_response())
+}
+
+// Allows admins & users managing the corporation or alliance owning a group
+pub async fn require_owner_permissions(
+    db: &DatabaseConnection,
+    session: Session,
+    owner_type: &GroupOwnerType,
+    owner_id: Option<i32>,
+) -> Result<i32, Response> {
+    let user = get_session_user(db, session).await?;
+
+    if user.admin {
+        return Ok(user.id);
+    }
+
+    match can_manage_owner(db, user.id, owner_type, owner_id).await {
+        Ok(true) => Ok(user.id),
+        Ok(false) => Err((StatusCode::FORBIDDEN, "Insufficient permissions").into_response()),
+        Err(_) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "There was an issue getting user permissions",
+        )
+            .into_response()),
+    }
+}
+
+// Allows admins & users managing the groups of at least one corporation or alliance
+pub async fn require_manager_permissions(
+    db: &DatabaseConnection,
+    session: Session,
+) -> Result<i32, Response> {
+    let user = get_session_user(db, session).await?;
+
+    if user.admin {
+        return Ok(user.id);
+    }
+
+    match get_managed_owners(db, user.id).await {
+        Ok(owners) if !owners.is_empty() => Ok(user.id),
+        Ok(_) => Err((StatusCode::FORBIDDEN, "Insufficient permissions").into_response()),
+        Err(_) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "There was an issue getting user permissions",
+        )
+            .into_response()),
+    }
+}
+
+// Allows admins & the leader of a corporation or alliance, used for naming delegates
+pub async fn require_owner_leader_permissions(
+    db: &DatabaseConnection,
+    session: Session,
+    owner_type: &GroupOwnerType,
+    owner_id: i32,
+) -> Result<i32, Response> {
+    let user = get_session_user(db, session).await?;
+
+    if user.admin {
+        return Ok(user.id);
+    }
+
+    match is_owner_leader(db, user.id, owner_type, owner_id).await {
+        Ok(true) => Ok(user.id),
+        Ok(false) => Err((StatusCode::FORBIDDEN, "Insufficient permissions").into_response()),
+        Err(_) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "There was an issue getting user permissions",
+        )
+            .into_response()),
+    }
+}
+
+// Allows admins & users managing the owner of the group
+pub async fn require_group_permissions(
+    db: &DatabaseConnection,
+    session: Session,
+    group_id: i32,
+) -> Result<i32, Response> {
+    let user = get_session_user(db, session.clone()).await?;
+
+    let group = match data::groups::get_group_by_id(db, group_id).await {
+        Ok(Some(group)) => group,
+        Ok(None) => {
+            if user.admin {
+                return Err((StatusCode::NOT_FOUND, "Group not found").into_response());
+            }
+
+            return Err((StatusCode::FORBIDDEN, "Insufficient permissions").into_response());
+        }
         Err(_) => {
             return Err((
                 StatusCode::INTERNAL_SERVER_ERROR,
-                "There was an issue getting user info",
+                "There was an issue getting group info",
             )
                 .into_response())
         }
     };
 
-    Err((StatusCode::FORBIDDEN, "Insufficient permissions").into_response())
+    require_owner_permissions(db, session, &group.owner_type.into(), group.owner_id).await
 }
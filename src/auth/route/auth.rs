@@ -6,7 +6,7 @@ use axum::{
     Extension, Router,
 };
 use chrono::{Duration, Utc};
-use eve_oauth2::{create_login_url, get_access_token, validate_token};
+use eve_oauth2::{create_login_url, get_access_token};
 use oauth2::TokenResponse;
 use redis::Commands;
 use sea_orm::DatabaseConnection;
@@ -15,8 +15,13 @@ use std::env;
 use tower_sessions::Session;
 
 use crate::{
+    auth::data::recruitment::{get_recruitment_login_scopes, record_recruitment_scopes},
     auth::data::user::{create_user, get_user_character_ownership_by_ownerhash, update_ownership},
-    eve::service::affiliation::update_affiliation,
+    eve::service::{
+        affiliation::update_affiliation,
+        roles::{save_character_token, update_character_roles},
+    },
+    eve::sso::validate_token,
 };
 use crate::{
     auth::data::user::{update_user_as_admin, update_user_main},
@@ -34,6 +39,8 @@ pub struct CallbackParams {
 pub struct LoginParams {
     set_main: Option<bool>,
     admin_setup: Option<String>,
+    // Requests the scopes required by the user's recruitment & records them once logged in
+    recruitment_id: Option<i32>,
 }
 
 pub fn auth_routes() -> Router {
@@ -48,18 +55,67 @@ pub fn auth_routes() -> Router {
     path = "/auth/login",
     responses(
         (status = 307, description = "Redirect to EVE Online login page"),
-        (status = 403, description = "Forbidden", body = String)
+        (status = 403, description = "Forbidden", body = String),
+        (status = 404, description = "Recruitment not found", body = String)
     )
 )]
-pub async fn login(session: Session, params: Query<LoginParams>) -> Response {
+pub async fn login(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    params: Query<LoginParams>,
+) -> Response {
     let set_main = params.0.set_main.unwrap_or(false);
     let admin_code = &params.0.admin_setup;
+    let recruitment_id = params.0.recruitment_id;
 
     let backend_domain = env::var("BACKEND_DOMAIN").expect("BACKEND_DOMAIN must be set");
     let esi_client_id = env::var("ESI_CLIENT_ID").expect("ESI_CLIENT_ID must be set");
     let esi_client_secret = env::var("ESI_CLIENT_SECRET").expect("ESI_CLIENT_SECRET must be set");
 
-    let scopes = vec!["".to_string()];
+    // Additional ESI scopes, e.g. for corporation role & title group filters
+    let mut scopes: Vec<String> = match env::var("ESI_SCOPES") {
+        Ok(scopes) => scopes.split_whitespace().map(String::from).collect(),
+        Err(_) => vec![],
+    };
+
+    // Recruitment logins must be made by the prospect who submitted the recruitment
+    if let Some(recruitment_id) = recruitment_id {
+        let user: Option<String> = session.get("user").await.unwrap_or(None);
+        let user_id = match user.map(|user| user.parse::<i32>().unwrap()) {
+            Some(user_id) => user_id,
+            None => return (StatusCode::NOT_FOUND, "Recruitment not found").into_response(),
+        };
+
+        match get_recruitment_login_scopes(&db, recruitment_id, user_id).await {
+            Ok(recruitment_scopes) => {
+                for scope in recruitment_scopes {
+                    if !scopes.contains(&scope) {
+                        scopes.push(scope);
+                    }
+                }
+            }
+            Err(err) => {
+                if err.to_string() == "Recruitment not found"
+                    || err.to_string() == "Recruitment pipeline not found"
+                {
+                    return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+                }
+
+                println!("{}", err);
+
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "There was an issue logging you in, please try again.",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    if scopes.is_empty() {
+        scopes.push("".to_string());
+    }
+
     let redirect_url = format!("http://{}/auth/callback", backend_domain);
 
     let auth_data = create_login_url(esi_client_id, esi_client_secret, redirect_url, scopes);
@@ -70,6 +126,13 @@ pub async fn login(session: Session, params: Query<LoginParams>) -> Response {
         session.insert("set_main", set_main).await.unwrap();
     }
 
+    if let Some(recruitment_id) = recruitment_id {
+        session
+            .insert("recruitment_id", recruitment_id)
+            .await
+            .unwrap();
+    }
+
     match admin_code {
         Some(admin_code) => {
             let valkey_url = env::var("VALKEY_URL").expect("VALKEY_URL must be set!");
@@ -115,15 +178,15 @@ pub async fn callback(
         db: &DatabaseConnection,
         code: String,
         user_id: Option<i32>,
-    ) -> Result<CharacterOwnership, anyhow::Error> {
+    ) -> Result<(CharacterOwnership, Vec<String>), anyhow::Error> {
         let esi_client_id = env::var("ESI_CLIENT_ID").expect("ESI_CLIENT_ID must be set");
         let esi_client_secret =
             env::var("ESI_CLIENT_SECRET").expect("ESI_CLIENT_SECRET must be set");
 
         let token = get_access_token(esi_client_id, esi_client_secret, code).await;
-        let token_claims = validate_token(token.access_token().secret().to_string()).await;
+        let claims = validate_token(token.access_token().secret()).await?;
 
-        let id_str = token_claims.claims.sub.split(':').collect::<Vec<&str>>()[2];
+        let id_str = claims.sub.split(':').collect::<Vec<&str>>()[2];
         let character_id: i32 = id_str.parse().expect("Failed to parse id to i32");
 
         let character = get_or_create_character(db, character_id).await?;
@@ -132,29 +195,49 @@ pub async fn callback(
             update_affiliation(db, vec![character_id]).await?
         }
 
-        let ownerhash = token_claims.claims.owner;
+        let scopes: Vec<&str> = claims.scp.iter().map(String::as_str).collect();
 
-        match user_id {
-            Some(user_id) => Ok(update_ownership(db, user_id, character_id, ownerhash).await?),
+        // Roles & titles aren't required to login, log the error and continue
+        if let Err(err) =
+            update_character_roles(db, character_id, token.access_token().secret(), &scopes).await
+        {
+            println!("{}", err);
+        }
+
+        if let Some(refresh_token) = token.refresh_token() {
+            if let Err(err) =
+                save_character_token(db, character_id, refresh_token.secret(), &scopes).await
+            {
+                println!("{}", err);
+            }
+        }
+
+        let ownerhash = claims.owner;
+
+        let ownership = match user_id {
+            Some(user_id) => update_ownership(db, user_id, character_id, ownerhash).await?,
             None => {
                 let ownership =
                     get_user_character_ownership_by_ownerhash(db, ownerhash.clone()).await?;
 
                 match ownership {
-                    Some(ownership) => Ok(ownership),
+                    Some(ownership) => ownership,
                     None => {
                         let user_id = create_user(db).await?;
 
-                        Ok(update_ownership(db, user_id, character_id, ownerhash).await?)
+                        update_ownership(db, user_id, character_id, ownerhash).await?
                     }
                 }
             }
-        }
+        };
+
+        Ok((ownership, claims.scp))
     }
 
     let state: Option<String> = session.get("state").await.unwrap_or(None);
     let set_main: Option<bool> = session.get("set_main").await.unwrap_or(None);
     let set_as_admin: Option<bool> = session.get("set_as_admin").await.unwrap_or(None);
+    let recruitment_id: Option<i32> = session.get("recruitment_id").await.unwrap_or(None);
 
     if state.is_none() || Some(params.state.clone()) != state {
         return (
@@ -166,11 +249,13 @@ pub async fn callback(
 
     let _ = session.remove::<String>("state").await;
     let _ = session.remove::<bool>("set_main").await;
+    let _ = session.remove::<i32>("recruitment_id").await;
 
     let user: Option<String> = session.get("user").await.unwrap_or(None);
     let user: Option<i32> = user.map(|user| user.parse::<i32>().unwrap());
 
-    let ownership_entry = match get_or_create_user(&db, params.0.code.clone(), user).await {
+    let (ownership_entry, scopes) = match get_or_create_user(&db, params.0.code.clone(), user).await
+    {
         Ok(entry) => entry,
         Err(err) => {
             println!("{}", err);
@@ -183,6 +268,35 @@ pub async fn callback(
         }
     };
 
+    // The prospect logged in to grant the scopes of their recruitment, a failure to record them
+    // would leave the recruitment awaiting scopes so it is reported rather than ignored
+    if let Some(recruitment_id) = recruitment_id {
+        let scopes: Vec<&str> = scopes.iter().map(String::as_str).collect();
+
+        if let Err(err) =
+            record_recruitment_scopes(&db, recruitment_id, ownership_entry.character_id, &scopes)
+                .await
+        {
+            if err.to_string() == "Recruitment not found"
+                || err.to_string() == "Recruitment pipeline not found"
+            {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Logged in character does not match the recruitment"
+                || err.to_string() == "Recruitment is already complete"
+            {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "There was an issue recording the scopes granted for your recruitment, please log in again.",
+            )
+                .into_response();
+        }
+    }
+
     if let Some(true) = set_as_admin {
         let valkey_url = env::var("VALKEY_URL").expect("VALKEY_URL must be set!");
 
@@ -3,8 +3,9 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use axum::{
+    extract,
     response::Response,
-    routing::{delete, get, post, put},
+    routing::{get, post, put},
     Extension, Router,
 };
 use sea_orm::DatabaseConnection;
@@ -13,14 +14,31 @@ use tower_sessions::Session;
 use utoipa::ToSchema;
 
 use crate::auth::data;
-use crate::auth::model::groups::{GroupApplicationStatus, GroupApplicationType};
-use crate::auth::permissions::require_permissions;
+use crate::auth::data::groups::applications::GroupApplicationFilter;
+use crate::auth::model::groups::{
+    GroupApplicationStatus, GroupApplicationType, NewGroupApplicationCommentDto,
+};
+use crate::auth::permissions::{get_session_user, require_group_permissions, require_permissions};
+use crate::auth::route::groups::members::MembershipDurationParams;
 
 pub fn group_application_routes() -> Router {
     Router::new()
         .route("/", get(get_group_applications))
+        .route("/summary", get(get_application_queues))
+        .route("/bulk", post(bulk_accept_reject_applications))
+        .route("/:application_id", get(get_group_application))
         .route("/:application_id", put(update_group_application))
-        .route("/:application_id", delete(delete_group_application))
+        .route(
+            "/:application_id/withdraw",
+            post(withdraw_group_application),
+        )
+        .route("/:application_id/claim", post(claim_group_application))
+        .route("/:application_id/assignee", put(assign_group_application))
+        .route("/:application_id/comments", get(get_application_comments))
+        .route(
+            "/:application_id/comments",
+            post(create_application_comment),
+        )
         .route(
             "/:application_id/:application_action",
             post(accept_reject_application),
@@ -33,13 +51,34 @@ pub struct GetGroupApplicationParams {
     pub user_id: Option<i32>,
     pub application_status: Option<GroupApplicationStatus>,
     pub application_type: Option<GroupApplicationType>,
+    pub assigned_to_me: Option<bool>,
+    pub unassigned: Option<bool>,
+    pub older_than_days: Option<i64>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl GetGroupApplicationParams {
+    pub fn filter(&self, user_id: i32) -> GroupApplicationFilter {
+        GroupApplicationFilter {
+            application_status: self.application_status.clone().map(|status| status.into()),
+            application_type: self.application_type.clone().map(|type_| type_.into()),
+            application_id: None,
+            group_id: self.group_id,
+            user_id: self.user_id,
+            assignee_id: self.assigned_to_me.unwrap_or_default().then_some(user_id),
+            unassigned: self.unassigned.unwrap_or_default(),
+            older_than_days: self.older_than_days,
+        }
+    }
 }
 
 #[utoipa::path(
     get,
     path = "/groups/applications",
     responses(
-        (status = 200, description = "Applications", body = GroupDto),
+        (status = 200, description = "Applications, newest first", body = Vec<GroupApplicationDto>),
+        (status = 400, description = "Invalid page size or application age", body = String),
         (status = 403, description = "Insufficient permissions", body = String),
         (status = 404, description = "Not found", body = String),
         (status = 500, description = "Internal server error", body = String)
@@ -49,6 +88,11 @@ pub struct GetGroupApplicationParams {
         ("user_id" = Option<i32>, Query, description = "Filter user by id"),
         ("application_status" = Option<GroupApplicationStatus>, Query, description = "Filter by application status"),
         ("application_type" = Option<GroupApplicationType>, Query, description = "Filter by application type"),
+        ("assigned_to_me" = Option<bool>, Query, description = "Only applications assigned to the current user"),
+        ("unassigned" = Option<bool>, Query, description = "Only applications nobody is assigned to"),
+        ("older_than_days" = Option<i64>, Query, description = "Only applications created more than this many days ago"),
+        ("page" = Option<u64>, Query, description = "Page to get starting from 0, requires page_size"),
+        ("page_size" = Option<u64>, Query, description = "Applications per page, all applications are returned if unset"),
     ),
     security(
         ("login" = [])
@@ -59,23 +103,37 @@ pub async fn get_group_applications(
     session: Session,
     Query(params): Query<GetGroupApplicationParams>,
 ) -> Response {
-    match require_permissions(&db, session).await {
-        Ok(_) => (),
+    // Users managing a group's owner can only get applications by filtering on that group
+    let permissions = match params.group_id {
+        Some(group_id) => require_group_permissions(&db, session, group_id).await,
+        None => require_permissions(&db, session).await,
+    };
+
+    let user_id = match permissions {
+        Ok(user_id) => user_id,
         Err(response) => return response,
     };
 
     match data::groups::applications::get_group_application(
         &db,
-        params.application_status.map(|status| status.into()),
-        params.application_type.map(|type_| type_.into()),
-        None,
-        params.group_id,
-        params.user_id,
+        params.filter(user_id),
+        params
+            .page_size
+            .map(|page_size| data::groups::applications::ApplicationPage {
+                page: params.page.unwrap_or_default(),
+                page_size,
+            }),
+        true,
     )
     .await
     {
         Ok(applications) => (StatusCode::OK, Json(applications)).into_response(),
         Err(err) => {
+            if err.to_string() == "Invalid page size"
+                || err.to_string() == "Invalid application age"
+            {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
             if err.to_string() == "Group does not exist" || err.to_string() == "User does not exist"
             {
                 return (StatusCode::NOT_FOUND, err.to_string()).into_response();
@@ -95,6 +153,120 @@ pub async fn get_group_applications(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/groups/applications/summary",
+    responses(
+        (status = 200, description = "Application counts by status & age per group", body = Vec<GroupApplicationQueueDto>),
+        (status = 400, description = "Invalid application age", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    params (
+        ("group_id" = Option<i32>, Query, description = "Filter by group id"),
+        ("user_id" = Option<i32>, Query, description = "Filter user by id"),
+        ("application_status" = Option<GroupApplicationStatus>, Query, description = "Filter by application status"),
+        ("application_type" = Option<GroupApplicationType>, Query, description = "Filter by application type"),
+        ("assigned_to_me" = Option<bool>, Query, description = "Only applications assigned to the current user"),
+        ("unassigned" = Option<bool>, Query, description = "Only applications nobody is assigned to"),
+        ("older_than_days" = Option<i64>, Query, description = "Only applications created more than this many days ago"),
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_application_queues(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Query(params): Query<GetGroupApplicationParams>,
+) -> Response {
+    // Users managing a group's owner can only get a summary by filtering on that group
+    let permissions = match params.group_id {
+        Some(group_id) => require_group_permissions(&db, session, group_id).await,
+        None => require_permissions(&db, session).await,
+    };
+
+    let user_id = match permissions {
+        Ok(user_id) => user_id,
+        Err(response) => return response,
+    };
+
+    match data::groups::applications::get_application_queues(&db, params.filter(user_id)).await {
+        Ok(queues) => (StatusCode::OK, Json(queues)).into_response(),
+        Err(err) => {
+            if err.to_string() == "Invalid application age" {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+            if err.to_string() == "Group does not exist" || err.to_string() == "User does not exist"
+            {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            }
+            if err.to_string() == "Group does not require applications" {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting application queues",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/applications/{application_id}",
+    responses(
+        (status = 200, description = "Application with its status history", body = GroupApplicationDto),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_group_application(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(i32,)>,
+) -> Response {
+    let (_, reviewer) = match require_application_permissions(&db, session, path.0).await {
+        Ok(permissions) => permissions,
+        Err(response) => return response,
+    };
+
+    match data::groups::applications::get_group_application(
+        &db,
+        GroupApplicationFilter {
+            application_id: Some(path.0),
+            ..Default::default()
+        },
+        None,
+        reviewer,
+    )
+    .await
+    {
+        Ok(applications) => match applications.into_iter().next() {
+            Some(application) => (StatusCode::OK, Json(application)).into_response(),
+            None => (StatusCode::NOT_FOUND, "Application not found").into_response(),
+        },
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group application",
+            )
+                .into_response()
+        }
+    }
+}
+
 #[utoipa::path(
     put,
     path = "/groups/applications/{application_id}",
@@ -114,18 +286,19 @@ pub async fn update_group_application(
     Path(path): Path<(i32,)>,
     application_request_message: Json<Option<String>>,
 ) -> Response {
-    let user_id = match require_permissions(&db, session).await {
-        Ok(user_id) => user_id,
+    let user_id = match get_session_user(&db, session).await {
+        Ok(user) => user.id,
         Err(response) => return response,
     };
 
     match data::groups::applications::get_group_application(
         &db,
+        GroupApplicationFilter {
+            application_id: Some(path.0),
+            ..Default::default()
+        },
         None,
-        None,
-        Some(path.0),
-        None,
-        None,
+        false,
     )
     .await
     {
@@ -187,10 +360,10 @@ pub async fn update_group_application(
 }
 
 #[utoipa::path(
-    delete,
-    path = "/groups/applications/{application_id}",
+    post,
+    path = "/groups/applications/{application_id}/withdraw",
     responses(
-        (status = 200, description = "Successfully deleted application", body = GroupDto),
+        (status = 200, description = "Successfully withdrew application", body = String),
         (status = 403, description = "Insufficient permissions", body = String),
         (status = 404, description = "Not found", body = String),
         (status = 500, description = "Internal server error", body = String)
@@ -199,39 +372,27 @@ pub async fn update_group_application(
         ("login" = [])
     )
 )]
-pub async fn delete_group_application(
+pub async fn withdraw_group_application(
     Extension(db): Extension<DatabaseConnection>,
     session: Session,
     Path(path): Path<(i32,)>,
 ) -> Response {
-    let user_id = match require_permissions(&db, session).await {
-        Ok(user_id) => user_id,
+    let user_id = match get_session_user(&db, session).await {
+        Ok(user) => user.id,
         Err(response) => return response,
     };
 
-    match data::groups::applications::get_group_application(
-        &db,
-        None,
-        None,
-        Some(path.0),
-        None,
-        None,
-    )
-    .await
-    {
-        Ok(application) => {
-            if application.is_empty() {
-                return (StatusCode::NOT_FOUND, "Application does not exist").into_response();
-            };
-
-            if application[0].user_id != user_id {
+    match data::groups::applications::get_application_by_id(&db, path.0).await {
+        Ok(Some(application)) => {
+            if application.user_id != user_id {
                 return (
                     StatusCode::FORBIDDEN,
-                    "Not allowed to delete other user's application",
+                    "Not allowed to withdraw other user's application",
                 )
                     .into_response();
-            };
+            }
         }
+        Ok(None) => return (StatusCode::NOT_FOUND, "Application does not exist").into_response(),
         Err(err) => {
             println!("{}", err);
 
@@ -243,16 +404,10 @@ pub async fn delete_group_application(
         }
     };
 
-    match data::groups::applications::delete_group_application(&db, path.0).await {
-        Ok(result) => {
-            if result.rows_affected == 0 {
-                return (StatusCode::NOT_FOUND, "Application does not exist").into_response();
-            }
-
-            (StatusCode::OK, "Successfully deleted application").into_response()
-        }
+    match data::groups::applications::withdraw_group_application(&db, path.0).await {
+        Ok(_) => (StatusCode::OK, "Successfully withdrew application").into_response(),
         Err(err) => {
-            if err.to_string() == "Not allowed to delete a completed application" {
+            if err.to_string() == "Not allowed to withdraw a completed application" {
                 return (StatusCode::FORBIDDEN, err.to_string()).into_response();
             } else if err.to_string() == "Application not found" {
                 return (StatusCode::NOT_FOUND, err.to_string()).into_response();
@@ -262,7 +417,7 @@ pub async fn delete_group_application(
 
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                "Error deleting application",
+                "Error withdrawing application",
             )
                 .into_response()
         }
@@ -279,11 +434,15 @@ pub enum ApplicationAction {
     post,
     path = "/groups/applications/{application_id}/{application_action}",
     responses(
-        (status = 200, description = "Successfully approved/rejected application", body = GroupDto),
+        (status = 200, description = "Applicant's resulting membership", body = ApplicationMembershipDto),
+        (status = 400, description = "User no longer meets group requirements or invalid membership duration", body = String),
         (status = 403, description = "Insufficient permissions", body = String),
         (status = 404, description = "Not found", body = String),
         (status = 500, description = "Internal server error", body = String)
     ),
+    params (
+        ("duration_days" = Option<i64>, Query, description = "Days until the accepted membership expires"),
+    ),
     security(
         ("login" = [])
     )
@@ -292,104 +451,395 @@ pub async fn accept_reject_application(
     Extension(db): Extension<DatabaseConnection>,
     session: Session,
     Path(path): Path<(i32, ApplicationAction)>,
+    Query(params): Query<MembershipDurationParams>,
     application_response_message: Json<Option<String>>,
 ) -> Response {
-    let responder_id = match require_permissions(&db, session).await {
+    let group_id = match data::groups::applications::get_group_application(
+        &db,
+        GroupApplicationFilter {
+            application_id: Some(path.0),
+            ..Default::default()
+        },
+        None,
+        false,
+    )
+    .await
+    {
+        Ok(application) => match application.first() {
+            Some(application) => application.group_id,
+            None => return (StatusCode::NOT_FOUND, "Application not found").into_response(),
+        },
+        Err(err) => {
+            println!("{}", err);
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group applications",
+            )
+                .into_response();
+        }
+    };
+
+    let responder_id = match require_group_permissions(&db, session, group_id).await {
         Ok(user_id) => user_id,
         Err(response) => return response,
     };
 
-    let response_message = application_response_message.0.unwrap_or_default();
+    let duration = match params.duration() {
+        Ok(duration) => duration,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
 
     let application_action = match path.1 {
         ApplicationAction::Accept => entity::sea_orm_active_enums::GroupApplicationStatus::Accepted,
         ApplicationAction::Reject => entity::sea_orm_active_enums::GroupApplicationStatus::Rejected,
     };
 
-    let application = match data::groups::applications::update_group_application(
+    match data::groups::applications::respond_to_group_application(
         &db,
         path.0,
-        None,
-        Some(response_message),
-        Some(application_action.clone()),
-        Some(responder_id),
+        responder_id,
+        application_action,
+        application_response_message.0,
+        duration,
     )
     .await
     {
-        Ok(application) => application,
+        Ok(membership) => (StatusCode::OK, Json(membership)).into_response(),
         Err(err) => {
             if err.to_string() == "Not allowed to update a completed application" {
                 return (StatusCode::FORBIDDEN, err.to_string()).into_response();
-            } else if err.to_string() == "Application not found" {
+            } else if err.to_string() == "Application not found"
+                || err.to_string() == "Group does not exist"
+            {
                 return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "User does not meet group requirements"
+                || err.to_string() == "Invalid membership duration"
+            {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
             }
 
             println!("{}", err);
 
-            return (
+            (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Error updating application",
             )
-                .into_response();
+                .into_response()
         }
-    };
+    }
+}
 
-    if application_action == entity::sea_orm_active_enums::GroupApplicationStatus::Rejected {
-        return (StatusCode::OK, "Successfully rejected application").into_response();
+// Gets the id of the user if they're able to manage the application's group
+async fn require_application_reviewer_permissions(
+    db: &DatabaseConnection,
+    session: Session,
+    application_id: i32,
+) -> Result<i32, Response> {
+    match data::groups::applications::get_application_by_id(db, application_id).await {
+        Ok(Some(application)) => require_group_permissions(db, session, application.group_id).await,
+        Ok(None) => Err((StatusCode::NOT_FOUND, "Application not found").into_response()),
+        Err(err) => {
+            println!("{}", err);
+
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group application",
+            )
+                .into_response())
+        }
     }
+}
 
-    match application.request_type {
-        entity::sea_orm_active_enums::GroupApplicationType::Join => {
-            match data::groups::members::add_group_members(
-                &db,
-                application.group_id,
-                vec![application.user_id],
+#[utoipa::path(
+    post,
+    path = "/groups/applications/{application_id}/claim",
+    responses(
+        (status = 200, description = "Successfully claimed application", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 409, description = "Application already claimed", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn claim_group_application(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(i32,)>,
+) -> Response {
+    let user_id = match require_application_reviewer_permissions(&db, session, path.0).await {
+        Ok(user_id) => user_id,
+        Err(response) => return response,
+    };
+
+    match data::groups::applications::claim_group_application(&db, path.0, user_id).await {
+        Ok(_) => (StatusCode::OK, "Successfully claimed application").into_response(),
+        Err(err) => {
+            if err.to_string() == "Not allowed to assign a completed application" {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
+            } else if err.to_string() == "Application already claimed" {
+                return (StatusCode::CONFLICT, err.to_string()).into_response();
+            } else if err.to_string() == "Application not found" {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error claiming application",
             )
-            .await
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/groups/applications/{application_id}/assignee",
+    responses(
+        (status = 200, description = "Successfully assigned application", body = String),
+        (status = 400, description = "Assignee is not able to review applications for this group", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn assign_group_application(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(i32,)>,
+    assignee_id: Json<Option<i32>>,
+) -> Response {
+    match require_application_reviewer_permissions(&db, session, path.0).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::applications::assign_group_application(&db, path.0, assignee_id.0).await {
+        Ok(_) => (StatusCode::OK, "Successfully assigned application").into_response(),
+        Err(err) => {
+            if err.to_string() == "Not allowed to assign a completed application" {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
+            } else if err.to_string() == "Application not found"
+                || err.to_string() == "Assignee does not exist"
             {
-                Ok(_) => {
-                    (StatusCode::OK, "Successfully approved group join request").into_response()
-                }
-                Err(err) => {
-                    if err.to_string() == "Group does not exist" {
-                        return (StatusCode::NOT_FOUND, err.to_string()).into_response();
-                    }
-
-                    println!("{}", err);
-
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        "Error approving group join application",
-                    )
-                        .into_response()
-                }
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string()
+                == "Assignee is not able to review applications for this group"
+            {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
             }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error assigning application",
+            )
+                .into_response()
         }
-        entity::sea_orm_active_enums::GroupApplicationType::Leave => {
-            match data::groups::members::delete_group_members(
-                &db,
-                application.group_id,
-                vec![application.user_id],
+    }
+}
+
+// The applicant can view their application & post comments which aren't internal, anyone else
+// has to be able to manage the application's group, returns whether the user is reviewing the
+// application
+async fn require_application_permissions(
+    db: &DatabaseConnection,
+    session: Session,
+    application_id: i32,
+) -> Result<(i32, bool), Response> {
+    let user = get_session_user(db, session.clone()).await?;
+
+    let application = match data::groups::applications::get_application_by_id(db, application_id)
+        .await
+    {
+        Ok(Some(application)) => application,
+        Ok(None) => return Err((StatusCode::NOT_FOUND, "Application not found").into_response()),
+        Err(err) => {
+            println!("{}", err);
+
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group application",
             )
-            .await
-            {
-                Ok(_) => {
-                    (StatusCode::OK, "Successfully approved group leave request").into_response()
-                }
-                Err(err) => {
-                    if err.to_string() == "Group does not exist" {
-                        return (StatusCode::NOT_FOUND, err.to_string()).into_response();
-                    }
-
-                    println!("{}", err);
-
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        "Error approving group leave request",
-                    )
-                        .into_response()
-                }
+                .into_response());
+        }
+    };
+
+    if application.user_id == user.id {
+        return Ok((user.id, false));
+    }
+
+    require_group_permissions(db, session, application.group_id).await?;
+
+    Ok((user.id, true))
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/applications/{application_id}/comments",
+    responses(
+        (status = 200, description = "Application comment thread", body = Vec<GroupApplicationCommentDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_application_comments(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(i32,)>,
+) -> Response {
+    let (_, reviewer) = match require_application_permissions(&db, session, path.0).await {
+        Ok(permissions) => permissions,
+        Err(response) => return response,
+    };
+
+    match data::groups::applications::get_application_comments(&db, vec![path.0], reviewer).await {
+        Ok(comments) => (StatusCode::OK, Json(comments)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting application comments",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/applications/{application_id}/comments",
+    responses(
+        (status = 200, description = "Created comment", body = GroupApplicationCommentDto),
+        (status = 400, description = "Bad request", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn create_application_comment(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(i32,)>,
+    extract::Json(payload): extract::Json<NewGroupApplicationCommentDto>,
+) -> Response {
+    let (user_id, reviewer) = match require_application_permissions(&db, session, path.0).await {
+        Ok(permissions) => permissions,
+        Err(response) => return response,
+    };
+
+    if payload.internal && !reviewer {
+        return (
+            StatusCode::FORBIDDEN,
+            "Only reviewers can post internal comments",
+        )
+            .into_response();
+    }
+
+    match data::groups::applications::create_application_comment(&db, path.0, user_id, payload)
+        .await
+    {
+        Ok(comment) => (StatusCode::OK, Json(comment)).into_response(),
+        Err(err) => {
+            if err.to_string() == "Comment can't be empty" {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            } else if err.to_string() == "Application not found" {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
             }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error creating application comment",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BulkApplicationActionDto {
+    pub application_ids: Vec<i32>,
+    pub action: ApplicationAction,
+    // Shared by all of the applications
+    pub response_message: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/applications/bulk",
+    responses(
+        (status = 200, description = "Result for each application", body = Vec<ApplicationActionResultDto>),
+        (status = 400, description = "Invalid membership duration", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    params (
+        ("duration_days" = Option<i64>, Query, description = "Days until the accepted memberships expire"),
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn bulk_accept_reject_applications(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Query(params): Query<MembershipDurationParams>,
+    extract::Json(payload): extract::Json<BulkApplicationActionDto>,
+) -> Response {
+    // Permissions are checked for each application's group, failures are returned per item
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    let duration = match params.duration() {
+        Ok(duration) => duration,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
+
+    let application_status = match payload.action {
+        ApplicationAction::Accept => entity::sea_orm_active_enums::GroupApplicationStatus::Accepted,
+        ApplicationAction::Reject => entity::sea_orm_active_enums::GroupApplicationStatus::Rejected,
+    };
+
+    match data::groups::applications::bulk_update_group_applications(
+        &db,
+        &user,
+        payload.application_ids,
+        application_status,
+        payload.response_message,
+        duration,
+    )
+    .await
+    {
+        Ok(results) => (StatusCode::OK, Json(results)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error updating applications",
+            )
+                .into_response()
         }
     }
 }
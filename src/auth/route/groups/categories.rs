@@ -0,0 +1,179 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum::{
+    extract,
+    response::Response,
+    routing::{delete, get, post, put},
+    Extension, Router,
+};
+use sea_orm::DatabaseConnection;
+use tower_sessions::Session;
+
+use crate::auth::data;
+use crate::auth::model::groups::{GroupCategoryDto, NewGroupCategoryDto};
+use crate::auth::permissions::require_permissions;
+
+pub fn group_category_routes() -> Router {
+    Router::new()
+        .route("/", post(create_group_category))
+        .route("/", get(get_group_categories))
+        .route("/:category_id", put(update_group_category))
+        .route("/:category_id", delete(delete_group_category))
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/categories",
+    responses(
+        (status = 200, description = "Created category info", body = GroupCategoryDto),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn create_group_category(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    extract::Json(payload): extract::Json<NewGroupCategoryDto>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::categories::create_category(&db, payload).await {
+        Ok(category) => (StatusCode::OK, Json(GroupCategoryDto::from(category))).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error creating new category",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/categories",
+    responses(
+        (status = 200, description = "List of categories", body = Vec<GroupCategoryDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_group_categories(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::categories::get_categories(&db).await {
+        Ok(categories) => {
+            let categories: Vec<GroupCategoryDto> = categories
+                .into_iter()
+                .map(|category| category.into())
+                .collect();
+
+            (StatusCode::OK, Json(categories)).into_response()
+        }
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting categories",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/groups/categories/{category_id}",
+    responses(
+        (status = 200, description = "Updated category info", body = GroupCategoryDto),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn update_group_category(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(category_id): Path<(i32,)>,
+    extract::Json(payload): extract::Json<NewGroupCategoryDto>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::categories::update_category(&db, category_id.0, payload).await {
+        Ok(result) => match result {
+            Some(category) => {
+                (StatusCode::OK, Json(GroupCategoryDto::from(category))).into_response()
+            }
+            None => (StatusCode::NOT_FOUND, "Category not found").into_response(),
+        },
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error updating category").into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/groups/categories/{category_id}",
+    responses(
+        (status = 200, description = "Category deleted successfully", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn delete_group_category(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(category_id): Path<(i32,)>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::categories::delete_category(&db, category_id.0).await {
+        Ok(result) => match result {
+            Some(id) => {
+                (StatusCode::OK, format!("Deleted category with id {}", id)).into_response()
+            }
+            None => (StatusCode::NOT_FOUND, "Category not found").into_response(),
+        },
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error deleting category").into_response()
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum::{response::Response, routing::get, Extension, Router};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+use utoipa::ToSchema;
+
+use crate::auth::data;
+use crate::auth::permissions::{require_owner_permissions, require_permissions};
+
+pub fn group_membership_history_routes() -> Router {
+    Router::new().route("/", get(get_membership_history))
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GetMembershipHistoryParams {
+    pub group_id: Option<i32>,
+    pub user_id: Option<i32>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/history",
+    responses(
+        (status = 200, description = "Membership history, newest first", body = Vec<GroupMembershipHistoryDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    params (
+        ("group_id" = Option<i32>, Query, description = "Filter by group id"),
+        ("user_id" = Option<i32>, Query, description = "Filter by user id"),
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_membership_history(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Query(params): Query<GetMembershipHistoryParams>,
+) -> Response {
+    // Users managing a group's owner can only get history by filtering on that group
+    let permissions = match params.group_id {
+        Some(group_id) => {
+            // The history of archived groups is kept, so they are looked up as well
+            let group = match data::groups::get_group_by_id(&db, group_id).await {
+                Ok(None) => data::groups::get_archived_group_by_id(&db, group_id).await,
+                result => result,
+            };
+
+            let group = match group {
+                Ok(Some(group)) => group,
+                Ok(None) => return (StatusCode::NOT_FOUND, "Group not found").into_response(),
+                Err(err) => {
+                    println!("{}", err);
+
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Error getting membership history",
+                    )
+                        .into_response();
+                }
+            };
+
+            require_owner_permissions(&db, session, &group.owner_type.into(), group.owner_id).await
+        }
+        None => require_permissions(&db, session).await,
+    };
+
+    match permissions {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::history::get_membership_history(&db, params.group_id, params.user_id).await
+    {
+        Ok(history) => (StatusCode::OK, Json(history)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting membership history",
+            )
+                .into_response()
+        }
+    }
+}
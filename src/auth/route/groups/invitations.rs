@@ -0,0 +1,189 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum::{
+    extract,
+    response::Response,
+    routing::{delete, get, post},
+    Extension, Router,
+};
+use sea_orm::DatabaseConnection;
+use tower_sessions::Session;
+
+use crate::auth::data;
+use crate::auth::model::groups::{GroupInvitationDto, NewGroupInvitationDto};
+use crate::auth::permissions::require_group_permissions;
+
+pub fn group_invitation_routes() -> Router {
+    Router::new()
+        .route("/:group_id/invitations", get(get_group_invitations))
+        .route("/:group_id/invitations", post(create_group_invitation))
+        .route(
+            "/invitations/:invitation_id",
+            delete(revoke_group_invitation),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/{group_id}/invitations",
+    responses(
+        (status = 200, description = "Group invitations, newest first", body = Vec<GroupInvitationDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_group_invitations(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path((group_id,)): Path<(i32,)>,
+) -> Response {
+    match require_group_permissions(&db, session, group_id).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::invitations::get_group_invitations(&db, group_id).await {
+        Ok(invitations) => {
+            let invitations = invitations
+                .into_iter()
+                .map(|invitation| invitation.into())
+                .collect::<Vec<GroupInvitationDto>>();
+
+            (StatusCode::OK, Json(invitations)).into_response()
+        }
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group invitations",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/{group_id}/invitations",
+    responses(
+        (status = 200, description = "Created invitation", body = GroupInvitationDto),
+        (status = 400, description = "Invalid invitation expiry", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 409, description = "User is already a member or has a pending invitation", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn create_group_invitation(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path((group_id,)): Path<(i32,)>,
+    extract::Json(payload): extract::Json<NewGroupInvitationDto>,
+) -> Response {
+    let manager_id = match require_group_permissions(&db, session, group_id).await {
+        Ok(user_id) => user_id,
+        Err(response) => return response,
+    };
+
+    match data::groups::invitations::create_group_invitation(
+        &db,
+        group_id,
+        Some(manager_id),
+        payload,
+    )
+    .await
+    {
+        Ok(invitation) => {
+            (StatusCode::OK, Json(GroupInvitationDto::from(invitation))).into_response()
+        }
+        Err(err) => {
+            if err.to_string() == "Group does not exist" || err.to_string() == "User does not exist"
+            {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Already a member"
+                || err.to_string() == "Invitation already exists"
+            {
+                return (StatusCode::CONFLICT, err.to_string()).into_response();
+            } else if err.to_string() == "Invalid invitation expiry" {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error creating group invitation",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/groups/invitations/{invitation_id}",
+    responses(
+        (status = 200, description = "Revoked invitation", body = GroupInvitationDto),
+        (status = 403, description = "Insufficient permissions or invitation is no longer pending", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn revoke_group_invitation(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path((invitation_id,)): Path<(i32,)>,
+) -> Response {
+    let group_id = match data::groups::invitations::get_invitation_by_id(&db, invitation_id).await {
+        Ok(Some(invitation)) => invitation.group_id,
+        Ok(None) => return (StatusCode::NOT_FOUND, "Invitation not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group invitation",
+            )
+                .into_response();
+        }
+    };
+
+    match require_group_permissions(&db, session, group_id).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::invitations::revoke_group_invitation(&db, invitation_id).await {
+        Ok(invitation) => {
+            (StatusCode::OK, Json(GroupInvitationDto::from(invitation))).into_response()
+        }
+        Err(err) => {
+            if err.to_string() == "Invitation not found" {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Invitation is no longer pending" {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error revoking group invitation",
+            )
+                .into_response()
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use axum::extract::Path;
+use anyhow::anyhow;
+use axum::extract::{Path, Query};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
@@ -7,11 +8,17 @@ use axum::{
     routing::{delete, get, post},
     Extension, Router,
 };
+use chrono::Duration;
+use entity::sea_orm_active_enums::GroupMembershipReason;
 use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
 use tower_sessions::Session;
+use utoipa::ToSchema;
 
 use crate::auth::data;
-use crate::auth::permissions::require_permissions;
+use crate::auth::data::groups::visibility::{can_view_members, get_user_group_access, GroupAccess};
+use crate::auth::model::groups::{JoinGroupDto, JoinGroupRequest};
+use crate::auth::permissions::{get_session_user, require_group_permissions};
 
 pub fn group_member_routes() -> Router {
     Router::new()
@@ -22,11 +29,30 @@ pub fn group_member_routes() -> Router {
         .route("/:group_id/members", delete(delete_group_members))
 }
 
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MembershipDurationParams {
+    // Memberships are permanent if not set
+    pub duration_days: Option<i64>,
+}
+
+impl MembershipDurationParams {
+    pub fn duration(&self) -> Result<Option<Duration>, anyhow::Error> {
+        match self.duration_days {
+            None => Ok(None),
+            Some(days) => match Duration::try_days(days) {
+                Some(duration) if days > 0 => Ok(Some(duration)),
+                _ => Err(anyhow!("Invalid membership duration")),
+            },
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/groups/{group_id}/join",
     responses(
         (status = 200, description = "Joined/applied successfully", body = GroupDto),
+        (status = 400, description = "Invalid application", body = String),
         (status = 403, description = "Forbidden", body = String),
         (status = 404, description = "Not found", body = String),
         (status = 409, description = "Application to join already exists", body = String),
@@ -40,14 +66,43 @@ pub async fn join_group(
     Extension(db): Extension<DatabaseConnection>,
     session: Session,
     Path(group_id): Path<(i32,)>,
-    application_text: Json<Option<String>>,
+    application: Json<Option<JoinGroupRequest>>,
 ) -> Response {
-    let user_id = match require_permissions(&db, session).await {
-        Ok(user_id) => user_id,
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
         Err(response) => return response,
     };
 
-    match data::groups::members::join_group(&db, group_id.0, user_id, application_text.0).await {
+    // Hidden groups can't be applied to by users who aren't able to see them
+    match get_user_group_access(&db, &user, group_id.0).await {
+        Ok(Some((_, GroupAccess::Hidden))) | Ok(None) => {
+            return (StatusCode::NOT_FOUND, "Group does not exist").into_response()
+        }
+        Ok(_) => (),
+        Err(err) => {
+            println!("{}", err);
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error adding user to group",
+            )
+                .into_response();
+        }
+    };
+
+    let user_id = user.id;
+
+    let application = application.0.map(JoinGroupDto::from).unwrap_or_default();
+
+    match data::groups::members::join_group(
+        &db,
+        group_id.0,
+        user_id,
+        application.request_message,
+        application.answers,
+    )
+    .await
+    {
         Ok(application) => match application {
             Some(application) => (StatusCode::OK, Json(application)).into_response(),
             None => (StatusCode::OK, "Joined group successfully").into_response(),
@@ -57,8 +112,10 @@ pub async fn join_group(
                 || err.to_string() == "Already a member"
             {
                 return (StatusCode::CONFLICT, err.to_string()).into_response();
+            } else if err.to_string().starts_with("Application cooldown active") {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
             } else if err.to_string() == "User does not meet group requirements"
-                || err.to_string() == "Invalid application"
+                || err.to_string().starts_with("Invalid application")
             {
                 return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
             } else if err.to_string() == "Group does not exist" {
@@ -98,8 +155,8 @@ pub async fn leave_group(
     Path(group_id): Path<(i32,)>,
     application_text: Json<Option<String>>,
 ) -> Response {
-    let user_id = match require_permissions(&db, session).await {
-        Ok(user_id) => user_id,
+    let user_id = match get_session_user(&db, session).await {
+        Ok(user) => user.id,
         Err(response) => return response,
     };
 
@@ -132,7 +189,7 @@ pub async fn leave_group(
     get,
     path = "/groups/{group_id}/members",
     responses(
-        (status = 200, description = "Group members", body = Vec<UserDto>),
+        (status = 200, description = "Group members", body = Vec<GroupMemberDto>),
         (status = 403, description = "Insufficient permissions", body = String),
         (status = 404, description = "Not found", body = String),
         (status = 500, description = "Internal server error", body = String)
@@ -146,11 +203,31 @@ pub async fn get_group_members(
     session: Session,
     Path(group_id): Path<(i32,)>,
 ) -> Response {
-    match require_permissions(&db, session).await {
-        Ok(_) => (),
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
         Err(response) => return response,
     };
 
+    match get_user_group_access(&db, &user, group_id.0).await {
+        Ok(Some((_, GroupAccess::Hidden))) | Ok(None) => {
+            return (StatusCode::NOT_FOUND, "Group not found").into_response()
+        }
+        Ok(Some((group, access))) => {
+            if !can_view_members(&group, &access) {
+                return (StatusCode::FORBIDDEN, "Insufficient permissions").into_response();
+            }
+        }
+        Err(err) => {
+            println!("{}", err);
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group members",
+            )
+                .into_response();
+        }
+    };
+
     match data::groups::members::get_group_members(&db, group_id.0).await {
         Ok(members) => (StatusCode::OK, Json(members)).into_response(),
         Err(err) => {
@@ -170,10 +247,14 @@ pub async fn get_group_members(
     path = "/groups/{group_id}/members",
     responses(
         (status = 200, description = "Users added successfully", body = GroupDto),
+        (status = 400, description = "Invalid membership duration", body = String),
         (status = 403, description = "Forbidden", body = String),
         (status = 404, description = "Not found", body = String),
         (status = 500, description = "Internal server error", body = String)
     ),
+    params (
+        ("duration_days" = Option<i64>, Query, description = "Days until the memberships expire"),
+    ),
     security(
         ("login" = [])
     )
@@ -182,18 +263,35 @@ pub async fn add_group_members(
     Extension(db): Extension<DatabaseConnection>,
     session: Session,
     Path(group_id): Path<(i32,)>,
+    Query(params): Query<MembershipDurationParams>,
     user_ids: Json<Vec<i32>>,
 ) -> Response {
-    match require_permissions(&db, session).await {
-        Ok(_) => (),
+    let manager_id = match require_group_permissions(&db, session, group_id.0).await {
+        Ok(user_id) => user_id,
         Err(response) => return response,
     };
 
-    match data::groups::members::add_group_members(&db, group_id.0, user_ids.to_vec()).await {
+    let duration = match params.duration() {
+        Ok(duration) => duration,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
+
+    match data::groups::members::add_group_members(
+        &db,
+        group_id.0,
+        user_ids.to_vec(),
+        Some(manager_id),
+        duration,
+        GroupMembershipReason::Manual,
+    )
+    .await
+    {
         Ok(_) => (StatusCode::OK, "Users added successfully").into_response(),
         Err(err) => {
             if err.to_string() == "Group does not exist" {
                 return (StatusCode::NOT_FOUND, "Group does not exist").into_response();
+            } else if err.to_string() == "Invalid membership duration" {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
             }
 
             println!("{}", err);
@@ -226,14 +324,22 @@ pub async fn delete_group_members(
     Path(group_id): Path<(i32,)>,
     user_ids: Json<Vec<i32>>,
 ) -> Response {
-    match require_permissions(&db, session).await {
-        Ok(_) => (),
+    let manager_id = match require_group_permissions(&db, session, group_id.0).await {
+        Ok(user_id) => user_id,
         Err(response) => return response,
     };
 
     let user_ids = user_ids.to_vec();
 
-    match data::groups::members::delete_group_members(&db, group_id.0, user_ids.to_vec()).await {
+    match data::groups::members::delete_group_members(
+        &db,
+        group_id.0,
+        user_ids.to_vec(),
+        Some(manager_id),
+        GroupMembershipReason::Manual,
+    )
+    .await
+    {
         Ok(_) => (StatusCode::OK, "Users removed successfully").into_response(),
         Err(err) => {
             println!("{}", err);
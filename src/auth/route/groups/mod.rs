@@ -1,7 +1,13 @@
 pub mod applications;
+pub mod categories;
+pub mod history;
+pub mod invitations;
 pub mod members;
+pub mod owners;
+pub mod questionnaires;
+pub mod templates;
 
-use axum::extract::Path;
+use axum::extract::{Path, Query};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
@@ -12,26 +18,63 @@ use axum::{
     Extension, Router,
 };
 use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
 use tower_sessions::Session;
+use utoipa::ToSchema;
 
 use self::applications::group_application_routes;
+use self::categories::group_category_routes;
+use self::history::group_membership_history_routes;
+use self::invitations::group_invitation_routes;
 use self::members::group_member_routes;
+use self::owners::group_owner_routes;
+use self::questionnaires::group_questionnaire_routes;
+use self::templates::group_template_routes;
 
 use crate::auth::data;
 use crate::auth::data::groups::get_group_dto;
-use crate::auth::model::groups::{NewGroupDto, UpdateGroupDto};
-use crate::auth::permissions::require_permissions;
+use crate::auth::data::groups::owners::{get_managed_owners, get_owned_group_ids};
+use crate::auth::data::groups::visibility::get_visible_group_dto;
+use crate::auth::model::groups::{
+    CloneGroupDto, GroupApplicationSettingsDto, NewGroupDto, UpdateGroupDto,
+};
+use crate::auth::permissions::{
+    get_session_user, require_group_permissions, require_owner_permissions,
+};
 
 pub fn group_routes() -> Router {
     Router::new()
         .route("/", post(create_group))
         .route("/", get(get_groups))
+        .route("/available", get(get_available_groups))
+        .route("/archived", get(get_archived_groups))
         .route("/:group_id", get(get_group_by_id))
         .route("/:group_id", put(update_group))
         .route("/:group_id", delete(delete_group))
         .route("/:group_id/filters", get(get_group_filters))
+        .route("/:group_id/restore", post(restore_group))
+        .route("/:group_id/clone", post(clone_group))
+        .route(
+            "/:group_id/application-settings",
+            get(get_group_application_settings),
+        )
+        .route(
+            "/:group_id/application-settings",
+            put(update_group_application_settings),
+        )
         .nest("", group_member_routes())
+        .nest("", group_invitation_routes())
+        .nest("", group_questionnaire_routes())
         .nest("/applications", group_application_routes())
+        .nest("/categories", group_category_routes())
+        .nest("/history", group_membership_history_routes())
+        .nest("/owners", group_owner_routes())
+        .nest("/templates", group_template_routes())
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GetGroupsParams {
+    pub category_id: Option<i32>,
 }
 
 #[utoipa::path(
@@ -52,13 +95,23 @@ pub async fn create_group(
     session: Session,
     extract::Json(payload): extract::Json<NewGroupDto>,
 ) -> Response {
-    match require_permissions(&db, session).await {
+    match require_owner_permissions(&db, session.clone(), &payload.owner_type, payload.owner_id)
+        .await
+    {
         Ok(_) => (),
         Err(response) => return response,
     };
 
+    // Subgroups share membership with their parent so the parent has to be manageable as well
+    if let Some(parent_id) = payload.parent_id {
+        match require_group_permissions(&db, session, parent_id).await {
+            Ok(_) => (),
+            Err(response) => return response,
+        };
+    }
+
     match data::groups::create_group(&db, payload).await {
-        Ok(group) => match get_group_dto(&db, Some(vec![group.id])).await {
+        Ok(group) => match get_group_dto(&db, Some(vec![group.id]), None).await {
             Ok(mut dto) => {
                 if dto.is_empty() {
                     return (StatusCode::NOT_FOUND, "Group not found").into_response();
@@ -101,6 +154,9 @@ pub async fn create_group(
         (status = 404, description = "User not found", body = String),
         (status = 500, description = "Internal server error", body = String)
     ),
+    params (
+        ("category_id" = Option<i32>, Query, description = "Filter by category id"),
+    ),
     security(
         ("login" = [])
     )
@@ -108,13 +164,76 @@ pub async fn create_group(
 pub async fn get_groups(
     Extension(db): Extension<DatabaseConnection>,
     session: Session,
+    Query(params): Query<GetGroupsParams>,
 ) -> Response {
-    match require_permissions(&db, session).await {
-        Ok(_) => (),
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    // Users who aren't admins only get the groups owned by the corporations & alliances they manage
+    let group_ids = if user.admin {
+        None
+    } else {
+        let owners = match get_managed_owners(&db, user.id).await {
+            Ok(owners) => owners,
+            Err(err) => {
+                println!("{}", err);
+
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Error getting groups").into_response();
+            }
+        };
+
+        if owners.is_empty() {
+            return (StatusCode::FORBIDDEN, "Insufficient permissions").into_response();
+        }
+
+        match get_owned_group_ids(&db, &owners).await {
+            Ok(group_ids) => Some(group_ids),
+            Err(err) => {
+                println!("{}", err);
+
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Error getting groups").into_response();
+            }
+        }
+    };
+
+    match data::groups::get_group_dto(&db, group_ids, params.category_id).await {
+        Ok(groups) => (StatusCode::OK, Json(groups)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error getting groups").into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/available",
+    responses(
+        (status = 200, description = "Open & apply groups visible to the user", body = Vec<AvailableGroupDto>),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    params (
+        ("category_id" = Option<i32>, Query, description = "Filter by category id"),
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_available_groups(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Query(params): Query<GetGroupsParams>,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
         Err(response) => return response,
     };
 
-    match data::groups::get_group_dto(&db, None).await {
+    match data::groups::visibility::get_available_groups(&db, &user, params.category_id).await {
         Ok(groups) => (StatusCode::OK, Json(groups)).into_response(),
         Err(err) => {
             println!("{}", err);
@@ -142,12 +261,12 @@ pub async fn get_group_by_id(
     session: Session,
     Path(group_id): Path<(i32,)>,
 ) -> Response {
-    match require_permissions(&db, session).await {
-        Ok(_) => (),
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
         Err(response) => return response,
     };
 
-    match get_group_dto(&db, Some(vec![group_id.0])).await {
+    match get_visible_group_dto(&db, &user, vec![group_id.0]).await {
         Ok(mut group) => {
             if group.is_empty() {
                 return (StatusCode::NOT_FOUND, "Group not found").into_response();
@@ -181,7 +300,7 @@ pub async fn get_group_filters(
     session: Session,
     Path(group_id): Path<(i32,)>,
 ) -> Response {
-    match require_permissions(&db, session).await {
+    match require_group_permissions(&db, session, group_id.0).await {
         Ok(_) => (),
         Err(response) => return response,
     };
@@ -222,13 +341,28 @@ pub async fn update_group(
     Path(group_id): Path<(i32,)>,
     extract::Json(payload): extract::Json<UpdateGroupDto>,
 ) -> Response {
-    match require_permissions(&db, session).await {
+    match require_group_permissions(&db, session.clone(), group_id.0).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    // Prevents moving the group to an owner the user doesn't manage
+    match require_owner_permissions(&db, session.clone(), &payload.owner_type, payload.owner_id)
+        .await
+    {
         Ok(_) => (),
         Err(response) => return response,
     };
 
+    if let Some(parent_id) = payload.parent_id {
+        match require_group_permissions(&db, session, parent_id).await {
+            Ok(_) => (),
+            Err(response) => return response,
+        };
+    }
+
     match data::groups::update_group(&db, group_id.0, payload).await {
-        Ok(group) => match get_group_dto(&db, Some(vec![group.id])).await {
+        Ok(group) => match get_group_dto(&db, Some(vec![group.id]), None).await {
             Ok(mut dto) => {
                 if dto.is_empty() {
                     return (StatusCode::NOT_FOUND, "Group not found").into_response();
@@ -247,12 +381,17 @@ pub async fn update_group(
         },
         Err(err) => {
             let err_string = err.to_string();
-            if err_string.contains("Filter rule with id")
+            if (err_string.contains("Filter rule with id")
+                || err_string.contains("Filter group with id"))
                 && err_string.contains("does not belong to group")
             {
                 return (StatusCode::FORBIDDEN, err_string).into_response();
             }
 
+            if !err.is::<sea_orm::error::DbErr>() {
+                return (StatusCode::BAD_REQUEST, err_string).into_response();
+            }
+
             println!("{}", err);
 
             (StatusCode::INTERNAL_SERVER_ERROR, "Error updating group").into_response()
@@ -264,7 +403,7 @@ pub async fn update_group(
     delete,
     path = "/groups/{group_id}",
     responses(
-        (status = 200, description = "Group deleted successfully", body = GroupDto),
+        (status = 200, description = "Group archived successfully", body = String),
         (status = 403, description = "Insufficient permissions", body = String),
         (status = 404, description = "Not found", body = String),
         (status = 500, description = "Internal server error", body = String)
@@ -278,14 +417,15 @@ pub async fn delete_group(
     session: Session,
     Path(group_id): Path<(i32,)>,
 ) -> Response {
-    match require_permissions(&db, session).await {
+    match require_group_permissions(&db, session, group_id.0).await {
         Ok(_) => (),
         Err(response) => return response,
     };
 
-    match data::groups::delete_group(&db, group_id.0).await {
+    // Groups are archived & only permanently deleted once the retention period has passed
+    match data::groups::archive_group(&db, group_id.0).await {
         Ok(result) => match result {
-            Some(id) => (StatusCode::OK, format!("Deleted group with id {}", id)).into_response(),
+            Some(id) => (StatusCode::OK, format!("Archived group with id {}", id)).into_response(),
             None => (StatusCode::NOT_FOUND, "Group not found").into_response(),
         },
         Err(err) => {
@@ -295,3 +435,287 @@ pub async fn delete_group(
         }
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/groups/archived",
+    responses(
+        (status = 200, description = "List of archived groups", body = Vec<ArchivedGroupDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_archived_groups(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    // Users who aren't admins only get the archived groups of the owners they manage
+    let group_ids = if user.admin {
+        None
+    } else {
+        let owners = match get_managed_owners(&db, user.id).await {
+            Ok(owners) => owners,
+            Err(err) => {
+                println!("{}", err);
+
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Error getting groups").into_response();
+            }
+        };
+
+        if owners.is_empty() {
+            return (StatusCode::FORBIDDEN, "Insufficient permissions").into_response();
+        }
+
+        match get_owned_group_ids(&db, &owners).await {
+            Ok(group_ids) => Some(group_ids),
+            Err(err) => {
+                println!("{}", err);
+
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Error getting groups").into_response();
+            }
+        }
+    };
+
+    match data::groups::get_archived_groups(&db, group_ids).await {
+        Ok(groups) => (StatusCode::OK, Json(groups)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error getting groups").into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/{group_id}/restore",
+    responses(
+        (status = 200, description = "Restored group info", body = GroupDto),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn restore_group(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(group_id): Path<(i32,)>,
+) -> Response {
+    let group = match data::groups::get_archived_group_by_id(&db, group_id.0).await {
+        Ok(Some(group)) => group,
+        Ok(None) => return (StatusCode::NOT_FOUND, "Archived group not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Error restoring group").into_response();
+        }
+    };
+
+    match require_owner_permissions(&db, session, &group.owner_type.into(), group.owner_id).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::restore_group(&db, group_id.0).await {
+        Ok(Some(group)) => match get_group_dto(&db, Some(vec![group.id]), None).await {
+            Ok(mut group) => (StatusCode::OK, Json(group.pop())).into_response(),
+            Err(err) => {
+                println!("{}", err);
+
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Group restored but error getting group info",
+                )
+                    .into_response()
+            }
+        },
+        Ok(None) => (StatusCode::NOT_FOUND, "Archived group not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error restoring group").into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/{group_id}/clone",
+    responses(
+        (status = 200, description = "Cloned group info", body = GroupDto),
+        (status = 400, description = "Bad request", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn clone_group(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(group_id): Path<(i32,)>,
+    extract::Json(payload): extract::Json<CloneGroupDto>,
+) -> Response {
+    match require_group_permissions(&db, session.clone(), group_id.0).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    let group = match data::groups::get_group_by_id(&db, group_id.0).await {
+        Ok(Some(group)) => group,
+        Ok(None) => return (StatusCode::NOT_FOUND, "Group not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Error cloning group").into_response();
+        }
+    };
+
+    // The clone needs to be manageable by the user under its new owner
+    let (owner_type, owner_id) = match &payload.owner_type {
+        Some(owner_type) => (owner_type.clone(), payload.owner_id),
+        None => (group.owner_type.into(), group.owner_id),
+    };
+
+    match require_owner_permissions(&db, session.clone(), &owner_type, owner_id).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    // The clone is created under the same parent as the source group
+    if let Some(parent_id) = group.parent_id {
+        match require_group_permissions(&db, session, parent_id).await {
+            Ok(_) => (),
+            Err(response) => return response,
+        };
+    }
+
+    match data::groups::clone_group(&db, group_id.0, payload).await {
+        Ok(Some(group)) => match get_group_dto(&db, Some(vec![group.id]), None).await {
+            Ok(mut group) => (StatusCode::OK, Json(group.pop())).into_response(),
+            Err(err) => {
+                println!("{}", err);
+
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Group cloned but error getting group info",
+                )
+                    .into_response()
+            }
+        },
+        Ok(None) => (StatusCode::NOT_FOUND, "Group not found").into_response(),
+        Err(err) => {
+            if err.is::<sea_orm::error::DbErr>() {
+                println!("{}", err);
+
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Error cloning group").into_response();
+            }
+
+            (StatusCode::BAD_REQUEST, err.to_string()).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/{group_id}/application-settings",
+    responses(
+        (status = 200, description = "Group application settings", body = GroupApplicationSettingsDto),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_group_application_settings(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(group_id): Path<(i32,)>,
+) -> Response {
+    match require_group_permissions(&db, session, group_id.0).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::get_group_by_id(&db, group_id.0).await {
+        Ok(Some(group)) => (
+            StatusCode::OK,
+            Json(GroupApplicationSettingsDto::from(group)),
+        )
+            .into_response(),
+        Ok(None) => (StatusCode::NOT_FOUND, "Group not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group application settings",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/groups/{group_id}/application-settings",
+    responses(
+        (status = 200, description = "Updated group application settings", body = GroupApplicationSettingsDto),
+        (status = 400, description = "Invalid application settings", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn update_group_application_settings(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(group_id): Path<(i32,)>,
+    extract::Json(payload): extract::Json<GroupApplicationSettingsDto>,
+) -> Response {
+    match require_group_permissions(&db, session, group_id.0).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::applications::update_application_settings(&db, group_id.0, payload).await {
+        Ok(Some(group)) => (
+            StatusCode::OK,
+            Json(GroupApplicationSettingsDto::from(group)),
+        )
+            .into_response(),
+        Ok(None) => (StatusCode::NOT_FOUND, "Group not found").into_response(),
+        Err(err) => {
+            if err.to_string() == "Invalid application settings" {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error updating group application settings",
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum::{
+    response::Response,
+    routing::{delete, get, post},
+    Extension, Router,
+};
+use sea_orm::DatabaseConnection;
+use tower_sessions::Session;
+
+use crate::auth::data;
+use crate::auth::model::groups::GroupOwnerType;
+use crate::auth::permissions::{
+    get_session_user, require_owner_leader_permissions, require_owner_permissions,
+};
+
+pub fn group_owner_routes() -> Router {
+    Router::new()
+        .route("/", get(get_managed_group_owners))
+        .route("/:owner_type/:owner_id/delegates", get(get_owner_delegates))
+        .route(
+            "/:owner_type/:owner_id/delegates/:user_id",
+            post(add_owner_delegate),
+        )
+        .route(
+            "/:owner_type/:owner_id/delegates/:user_id",
+            delete(delete_owner_delegate),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/owners",
+    responses(
+        (status = 200, description = "Corporations & alliances whose groups the user can manage", body = Vec<GroupOwnerDto>),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_managed_group_owners(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    match data::groups::owners::get_managed_owners(&db, user.id).await {
+        Ok(owners) => (StatusCode::OK, Json(owners)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group owners",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/owners/{owner_type}/{owner_id}/delegates",
+    responses(
+        (status = 200, description = "User ids of the owner's delegates", body = Vec<i32>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_owner_delegates(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(GroupOwnerType, i32)>,
+) -> Response {
+    match require_owner_permissions(&db, session, &path.0, Some(path.1)).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::owners::get_owner_delegates(&db, path.0, path.1).await {
+        Ok(delegates) => (StatusCode::OK, Json(delegates)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error getting delegates").into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/owners/{owner_type}/{owner_id}/delegates/{user_id}",
+    responses(
+        (status = 200, description = "Successfully added delegate", body = String),
+        (status = 400, description = "Invalid owner type", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn add_owner_delegate(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(GroupOwnerType, i32, i32)>,
+) -> Response {
+    match require_owner_leader_permissions(&db, session, &path.0, path.1).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::owners::add_owner_delegate(&db, path.0, path.1, path.2).await {
+        Ok(_) => (StatusCode::OK, "Successfully added delegate").into_response(),
+        Err(err) => {
+            if err.to_string() == "User does not exist" {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            }
+
+            if !err.is::<sea_orm::error::DbErr>() {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error adding delegate").into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/groups/owners/{owner_type}/{owner_id}/delegates/{user_id}",
+    responses(
+        (status = 200, description = "Successfully removed delegate", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn delete_owner_delegate(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(GroupOwnerType, i32, i32)>,
+) -> Response {
+    match require_owner_leader_permissions(&db, session, &path.0, path.1).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::owners::delete_owner_delegate(&db, path.0, path.1, path.2).await {
+        Ok(result) => match result {
+            Some(_) => (StatusCode::OK, "Successfully removed delegate").into_response(),
+            None => (StatusCode::NOT_FOUND, "Delegate not found").into_response(),
+        },
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error removing delegate").into_response()
+        }
+    }
+}
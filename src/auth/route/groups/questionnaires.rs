@@ -0,0 +1,135 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum::{
+    extract,
+    response::Response,
+    routing::{get, put},
+    Extension, Router,
+};
+use sea_orm::DatabaseConnection;
+use tower_sessions::Session;
+
+use crate::auth::data;
+use crate::auth::data::groups::visibility::{get_user_group_access, GroupAccess};
+use crate::auth::model::groups::{GroupQuestionDto, NewGroupQuestionDto};
+use crate::auth::permissions::{get_session_user, require_group_permissions};
+
+pub fn group_questionnaire_routes() -> Router {
+    Router::new()
+        .route("/:group_id/questions", get(get_group_questions))
+        .route("/:group_id/questions", put(update_group_questions))
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/{group_id}/questions",
+    responses(
+        (status = 200, description = "Group application questionnaire", body = Vec<GroupQuestionDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_group_questions(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(group_id): Path<(i32,)>,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    // Anyone able to apply to the group is able to see its questionnaire
+    match get_user_group_access(&db, &user, group_id.0).await {
+        Ok(Some((_, GroupAccess::Hidden))) | Ok(None) => {
+            return (StatusCode::NOT_FOUND, "Group not found").into_response()
+        }
+        Ok(_) => (),
+        Err(err) => {
+            println!("{}", err);
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group questions",
+            )
+                .into_response();
+        }
+    };
+
+    match data::groups::questionnaires::get_group_questions(&db, group_id.0).await {
+        Ok(questions) => {
+            let questions: Vec<GroupQuestionDto> = questions
+                .into_iter()
+                .map(|question| question.into())
+                .collect();
+
+            (StatusCode::OK, Json(questions)).into_response()
+        }
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting group questions",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/groups/{group_id}/questions",
+    responses(
+        (status = 200, description = "Updated group application questionnaire", body = Vec<GroupQuestionDto>),
+        (status = 400, description = "Bad request", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn update_group_questions(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(group_id): Path<(i32,)>,
+    extract::Json(payload): extract::Json<Vec<NewGroupQuestionDto>>,
+) -> Response {
+    match require_group_permissions(&db, session, group_id.0).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::questionnaires::set_group_questions(&db, group_id.0, payload).await {
+        Ok(Some(questions)) => {
+            let questions: Vec<GroupQuestionDto> = questions
+                .into_iter()
+                .map(|question| question.into())
+                .collect();
+
+            (StatusCode::OK, Json(questions)).into_response()
+        }
+        Ok(None) => (StatusCode::NOT_FOUND, "Group not found").into_response(),
+        Err(err) => {
+            if err.is::<sea_orm::error::DbErr>() || err.is::<serde_json::Error>() {
+                println!("{}", err);
+
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Error updating group questions",
+                )
+                    .into_response();
+            }
+
+            (StatusCode::BAD_REQUEST, err.to_string()).into_response()
+        }
+    }
+}
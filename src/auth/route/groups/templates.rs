@@ -0,0 +1,262 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum::{
+    extract,
+    response::Response,
+    routing::{delete, get, post, put},
+    Extension, Router,
+};
+use sea_orm::DatabaseConnection;
+use tower_sessions::Session;
+
+use crate::auth::data;
+use crate::auth::data::groups::get_group_dto;
+use crate::auth::model::groups::{
+    GroupTemplateDto, InstantiateGroupTemplateDto, NewGroupTemplateDto,
+};
+use crate::auth::permissions::{
+    require_manager_permissions, require_owner_permissions, require_permissions,
+};
+
+use entity::auth_group_template::Model as GroupTemplate;
+
+pub fn group_template_routes() -> Router {
+    Router::new()
+        .route("/", post(create_group_template))
+        .route("/", get(get_group_templates))
+        .route("/:template_id", put(update_group_template))
+        .route("/:template_id", delete(delete_group_template))
+        .route(
+            "/:template_id/instantiate",
+            post(instantiate_group_template),
+        )
+}
+
+fn template_response(template: GroupTemplate) -> Response {
+    match GroupTemplateDto::try_from(template) {
+        Ok(template) => (StatusCode::OK, Json(template)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error reading template definition",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/templates",
+    responses(
+        (status = 200, description = "Created template info", body = GroupTemplateDto),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn create_group_template(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    extract::Json(payload): extract::Json<NewGroupTemplateDto>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::templates::create_template(&db, payload).await {
+        Ok(template) => template_response(template),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error creating new template",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/templates",
+    responses(
+        (status = 200, description = "List of templates", body = Vec<GroupTemplateDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_group_templates(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+) -> Response {
+    // Corporation & alliance managers need the template ids to instantiate them for their groups
+    match require_manager_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    let templates = match data::groups::templates::get_templates(&db).await {
+        Ok(templates) => templates,
+        Err(err) => {
+            println!("{}", err);
+
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Error getting templates").into_response();
+        }
+    };
+
+    let templates: Result<Vec<GroupTemplateDto>, _> = templates
+        .into_iter()
+        .map(GroupTemplateDto::try_from)
+        .collect();
+
+    match templates {
+        Ok(templates) => (StatusCode::OK, Json(templates)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error reading template definition",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/groups/templates/{template_id}",
+    responses(
+        (status = 200, description = "Updated template info", body = GroupTemplateDto),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn update_group_template(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(template_id): Path<(i32,)>,
+    extract::Json(payload): extract::Json<NewGroupTemplateDto>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::templates::update_template(&db, template_id.0, payload).await {
+        Ok(Some(template)) => template_response(template),
+        Ok(None) => (StatusCode::NOT_FOUND, "Template not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error updating template").into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/groups/templates/{template_id}",
+    responses(
+        (status = 200, description = "Template deleted successfully", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn delete_group_template(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(template_id): Path<(i32,)>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::templates::delete_template(&db, template_id.0).await {
+        Ok(Some(id)) => {
+            (StatusCode::OK, format!("Deleted template with id {}", id)).into_response()
+        }
+        Ok(None) => (StatusCode::NOT_FOUND, "Template not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (StatusCode::INTERNAL_SERVER_ERROR, "Error deleting template").into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/templates/{template_id}/instantiate",
+    responses(
+        (status = 200, description = "Created group info", body = GroupDto),
+        (status = 400, description = "Bad request", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn instantiate_group_template(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(template_id): Path<(i32,)>,
+    extract::Json(payload): extract::Json<InstantiateGroupTemplateDto>,
+) -> Response {
+    match require_owner_permissions(&db, session, &payload.owner_type, payload.owner_id).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::groups::templates::instantiate_template(&db, template_id.0, payload).await {
+        Ok(Some(group)) => match get_group_dto(&db, Some(vec![group.id]), None).await {
+            Ok(mut group) => (StatusCode::OK, Json(group.pop())).into_response(),
+            Err(err) => {
+                println!("{}", err);
+
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Group created but error getting group info",
+                )
+                    .into_response()
+            }
+        },
+        Ok(None) => (StatusCode::NOT_FOUND, "Template not found").into_response(),
+        Err(err) => {
+            if err.is::<sea_orm::error::DbErr>() || err.is::<serde_json::Error>() {
+                println!("{}", err);
+
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Error creating group from template",
+                )
+                    .into_response();
+            }
+
+            (StatusCode::BAD_REQUEST, err.to_string()).into_response()
+        }
+    }
+}
@@ -1,3 +1,5 @@
 pub mod auth;
 pub mod groups;
+pub mod organizations;
+pub mod recruitment;
 pub mod user;
@@ -0,0 +1,267 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum::{
+    extract,
+    response::Response,
+    routing::{delete, get, post, put},
+    Extension, Router,
+};
+use sea_orm::DatabaseConnection;
+use tower_sessions::Session;
+
+use crate::auth::data;
+use crate::auth::data::organizations::get_organization_dto;
+use crate::auth::model::organizations::NewOrganizationDto;
+use crate::auth::permissions::require_permissions;
+
+pub fn organization_routes() -> Router {
+    Router::new()
+        .route("/", post(create_organization))
+        .route("/", get(get_organizations))
+        .route("/:organization_id", get(get_organization_by_id))
+        .route("/:organization_id", put(update_organization))
+        .route("/:organization_id", delete(delete_organization))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations",
+    responses(
+        (status = 200, description = "Created organization info", body = OrganizationDto),
+        (status = 400, description = "Invalid organization", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn create_organization(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    extract::Json(payload): extract::Json<NewOrganizationDto>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::organizations::create_organization(&db, payload).await {
+        Ok(organization) => match get_organization_dto(&db, Some(vec![organization.id])).await {
+            Ok(mut dto) => {
+                if dto.is_empty() {
+                    return (StatusCode::NOT_FOUND, "Organization not found").into_response();
+                }
+
+                (StatusCode::OK, Json(dto.pop())).into_response()
+            }
+            Err(err) => {
+                println!("{}", err);
+
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Error getting organization info",
+                )
+                    .into_response()
+            }
+        },
+        Err(err) => {
+            if err.is::<sea_orm::error::DbErr>() {
+                println!("{}", err);
+
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Error creating new organization",
+                )
+                    .into_response();
+            }
+
+            (StatusCode::BAD_REQUEST, err.to_string()).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations",
+    responses(
+        (status = 200, description = "List of organizations", body = Vec<OrganizationDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_organizations(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match get_organization_dto(&db, None).await {
+        Ok(organizations) => (StatusCode::OK, Json(organizations)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting organizations",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}",
+    responses(
+        (status = 200, description = "Organization info", body = OrganizationDto),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_organization_by_id(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(organization_id): Path<(i32,)>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match get_organization_dto(&db, Some(vec![organization_id.0])).await {
+        Ok(mut organization) => {
+            if organization.is_empty() {
+                return (StatusCode::NOT_FOUND, "Organization not found").into_response();
+            }
+
+            (StatusCode::OK, Json(organization.pop())).into_response()
+        }
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting organizations",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}",
+    responses(
+        (status = 200, description = "Updated organization info", body = OrganizationDto),
+        (status = 400, description = "Invalid organization", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn update_organization(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(organization_id): Path<(i32,)>,
+    extract::Json(payload): extract::Json<NewOrganizationDto>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::organizations::update_organization(&db, organization_id.0, payload).await {
+        Ok(None) => (StatusCode::NOT_FOUND, "Organization not found").into_response(),
+        Ok(Some(organization)) => {
+            match get_organization_dto(&db, Some(vec![organization.id])).await {
+                Ok(mut dto) => (StatusCode::OK, Json(dto.pop())).into_response(),
+                Err(err) => {
+                    println!("{}", err);
+
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Error getting organization info",
+                    )
+                        .into_response()
+                }
+            }
+        }
+        Err(err) => {
+            if err.is::<sea_orm::error::DbErr>() {
+                println!("{}", err);
+
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Error updating organization",
+                )
+                    .into_response();
+            }
+
+            (StatusCode::BAD_REQUEST, err.to_string()).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}",
+    responses(
+        (status = 200, description = "Organization deleted successfully", body = String),
+        (status = 403, description = "Insufficient permissions or organization still in use", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn delete_organization(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(organization_id): Path<(i32,)>,
+) -> Response {
+    match require_permissions(&db, session).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::organizations::delete_organization(&db, organization_id.0).await {
+        Ok(result) => match result {
+            Some(id) => (
+                StatusCode::OK,
+                format!("Deleted organization with id {}", id),
+            )
+                .into_response(),
+            None => (StatusCode::NOT_FOUND, "Organization not found").into_response(),
+        },
+        Err(err) => {
+            if err.is::<sea_orm::error::DbErr>() {
+                println!("{}", err);
+
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Error deleting organization",
+                )
+                    .into_response();
+            }
+
+            (StatusCode::FORBIDDEN, err.to_string()).into_response()
+        }
+    }
+}
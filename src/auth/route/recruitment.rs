@@ -0,0 +1,458 @@
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use axum::{
+    extract,
+    response::Response,
+    routing::{get, post, put},
+    Extension, Router,
+};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+use utoipa::ToSchema;
+
+use crate::auth::data;
+use crate::auth::data::recruitment::RecruitmentFilter;
+use crate::auth::model::recruitment::{
+    NewRecruitmentDto, NewRecruitmentPipelineDto, RecruitmentPipelineDto, RecruitmentStatus,
+};
+use crate::auth::permissions::{get_session_user, require_group_permissions, require_permissions};
+
+pub fn recruitment_routes() -> Router {
+    Router::new()
+        .route("/", get(get_recruitments))
+        .route("/", post(submit_recruitment))
+        .route("/pipelines", get(get_recruitment_pipelines))
+        .route("/pipelines", post(create_recruitment_pipeline))
+        .route("/pipelines/:pipeline_id", put(update_recruitment_pipeline))
+        .route("/:recruitment_id/withdraw", post(withdraw_recruitment))
+        .route(
+            "/:recruitment_id/:recruitment_action",
+            post(approve_reject_recruitment),
+        )
+}
+
+// Allows admins & users managing the group recruits of the pipeline are handed off to
+async fn require_pipeline_permissions(
+    db: &DatabaseConnection,
+    session: Session,
+    pipeline_id: i32,
+) -> Result<i32, Response> {
+    match data::recruitment::get_pipeline_by_id(db, pipeline_id).await {
+        Ok(Some(pipeline)) => require_group_permissions(db, session, pipeline.group_id).await,
+        Ok(None) => Err((StatusCode::NOT_FOUND, "Recruitment pipeline not found").into_response()),
+        Err(err) => {
+            println!("{}", err);
+
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting recruitment pipeline",
+            )
+                .into_response())
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recruitment/pipelines",
+    responses(
+        (status = 200, description = "Open recruitment pipelines, admins also get closed pipelines", body = Vec<RecruitmentPipelineDto>),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_recruitment_pipelines(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    match data::recruitment::get_recruitment_pipelines(&db, user.admin).await {
+        Ok(pipelines) => {
+            let pipelines = pipelines
+                .into_iter()
+                .map(|pipeline| pipeline.into())
+                .collect::<Vec<RecruitmentPipelineDto>>();
+
+            (StatusCode::OK, Json(pipelines)).into_response()
+        }
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting recruitment pipelines",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/recruitment/pipelines",
+    responses(
+        (status = 200, description = "Created recruitment pipeline", body = RecruitmentPipelineDto),
+        (status = 400, description = "Invalid recruitment pipeline", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn create_recruitment_pipeline(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    extract::Json(payload): extract::Json<NewRecruitmentPipelineDto>,
+) -> Response {
+    match require_group_permissions(&db, session, payload.group_id).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::recruitment::create_recruitment_pipeline(&db, payload).await {
+        Ok(pipeline) => {
+            (StatusCode::OK, Json(RecruitmentPipelineDto::from(pipeline))).into_response()
+        }
+        Err(err) => {
+            if err.to_string() == "Group does not exist" {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Invalid recruitment pipeline name" {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error creating recruitment pipeline",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/recruitment/pipelines/{pipeline_id}",
+    responses(
+        (status = 200, description = "Updated recruitment pipeline", body = RecruitmentPipelineDto),
+        (status = 400, description = "Invalid recruitment pipeline", body = String),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn update_recruitment_pipeline(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path((pipeline_id,)): Path<(i32,)>,
+    extract::Json(payload): extract::Json<NewRecruitmentPipelineDto>,
+) -> Response {
+    // Managers need to be able to manage both the current & the new group
+    match require_pipeline_permissions(&db, session.clone(), pipeline_id).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match require_group_permissions(&db, session, payload.group_id).await {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    match data::recruitment::update_recruitment_pipeline(&db, pipeline_id, payload).await {
+        Ok(Some(pipeline)) => {
+            (StatusCode::OK, Json(RecruitmentPipelineDto::from(pipeline))).into_response()
+        }
+        Ok(None) => (StatusCode::NOT_FOUND, "Recruitment pipeline not found").into_response(),
+        Err(err) => {
+            if err.to_string() == "Group does not exist" {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Invalid recruitment pipeline name" {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error updating recruitment pipeline",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GetRecruitmentParams {
+    pub pipeline_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub status: Option<RecruitmentStatus>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/recruitment",
+    responses(
+        (status = 200, description = "Recruitments, newest first", body = Vec<RecruitmentDto>),
+        (status = 403, description = "Insufficient permissions", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    params (
+        ("pipeline_id" = Option<i32>, Query, description = "Filter by recruitment pipeline id"),
+        ("user_id" = Option<i32>, Query, description = "Filter by user id"),
+        ("status" = Option<RecruitmentStatus>, Query, description = "Filter by recruitment status"),
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_recruitments(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Query(params): Query<GetRecruitmentParams>,
+) -> Response {
+    // Recruiters can only get recruitments by filtering on a pipeline they manage
+    let permissions = match params.pipeline_id {
+        Some(pipeline_id) => require_pipeline_permissions(&db, session, pipeline_id).await,
+        None => require_permissions(&db, session).await,
+    };
+
+    match permissions {
+        Ok(_) => (),
+        Err(response) => return response,
+    };
+
+    let filter = RecruitmentFilter {
+        pipeline_id: params.pipeline_id,
+        user_id: params.user_id,
+        status: params.status.map(|status| status.into()),
+    };
+
+    match data::recruitment::get_recruitments(&db, filter).await {
+        Ok(recruitments) => (StatusCode::OK, Json(recruitments)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting recruitments",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/recruitment",
+    responses(
+        (status = 200, description = "Submitted recruitment, login with the recruitment id to grant any required scopes", body = RecruitmentDto),
+        (status = 400, description = "Invalid application", body = String),
+        (status = 403, description = "Forbidden", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 409, description = "Recruitment or application already in progress", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn submit_recruitment(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    extract::Json(payload): extract::Json<NewRecruitmentDto>,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    let recruitment = match data::recruitment::submit_recruitment(&db, user.id, payload).await {
+        Ok(recruitment) => recruitment,
+        Err(err) => {
+            if err.to_string() == "Recruitment pipeline not found"
+                || err.to_string() == "Group does not exist"
+            {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Recruitment pipeline is closed"
+                || err.to_string() == "Character is not owned by user"
+                || err.to_string().starts_with("Application cooldown active")
+            {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
+            } else if err.to_string() == "Character is already in the corporation"
+                || err.to_string() == "Recruitment already in progress"
+                || err.to_string() == "Application to join already exists"
+            {
+                return (StatusCode::CONFLICT, err.to_string()).into_response();
+            } else if err.to_string().starts_with("Invalid application") {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error submitting recruitment",
+            )
+                .into_response();
+        }
+    };
+
+    get_recruitment_response(&db, recruitment.id).await
+}
+
+async fn get_recruitment_response(db: &DatabaseConnection, recruitment_id: i32) -> Response {
+    match data::recruitment::get_recruitment_dto_by_id(db, recruitment_id).await {
+        Ok(Some(recruitment)) => (StatusCode::OK, Json(recruitment)).into_response(),
+        Ok(None) => (StatusCode::NOT_FOUND, "Recruitment not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting recruitment",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/recruitment/{recruitment_id}/withdraw",
+    responses(
+        (status = 200, description = "Withdrawn recruitment", body = RecruitmentDto),
+        (status = 403, description = "Recruitment is already complete", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn withdraw_recruitment(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path((recruitment_id,)): Path<(i32,)>,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    match data::recruitment::withdraw_recruitment(&db, recruitment_id, user.id).await {
+        Ok(recruitment) => get_recruitment_response(&db, recruitment.id).await,
+        Err(err) => {
+            if err.to_string() == "Recruitment not found" {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Recruitment is already complete" {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error withdrawing recruitment",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub enum RecruitmentAction {
+    Approve,
+    Reject,
+}
+
+#[utoipa::path(
+    post,
+    path = "/recruitment/{recruitment_id}/{recruitment_action}",
+    responses(
+        (status = 200, description = "Updated recruitment, approved recruits are handed off to the pipeline's group once they join the corporation", body = RecruitmentDto),
+        (status = 403, description = "Insufficient permissions or recruitment isn't being vetted", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn approve_reject_recruitment(
+    Extension(db): Extension<DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(i32, RecruitmentAction)>,
+    response_message: Json<Option<String>>,
+) -> Response {
+    let pipeline_id = match data::recruitment::get_recruitment_by_id(&db, path.0).await {
+        Ok(Some(recruitment)) => recruitment.pipeline_id,
+        Ok(None) => return (StatusCode::NOT_FOUND, "Recruitment not found").into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting recruitment",
+            )
+                .into_response();
+        }
+    };
+
+    let recruiter_id = match require_pipeline_permissions(&db, session, pipeline_id).await {
+        Ok(user_id) => user_id,
+        Err(response) => return response,
+    };
+
+    let approve = match path.1 {
+        RecruitmentAction::Approve => true,
+        RecruitmentAction::Reject => false,
+    };
+
+    match data::recruitment::respond_to_recruitment(
+        &db,
+        path.0,
+        recruiter_id,
+        approve,
+        response_message.0,
+    )
+    .await
+    {
+        Ok(recruitment) => get_recruitment_response(&db, recruitment.id).await,
+        Err(err) => {
+            if err.to_string() == "Recruitment not found"
+                || err.to_string() == "Recruitment pipeline not found"
+            {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Required scopes have not been granted"
+                || err.to_string() == "Recruitment is not being vetted"
+            {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error updating recruitment",
+            )
+                .into_response()
+        }
+    }
+}
@@ -1,20 +1,32 @@
 use axum::{
+    extract::Path,
     http::StatusCode,
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{get, post},
     Extension, Json, Router,
 };
+use chrono::{DateTime, Utc};
 use sea_orm::ColumnTrait;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use tower_sessions::Session;
+use utoipa::ToSchema;
 
 use crate::{
     auth::{
         data::{
-            groups::get_group_dto,
+            groups::{
+                invitations::{get_user_invitations, respond_to_group_invitation},
+                visibility::get_visible_group_dto,
+            },
+            recruitment::{get_recruitments, RecruitmentFilter},
             user::{bulk_get_user_groups, get_user_character_ownerships},
         },
-        model::user::UserDto,
+        model::{
+            groups::GroupInvitationDto,
+            user::{UserDto, UserGroupMembershipDto},
+        },
+        permissions::get_session_user,
     },
     eve::{data::character::CharacterRepository, service::affiliation::get_character_affiliations},
 };
@@ -25,6 +37,12 @@ pub fn user_routes() -> Router {
         .route("/main", get(get_user_main_character))
         .route("/characters", get(get_user_characters))
         .route("/groups", get(get_user_groups))
+        .route("/invitations", get(get_user_group_invitations))
+        .route(
+            "/invitations/:invitation_id/:invitation_action",
+            post(respond_to_user_invitation),
+        )
+        .route("/recruitment", get(get_user_recruitments))
 }
 
 async fn get_user_id_from_session(session: Session) -> Result<i32, Response> {
@@ -225,7 +243,7 @@ pub async fn get_user_characters(
     get,
     path = "/user/groups",
     responses(
-        (status = 200, description = "Returns list of user groups", body = Vec<GroupDto>),
+        (status = 200, description = "Returns list of user groups including inherited memberships", body = Vec<UserGroupMembershipDto>),
         (status = 404, description = "User not found", body = String),
         (status = 500, description = "Internal server error", body = String)
     ),
@@ -237,17 +255,17 @@ pub async fn get_user_groups(
     Extension(db): Extension<sea_orm::DatabaseConnection>,
     session: Session,
 ) -> Response {
-    let user_id = match get_user_id_from_session(session).await {
-        Ok(user_id) => user_id,
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
         Err(response) => return response,
     };
 
-    let group_ids = match bulk_get_user_groups(&db, vec![user_id]).await {
-        Ok(groups) => {
-            if groups.is_empty() {
+    let memberships = match bulk_get_user_groups(&db, vec![user.id]).await {
+        Ok(mut groups) => {
+            if groups.is_empty() || groups[0].groups.is_empty() {
                 return (StatusCode::NOT_FOUND, "No groups found for user").into_response();
             } else {
-                groups[0].groups.clone()
+                groups.remove(0).groups
             }
         }
         Err(err) => {
@@ -261,8 +279,32 @@ pub async fn get_user_groups(
         }
     };
 
-    match get_group_dto(&db, Some(group_ids)).await {
-        Ok(groups) => (StatusCode::OK, Json(groups)).into_response(),
+    let group_ids = memberships
+        .iter()
+        .map(|membership| membership.group_id)
+        .collect::<Vec<i32>>();
+
+    match get_visible_group_dto(&db, &user, group_ids).await {
+        Ok(groups) => {
+            let groups = groups
+                .into_iter()
+                .map(|group| {
+                    let membership = memberships
+                        .iter()
+                        .find(|membership| membership.group_id == group.id);
+
+                    UserGroupMembershipDto {
+                        inherited_from: membership.and_then(|membership| membership.inherited_from),
+                        expires: membership
+                            .and_then(|membership| membership.expires)
+                            .map(|expires| DateTime::from_naive_utc_and_offset(expires, Utc)),
+                        group,
+                    }
+                })
+                .collect::<Vec<UserGroupMembershipDto>>();
+
+            (StatusCode::OK, Json(groups)).into_response()
+        }
         Err(err) => {
             println!("{}", err);
 
@@ -274,3 +316,148 @@ pub async fn get_user_groups(
         }
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/user/invitations",
+    responses(
+        (status = 200, description = "Pending group invitations for the user, newest first", body = Vec<GroupInvitationDto>),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_user_group_invitations(
+    Extension(db): Extension<sea_orm::DatabaseConnection>,
+    session: Session,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    match get_user_invitations(&db, user.id).await {
+        Ok(invitations) => {
+            let invitations = invitations
+                .into_iter()
+                .map(|invitation| invitation.into())
+                .collect::<Vec<GroupInvitationDto>>();
+
+            (StatusCode::OK, Json(invitations)).into_response()
+        }
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting user invitations.",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub enum InvitationAction {
+    Accept,
+    Decline,
+}
+
+#[utoipa::path(
+    post,
+    path = "/user/invitations/{invitation_id}/{invitation_action}",
+    responses(
+        (status = 200, description = "Updated invitation", body = GroupInvitationDto),
+        (status = 400, description = "User does not meet group requirements", body = String),
+        (status = 403, description = "Invitation is no longer pending or has expired", body = String),
+        (status = 404, description = "Not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn respond_to_user_invitation(
+    Extension(db): Extension<sea_orm::DatabaseConnection>,
+    session: Session,
+    Path(path): Path<(i32, InvitationAction)>,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    let accept = match path.1 {
+        InvitationAction::Accept => true,
+        InvitationAction::Decline => false,
+    };
+
+    match respond_to_group_invitation(&db, path.0, user.id, accept).await {
+        Ok(invitation) => {
+            (StatusCode::OK, Json(GroupInvitationDto::from(invitation))).into_response()
+        }
+        Err(err) => {
+            if err.to_string() == "Invitation not found"
+                || err.to_string() == "Group does not exist"
+            {
+                return (StatusCode::NOT_FOUND, err.to_string()).into_response();
+            } else if err.to_string() == "Invitation is no longer pending"
+                || err.to_string() == "Invitation has expired"
+            {
+                return (StatusCode::FORBIDDEN, err.to_string()).into_response();
+            } else if err.to_string() == "User does not meet group requirements" {
+                return (StatusCode::BAD_REQUEST, err.to_string()).into_response();
+            }
+
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error responding to invitation.",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/user/recruitment",
+    responses(
+        (status = 200, description = "Recruitments of the user, newest first", body = Vec<RecruitmentDto>),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    security(
+        ("login" = [])
+    )
+)]
+pub async fn get_user_recruitments(
+    Extension(db): Extension<sea_orm::DatabaseConnection>,
+    session: Session,
+) -> Response {
+    let user = match get_session_user(&db, session).await {
+        Ok(user) => user,
+        Err(response) => return response,
+    };
+
+    let filter = RecruitmentFilter {
+        user_id: Some(user.id),
+        ..Default::default()
+    };
+
+    match get_recruitments(&db, filter).await {
+        Ok(recruitments) => (StatusCode::OK, Json(recruitments)).into_response(),
+        Err(err) => {
+            println!("{}", err);
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Error getting user recruitments.",
+            )
+                .into_response()
+        }
+    }
+}
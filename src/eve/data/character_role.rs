@@ -0,0 +1,126 @@
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+
+use entity::eve_character_role::Model as CharacterRole;
+use entity::prelude::EveCharacterRole;
+
+pub struct CharacterRoleRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> CharacterRoleRepository<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    // Replaces all stored roles of a character with the provided roles
+    pub async fn replace(
+        &self,
+        character_id: i32,
+        roles: Vec<String>,
+    ) -> Result<(), sea_orm::DbErr> {
+        EveCharacterRole::delete_many()
+            .filter(entity::eve_character_role::Column::CharacterId.eq(character_id))
+            .exec(self.db)
+            .await?;
+
+        if roles.is_empty() {
+            return Ok(());
+        }
+
+        let roles = roles
+            .into_iter()
+            .map(|role| entity::eve_character_role::ActiveModel {
+                character_id: Set(character_id),
+                role: Set(role),
+                ..Default::default()
+            })
+            .collect::<Vec<entity::eve_character_role::ActiveModel>>();
+
+        EveCharacterRole::insert_many(roles).exec(self.db).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_by_character_ids(
+        &self,
+        character_ids: &[i32],
+    ) -> Result<Vec<CharacterRole>, sea_orm::DbErr> {
+        EveCharacterRole::find()
+            .filter(entity::eve_character_role::Column::CharacterId.is_in(character_ids.to_vec()))
+            .all(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eve::data::{character::CharacterRepository, corporation::CorporationRepository};
+
+    use super::*;
+    use rand::Rng;
+    use sea_orm::{ConnectionTrait, Database, DbBackend, Schema};
+
+    async fn initialize_test(db: &DatabaseConnection) -> Result<i32, sea_orm::DbErr> {
+        let schema = Schema::new(DbBackend::Sqlite);
+
+        let stmts = vec![
+            schema.create_table_from_entity(entity::prelude::EveAlliance),
+            schema.create_table_from_entity(entity::prelude::EveCorporation),
+            schema.create_table_from_entity(entity::prelude::EveCharacter),
+            schema.create_table_from_entity(entity::prelude::EveCharacterRole),
+        ];
+
+        for stmt in stmts {
+            let _ = db.execute(db.get_database_backend().build(&stmt)).await?;
+        }
+
+        let mut rng = rand::thread_rng();
+
+        // create corporation & character first due to foreign key constraint
+        let corporation_id = rng.gen::<i32>();
+        let character_id = rng.gen::<i32>();
+
+        CorporationRepository::new(db)
+            .create(
+                corporation_id,
+                "Corporation".to_string(),
+                None,
+                character_id,
+            )
+            .await?;
+
+        CharacterRepository::new(db)
+            .create(character_id, "Character".to_string(), corporation_id)
+            .await?;
+
+        Ok(character_id)
+    }
+
+    #[tokio::test]
+    async fn replace_character_roles() -> Result<(), sea_orm::DbErr> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let character_id = initialize_test(&db).await?;
+        let repo = CharacterRoleRepository::new(&db);
+
+        repo.replace(
+            character_id,
+            vec!["Director".to_string(), "Accountant".to_string()],
+        )
+        .await?;
+        repo.replace(character_id, vec!["Personnel_Manager".to_string()])
+            .await?;
+
+        let roles = repo.get_by_character_ids(&[character_id]).await?;
+
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].role, "Personnel_Manager");
+
+        repo.replace(character_id, vec![]).await?;
+
+        let roles = repo.get_by_character_ids(&[character_id]).await?;
+
+        assert!(roles.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+
+use entity::eve_character_title::Model as CharacterTitle;
+use entity::prelude::EveCharacterTitle;
+
+pub struct CharacterTitleRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> CharacterTitleRepository<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    // Replaces all stored titles of a character with the provided (title_id, title_name) pairs
+    pub async fn replace(
+        &self,
+        character_id: i32,
+        titles: Vec<(i32, String)>,
+    ) -> Result<(), sea_orm::DbErr> {
+        EveCharacterTitle::delete_many()
+            .filter(entity::eve_character_title::Column::CharacterId.eq(character_id))
+            .exec(self.db)
+            .await?;
+
+        if titles.is_empty() {
+            return Ok(());
+        }
+
+        let titles = titles
+            .into_iter()
+            .map(
+                |(title_id, title_name)| entity::eve_character_title::ActiveModel {
+                    character_id: Set(character_id),
+                    title_id: Set(title_id),
+                    title_name: Set(title_name),
+                    ..Default::default()
+                },
+            )
+            .collect::<Vec<entity::eve_character_title::ActiveModel>>();
+
+        EveCharacterTitle::insert_many(titles).exec(self.db).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_by_character_ids(
+        &self,
+        character_ids: &[i32],
+    ) -> Result<Vec<CharacterTitle>, sea_orm::DbErr> {
+        EveCharacterTitle::find()
+            .filter(entity::eve_character_title::Column::CharacterId.is_in(character_ids.to_vec()))
+            .all(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eve::data::{character::CharacterRepository, corporation::CorporationRepository};
+
+    use super::*;
+    use rand::Rng;
+    use sea_orm::{ConnectionTrait, Database, DbBackend, Schema};
+
+    async fn initialize_test(db: &DatabaseConnection) -> Result<i32, sea_orm::DbErr> {
+        let schema = Schema::new(DbBackend::Sqlite);
+
+        let stmts = vec![
+            schema.create_table_from_entity(entity::prelude::EveAlliance),
+            schema.create_table_from_entity(entity::prelude::EveCorporation),
+            schema.create_table_from_entity(entity::prelude::EveCharacter),
+            schema.create_table_from_entity(entity::prelude::EveCharacterTitle),
+        ];
+
+        for stmt in stmts {
+            let _ = db.execute(db.get_database_backend().build(&stmt)).await?;
+        }
+
+        let mut rng = rand::thread_rng();
+
+        // create corporation & character first due to foreign key constraint
+        let corporation_id = rng.gen::<i32>();
+        let character_id = rng.gen::<i32>();
+
+        CorporationRepository::new(db)
+            .create(
+                corporation_id,
+                "Corporation".to_string(),
+                None,
+                character_id,
+            )
+            .await?;
+
+        CharacterRepository::new(db)
+            .create(character_id, "Character".to_string(), corporation_id)
+            .await?;
+
+        Ok(character_id)
+    }
+
+    #[tokio::test]
+    async fn replace_character_titles() -> Result<(), sea_orm::DbErr> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let character_id = initialize_test(&db).await?;
+        let repo = CharacterTitleRepository::new(&db);
+
+        repo.replace(
+            character_id,
+            vec![
+                (1, "Member".to_string()),
+                (2, "Fleet Commander".to_string()),
+            ],
+        )
+        .await?;
+        repo.replace(character_id, vec![(2, "Fleet Commander".to_string())])
+            .await?;
+
+        let titles = repo.get_by_character_ids(&[character_id]).await?;
+
+        assert_eq!(titles.len(), 1);
+        assert_eq!(titles[0].title_id, 2);
+        assert_eq!(titles[0].title_name, "Fleet Commander");
+
+        Ok(())
+    }
+}
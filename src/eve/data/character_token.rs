@@ -0,0 +1,185 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use sea_orm::{
+    sea_query::OnConflict, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set,
+};
+
+use entity::eve_character_token::Model as CharacterToken;
+use entity::prelude::EveCharacterToken;
+
+// Refresh tokens grant ESI access without the owner logging in, so they are stored encrypted
+// with AES-256-GCM using the ESI_TOKEN_ENCRYPTION_KEY & a random nonce prepended to each token
+fn encryption_key() -> Result<LessSafeKey, DbErr> {
+    #[cfg(not(test))]
+    let key = std::env::var("ESI_TOKEN_ENCRYPTION_KEY")
+        .map_err(|_| DbErr::Custom("ESI_TOKEN_ENCRYPTION_KEY must be set".to_string()))?;
+
+    #[cfg(test)]
+    let key = STANDARD.encode([7u8; 32]);
+
+    let key = STANDARD.decode(key.trim()).ok().and_then(|key| {
+        UnboundKey::new(&AES_256_GCM, &key)
+            .ok()
+            .map(LessSafeKey::new)
+    });
+
+    key.ok_or_else(|| {
+        DbErr::Custom("ESI_TOKEN_ENCRYPTION_KEY must be a base64 encoded 32 byte key".to_string())
+    })
+}
+
+fn encrypt_token(key: &LessSafeKey, refresh_token: &str) -> Result<String, DbErr> {
+    let error = || DbErr::Custom("Failed to encrypt refresh token".to_string());
+
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new().fill(&mut nonce).map_err(|_| error())?;
+
+    let mut token = refresh_token.as_bytes().to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::empty(),
+        &mut token,
+    )
+    .map_err(|_| error())?;
+
+    Ok(STANDARD.encode([nonce.as_slice(), &token].concat()))
+}
+
+fn decrypt_token(key: &LessSafeKey, encrypted_token: &str) -> Option<String> {
+    let token = STANDARD.decode(encrypted_token).ok()?;
+
+    if token.len() < NONCE_LEN {
+        return None;
+    }
+
+    let (nonce, token) = token.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+
+    let mut token = token.to_vec();
+    let token = key.open_in_place(nonce, Aad::empty(), &mut token).ok()?;
+
+    String::from_utf8(token.to_vec()).ok()
+}
+
+pub struct CharacterTokenRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> CharacterTokenRepository<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    // Stores the refresh token of a character, replacing any token stored previously
+    pub async fn upsert(
+        &self,
+        character_id: i32,
+        refresh_token: String,
+        scopes: String,
+    ) -> Result<(), DbErr> {
+        let refresh_token = encrypt_token(&encryption_key()?, &refresh_token)?;
+
+        let token = entity::eve_character_token::ActiveModel {
+            character_id: Set(character_id),
+            refresh_token: Set(refresh_token),
+            scopes: Set(scopes),
+            last_updated: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        EveCharacterToken::insert(token)
+            .on_conflict(
+                OnConflict::column(entity::eve_character_token::Column::CharacterId)
+                    .update_columns([
+                        entity::eve_character_token::Column::RefreshToken,
+                        entity::eve_character_token::Column::Scopes,
+                        entity::eve_character_token::Column::LastUpdated,
+                    ])
+                    .to_owned(),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    // Returns the stored tokens with their refresh token decrypted, tokens which fail to decrypt
+    // e.g. after the encryption key was changed are skipped until the character logs in again
+    pub async fn get_all(&self) -> Result<Vec<CharacterToken>, DbErr> {
+        let key = encryption_key()?;
+
+        let tokens = EveCharacterToken::find().all(self.db).await?;
+
+        let tokens = tokens
+            .into_iter()
+            .filter_map(
+                |mut token| match decrypt_token(&key, &token.refresh_token) {
+                    Some(refresh_token) => {
+                        token.refresh_token = refresh_token;
+
+                        Some(token)
+                    }
+                    None => {
+                        println!(
+                            "Failed to decrypt refresh token of character {}",
+                            token.character_id
+                        );
+
+                        None
+                    }
+                },
+            )
+            .collect();
+
+        Ok(tokens)
+    }
+
+    pub async fn delete(&self, character_id: i32) -> Result<(), DbErr> {
+        EveCharacterToken::delete_many()
+            .filter(entity::eve_character_token::Column::CharacterId.eq(character_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::EntityTrait;
+
+    use entity::prelude::EveCharacterToken;
+
+    use super::CharacterTokenRepository;
+    use crate::error::DbOrReqwestError;
+    use crate::eve::service::character::get_or_create_character;
+    use crate::test_utils::initialize_test_db;
+
+    #[tokio::test]
+    async fn refresh_token_stored_encrypted() -> Result<(), DbOrReqwestError> {
+        let db = initialize_test_db().await?;
+
+        let character = get_or_create_character(&db, 180548812).await?;
+        let token_repo = CharacterTokenRepository::new(&db);
+
+        token_repo
+            .upsert(character.character_id, "token".to_string(), "".to_string())
+            .await?;
+
+        let stored = EveCharacterToken::find().all(&db).await?;
+        let tokens = token_repo.get_all().await?;
+
+        assert_ne!(stored[0].refresh_token, "token");
+        assert_eq!(tokens[0].refresh_token, "token");
+
+        // Tokens which fail to decrypt are skipped
+        let mut token: entity::eve_character_token::ActiveModel = stored[0].clone().into();
+        token.refresh_token = sea_orm::Set("token".to_string());
+        EveCharacterToken::update(token).exec(&db).await?;
+
+        assert!(token_repo.get_all().await?.is_empty());
+
+        Ok(())
+    }
+}
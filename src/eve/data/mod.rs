@@ -1,3 +1,6 @@
 pub mod alliance;
 pub mod character;
+pub mod character_role;
+pub mod character_title;
+pub mod character_token;
 pub mod corporation;
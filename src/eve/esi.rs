@@ -0,0 +1,156 @@
+// Authenticated ESI endpoints which aren't covered by the eve_esi crate, these require an
+// access token for the character with the relevant scope granted.
+
+use serde::Deserialize;
+use std::env;
+
+const ESI_URL: &str = "https://esi.evetech.net/latest";
+const SSO_TOKEN_URL: &str = "https://login.eveonline.com/v2/oauth/token";
+
+pub const CHARACTER_ROLES_SCOPE: &str = "esi-characters.read_corporation_roles.v1";
+pub const CHARACTER_TITLES_SCOPE: &str = "esi-characters.read_titles.v1";
+
+// Corporation roles as returned by the /characters/{character_id}/roles/ endpoint
+pub const CORPORATION_ROLES: &[&str] = &[
+    "Account_Take_1",
+    "Account_Take_2",
+    "Account_Take_3",
+    "Account_Take_4",
+    "Account_Take_5",
+    "Account_Take_6",
+    "Account_Take_7",
+    "Accountant",
+    "Auditor",
+    "Brand_Manager",
+    "Communications_Officer",
+    "Config_Equipment",
+    "Config_Starbase_Equipment",
+    "Container_Take_1",
+    "Container_Take_2",
+    "Container_Take_3",
+    "Container_Take_4",
+    "Container_Take_5",
+    "Container_Take_6",
+    "Container_Take_7",
+    "Contract_Manager",
+    "Deliveries_Container_Take",
+    "Deliveries_Query",
+    "Deliveries_Take",
+    "Diplomat",
+    "Director",
+    "Factory_Manager",
+    "Fitting_Manager",
+    "Hangar_Query_1",
+    "Hangar_Query_2",
+    "Hangar_Query_3",
+    "Hangar_Query_4",
+    "Hangar_Query_5",
+    "Hangar_Query_6",
+    "Hangar_Query_7",
+    "Hangar_Take_1",
+    "Hangar_Take_2",
+    "Hangar_Take_3",
+    "Hangar_Take_4",
+    "Hangar_Take_5",
+    "Hangar_Take_6",
+    "Hangar_Take_7",
+    "Junior_Accountant",
+    "Personnel_Manager",
+    "Project_Manager",
+    "Rent_Factory_Facility",
+    "Rent_Office",
+    "Rent_Research_Facility",
+    "Security_Officer",
+    "Skill_Plan_Manager",
+    "Starbase_Defense_Operator",
+    "Starbase_Fuel_Technician",
+    "Station_Manager",
+    "Terrestrial_Combat_Officer",
+    "Terrestrial_Logistics_Officer",
+    "Trader",
+];
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CharacterRoles {
+    // Only the corporation wide roles are stored, the location based hangar roles
+    // (roles_at_base, roles_at_hq, roles_at_other) are ignored
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CharacterTitle {
+    pub title_id: i32,
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RefreshedToken {
+    pub access_token: String,
+    // EVE SSO rotates refresh tokens, the new token must replace the stored one
+    pub refresh_token: String,
+}
+
+fn user_agent() -> String {
+    let application_name = env::var("APPLICATION_NAME").unwrap_or_default();
+    let application_email = env::var("APPLICATION_EMAIL").unwrap_or_default();
+
+    format!("{} ({})", application_name, application_email)
+}
+
+#[cfg_attr(test, allow(dead_code))]
+pub async fn get_character_roles(
+    character_id: i32,
+    access_token: &str,
+) -> Result<CharacterRoles, reqwest::Error> {
+    let url = format!("{}/characters/{}/roles/", ESI_URL, character_id);
+
+    reqwest::Client::new()
+        .get(url)
+        .header(reqwest::header::USER_AGENT, user_agent())
+        .bearer_auth(access_token)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<CharacterRoles>()
+        .await
+}
+
+#[cfg_attr(test, allow(dead_code))]
+pub async fn get_character_titles(
+    character_id: i32,
+    access_token: &str,
+) -> Result<Vec<CharacterTitle>, reqwest::Error> {
+    let url = format!("{}/characters/{}/titles/", ESI_URL, character_id);
+
+    reqwest::Client::new()
+        .get(url)
+        .header(reqwest::header::USER_AGENT, user_agent())
+        .bearer_auth(access_token)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<Vec<CharacterTitle>>()
+        .await
+}
+
+// Exchanges a stored refresh token for a new access token using the ESI application credentials
+#[cfg_attr(test, allow(dead_code))]
+pub async fn refresh_access_token(refresh_token: &str) -> Result<RefreshedToken, reqwest::Error> {
+    let esi_client_id = env::var("ESI_CLIENT_ID").expect("ESI_CLIENT_ID must be set");
+    let esi_client_secret = env::var("ESI_CLIENT_SECRET").expect("ESI_CLIENT_SECRET must be set");
+
+    reqwest::Client::new()
+        .post(SSO_TOKEN_URL)
+        .header(reqwest::header::USER_AGENT, user_agent())
+        .basic_auth(esi_client_id, Some(esi_client_secret))
+        .form(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<RefreshedToken>()
+        .await
+}
@@ -1,3 +1,5 @@
 pub mod data;
+pub mod esi;
 pub mod model;
 pub mod service;
+pub mod sso;
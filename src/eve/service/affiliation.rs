@@ -4,12 +4,14 @@ use sea_orm::DatabaseConnection;
 
 use sea_orm::ColumnTrait;
 use sea_orm::DbErr;
+use sea_orm::{EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait};
 
 use crate::error::DbOrReqwestError;
 use crate::eve::data::alliance::AllianceRepository;
 use crate::eve::data::character::CharacterRepository;
 use crate::eve::data::corporation::CorporationRepository;
 use crate::eve::model::character::CharacterAffiliationDto;
+use crate::eve::service::roles::clear_character_roles;
 
 pub async fn update_affiliation(
     db: &DatabaseConnection,
@@ -37,12 +39,47 @@ pub async fn update_affiliation(
         if let Some(affiliation) = affiliation {
             repo.update(character.id, affiliation.corporation_id)
                 .await?;
+
+            // Roles & titles are held within a corporation, they don't carry over to a new one
+            if affiliation.corporation_id != character.corporation_id {
+                clear_character_roles(db, character.character_id).await?;
+            }
         }
     }
 
     Ok(())
 }
 
+// ESI accepts up to 1000 character ids per affiliation request
+const AFFILIATION_BATCH_SIZE: usize = 1000;
+
+// Updates the affiliations of user owned characters which haven't been updated for the provided
+// duration, returns the amount of characters updated
+pub async fn update_stale_affiliations(
+    db: &DatabaseConnection,
+    max_age: chrono::Duration,
+) -> Result<usize, DbOrReqwestError> {
+    let threshold = chrono::Utc::now().naive_utc() - max_age;
+
+    let character_ids: Vec<i32> = entity::prelude::EveCharacter::find()
+        .select_only()
+        .column(entity::eve_character::Column::CharacterId)
+        .join(
+            JoinType::InnerJoin,
+            entity::eve_character::Relation::AuthUserCharacterOwnership.def(),
+        )
+        .filter(entity::eve_character::Column::LastUpdated.lt(threshold))
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    for batch in character_ids.chunks(AFFILIATION_BATCH_SIZE) {
+        update_affiliation(db, batch.to_vec()).await?;
+    }
+
+    Ok(character_ids.len())
+}
+
 pub async fn get_character_affiliations(
     db: &DatabaseConnection,
     character_ids: Vec<i32>,
@@ -51,6 +88,11 @@ pub async fn get_character_affiliations(
         db: &DatabaseConnection,
         character_ids: Vec<i32>,
     ) -> Result<Vec<entity::eve_character::Model>, sea_orm::DbErr> {
+        // Paginating with a page size of 0 panics
+        if character_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
         let character_repo = CharacterRepository::new(db);
 
         let character_ids_len = character_ids.len() as u64;
@@ -68,6 +110,10 @@ pub async fn get_character_affiliations(
         db: &DatabaseConnection,
         corporation_ids: HashSet<i32>,
     ) -> Result<Vec<entity::eve_corporation::Model>, sea_orm::DbErr> {
+        if corporation_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
         let corporation_repo = CorporationRepository::new(db);
 
         let unique_corporation_ids: Vec<i32> = corporation_ids.into_iter().collect();
@@ -88,6 +134,10 @@ pub async fn get_character_affiliations(
         db: &DatabaseConnection,
         alliance_ids: HashSet<i32>,
     ) -> Result<Vec<entity::eve_alliance::Model>, sea_orm::DbErr> {
+        if alliance_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
         let alliance_repo = AllianceRepository::new(db);
 
         let unique_alliance_ids: Vec<i32> = alliance_ids.into_iter().collect();
@@ -160,28 +210,19 @@ mod tests {
         eve::{
             data::{
                 alliance::AllianceRepository, character::CharacterRepository,
-                corporation::CorporationRepository,
+                character_role::CharacterRoleRepository, corporation::CorporationRepository,
             },
             model::character::CharacterAffiliationDto,
         },
+        test_utils::initialize_test_db,
     };
 
     #[tokio::test]
     async fn update_affiliation() -> Result<(), DbOrReqwestError> {
         use super::update_affiliation;
 
-        let db = Database::connect("sqlite::memory:").await?;
-        let schema = Schema::new(DbBackend::Sqlite);
-
-        let stmts = vec![
-            schema.create_table_from_entity(entity::prelude::EveCharacter),
-            schema.create_table_from_entity(entity::prelude::EveCorporation),
-            schema.create_table_from_entity(entity::prelude::EveAlliance),
-        ];
-
-        for stmt in stmts {
-            let _ = db.execute(db.get_database_backend().build(&stmt)).await?;
-        }
+        // A corporation change clears the stored roles & titles, these tables are required too
+        let db = initialize_test_db().await?;
 
         let character_repo = CharacterRepository::new(&db);
         let corporation_repo = CorporationRepository::new(&db);
@@ -221,6 +262,51 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn update_affiliation_clears_roles_on_corporation_change() -> Result<(), DbOrReqwestError>
+    {
+        use super::update_affiliation;
+
+        let db = initialize_test_db().await?;
+
+        let character_repo = CharacterRepository::new(&db);
+        let corporation_repo = CorporationRepository::new(&db);
+        let role_repo = CharacterRoleRepository::new(&db);
+
+        let _ = corporation_repo
+            .create(98755360, "Black Rose Inc.".to_string(), None, 2114794365)
+            .await?;
+        let _ = corporation_repo
+            .create(109299958, "C C P".to_string(), None, 180548812)
+            .await?;
+
+        // The mocked affiliation places every character in corporation 109299958
+        let moved = character_repo
+            .create(2114794365, "Hyziri".to_string(), 98755360)
+            .await?;
+        let stayed = character_repo
+            .create(180548812, "CCP Bartender".to_string(), 109299958)
+            .await?;
+
+        role_repo
+            .replace(moved.character_id, vec!["Director".to_string()])
+            .await?;
+        role_repo
+            .replace(stayed.character_id, vec!["Director".to_string()])
+            .await?;
+
+        update_affiliation(&db, vec![moved.character_id, stayed.character_id]).await?;
+
+        let roles = role_repo
+            .get_by_character_ids(&[moved.character_id, stayed.character_id])
+            .await?;
+
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].character_id, stayed.character_id);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn get_character_affiliations() -> Result<(), DbOrReqwestError> {
         use super::get_character_affiliations;
@@ -2,3 +2,4 @@ pub mod affiliation;
 pub mod alliance;
 pub mod character;
 pub mod corporation;
+pub mod roles;
@@ -0,0 +1,219 @@
+use sea_orm::DatabaseConnection;
+
+use crate::error::DbOrReqwestError;
+use crate::eve::data::character_role::CharacterRoleRepository;
+use crate::eve::data::character_title::CharacterTitleRepository;
+use crate::eve::data::character_token::CharacterTokenRepository;
+use crate::eve::esi::{CHARACTER_ROLES_SCOPE, CHARACTER_TITLES_SCOPE};
+
+#[cfg(not(test))]
+use crate::eve::esi::{get_character_roles, get_character_titles, refresh_access_token};
+
+#[cfg(test)]
+use crate::mock::eve_esi_mock::{get_character_roles, get_character_titles, refresh_access_token};
+
+// Refreshes the stored corporation roles & titles of a character, each is only
+// updated if the scope required to fetch it was granted to the access token
+pub async fn update_character_roles(
+    db: &DatabaseConnection,
+    character_id: i32,
+    access_token: &str,
+    scopes: &[&str],
+) -> Result<(), DbOrReqwestError> {
+    if scopes.contains(&CHARACTER_ROLES_SCOPE) {
+        let roles = get_character_roles(character_id, access_token).await?;
+
+        CharacterRoleRepository::new(db)
+            .replace(character_id, roles.roles)
+            .await?;
+    }
+
+    if scopes.contains(&CHARACTER_TITLES_SCOPE) {
+        let titles = get_character_titles(character_id, access_token).await?;
+
+        let titles = titles
+            .into_iter()
+            .map(|title| (title.title_id, title.name))
+            .collect();
+
+        CharacterTitleRepository::new(db)
+            .replace(character_id, titles)
+            .await?;
+    }
+
+    Ok(())
+}
+
+// Stores the refresh token of a character if it grants access to its roles or titles so
+// they can be refreshed periodically without the character logging in again
+pub async fn save_character_token(
+    db: &DatabaseConnection,
+    character_id: i32,
+    refresh_token: &str,
+    scopes: &[&str],
+) -> Result<(), sea_orm::DbErr> {
+    if !scopes.contains(&CHARACTER_ROLES_SCOPE) && !scopes.contains(&CHARACTER_TITLES_SCOPE) {
+        return Ok(());
+    }
+
+    CharacterTokenRepository::new(db)
+        .upsert(character_id, refresh_token.to_string(), scopes.join(" "))
+        .await
+}
+
+// Removes the stored corporation roles & titles of a character, e.g. after it left its corporation
+pub async fn clear_character_roles(
+    db: &DatabaseConnection,
+    character_id: i32,
+) -> Result<(), sea_orm::DbErr> {
+    CharacterRoleRepository::new(db)
+        .replace(character_id, vec![])
+        .await?;
+
+    CharacterTitleRepository::new(db)
+        .replace(character_id, vec![])
+        .await?;
+
+    Ok(())
+}
+
+// Refreshes the roles & titles of every character with a stored refresh token, returns the
+// amount of characters refreshed. Tokens rejected by EVE SSO have been revoked by the owner,
+// these are deleted along with the roles & titles they were used to fetch.
+pub async fn refresh_stored_character_roles(
+    db: &DatabaseConnection,
+) -> Result<usize, sea_orm::DbErr> {
+    let token_repo = CharacterTokenRepository::new(db);
+
+    let tokens = token_repo.get_all().await?;
+
+    let mut refreshed = 0;
+
+    for token in tokens {
+        let refreshed_token = match refresh_access_token(&token.refresh_token).await {
+            Ok(refreshed_token) => refreshed_token,
+            Err(err) if err.status().is_some_and(|status| status.is_client_error()) => {
+                token_repo.delete(token.character_id).await?;
+                clear_character_roles(db, token.character_id).await?;
+
+                continue;
+            }
+            Err(err) => {
+                println!("{}", err);
+
+                continue;
+            }
+        };
+
+        token_repo
+            .upsert(
+                token.character_id,
+                refreshed_token.refresh_token,
+                token.scopes.clone(),
+            )
+            .await?;
+
+        let scopes: Vec<&str> = token.scopes.split_whitespace().collect();
+
+        match update_character_roles(
+            db,
+            token.character_id,
+            &refreshed_token.access_token,
+            &scopes,
+        )
+        .await
+        {
+            Ok(()) => refreshed += 1,
+            Err(DbOrReqwestError::DbError(err)) => return Err(err),
+            Err(err) => println!("{}", err),
+        }
+    }
+
+    Ok(refreshed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{refresh_stored_character_roles, save_character_token, update_character_roles};
+    use crate::error::DbOrReqwestError;
+    use crate::eve::data::character_role::CharacterRoleRepository;
+    use crate::eve::data::character_title::CharacterTitleRepository;
+    use crate::eve::data::character_token::CharacterTokenRepository;
+    use crate::eve::esi::{CHARACTER_ROLES_SCOPE, CHARACTER_TITLES_SCOPE};
+    use crate::eve::service::character::get_or_create_character;
+    use crate::test_utils::initialize_test_db;
+
+    #[tokio::test]
+    async fn update_roles_and_titles() -> Result<(), DbOrReqwestError> {
+        let db = initialize_test_db().await?;
+
+        let character = get_or_create_character(&db, 180548812).await?;
+
+        // Titles aren't updated without the titles scope
+        update_character_roles(&db, character.character_id, "", &[CHARACTER_ROLES_SCOPE]).await?;
+
+        let roles = CharacterRoleRepository::new(&db)
+            .get_by_character_ids(&[character.character_id])
+            .await?;
+        let titles = CharacterTitleRepository::new(&db)
+            .get_by_character_ids(&[character.character_id])
+            .await?;
+
+        assert_eq!(roles.len(), 2);
+        assert!(titles.is_empty());
+
+        update_character_roles(
+            &db,
+            character.character_id,
+            "",
+            &[CHARACTER_ROLES_SCOPE, CHARACTER_TITLES_SCOPE],
+        )
+        .await?;
+
+        let titles = CharacterTitleRepository::new(&db)
+            .get_by_character_ids(&[character.character_id])
+            .await?;
+
+        assert_eq!(titles.len(), 1);
+        assert_eq!(titles[0].title_name, "Fleet Commander");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_roles_with_stored_token() -> Result<(), DbOrReqwestError> {
+        let db = initialize_test_db().await?;
+
+        let character = get_or_create_character(&db, 180548812).await?;
+        let token_repo = CharacterTokenRepository::new(&db);
+
+        // Tokens without the roles or titles scopes aren't stored
+        save_character_token(&db, character.character_id, "token", &["publicData"]).await?;
+
+        assert!(token_repo.get_all().await?.is_empty());
+
+        save_character_token(
+            &db,
+            character.character_id,
+            "token",
+            &[CHARACTER_ROLES_SCOPE],
+        )
+        .await?;
+
+        let refreshed = refresh_stored_character_roles(&db).await?;
+
+        let roles = CharacterRoleRepository::new(&db)
+            .get_by_character_ids(&[character.character_id])
+            .await?;
+        let tokens = token_repo.get_all().await?;
+
+        assert_eq!(refreshed, 1);
+        assert_eq!(roles.len(), 2);
+        // The rotated refresh token replaces the stored token
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].refresh_token, "token_rotated");
+        assert_eq!(tokens[0].scopes, CHARACTER_ROLES_SCOPE);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+// EVE SSO access token validation. eve_oauth2 declares the scp claim as a string & panics when
+// decoding fails, but EVE SSO sends it as an array once more than one scope is granted, so the
+// claims are decoded here instead.
+
+use eve_oauth2::models::{EveJwtKey, EveJwtKeys, EveSsoMetaData};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Deserializer};
+
+const SSO_META_DATA_URL: &str =
+    "https://login.eveonline.com/.well-known/oauth-authorization-server";
+
+#[derive(Deserialize, Debug)]
+pub struct EveJwtClaims {
+    #[serde(default, deserialize_with = "deserialize_scopes")]
+    pub scp: Vec<String>,
+    pub sub: String,
+    pub owner: String,
+}
+
+// The scp claim is omitted without scopes, a string with a single scope & an array with several
+fn deserialize_scopes<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Scopes {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<Scopes>::deserialize(deserializer)? {
+        Some(Scopes::One(scope)) => vec![scope],
+        Some(Scopes::Many(scopes)) => scopes,
+        None => vec![],
+    })
+}
+
+// Verifies the signature, audience & issuer of an access token against the EVE SSO signing keys
+pub async fn validate_token(token: &str) -> Result<EveJwtClaims, anyhow::Error> {
+    let client = reqwest::Client::new();
+
+    let meta_data: EveSsoMetaData = client
+        .get(SSO_META_DATA_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let keys: EveJwtKeys = client
+        .get(meta_data.jwks_uri)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let (n, e) = keys
+        .keys
+        .into_iter()
+        .find_map(|key| match key {
+            EveJwtKey::RS256 { n, e, .. } => Some((n, e)),
+            _ => None,
+        })
+        .ok_or_else(|| anyhow::anyhow!("Failed to find RS256 EVE SSO signing key"))?;
+
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.set_audience(&["EVE Online"]);
+    validation.set_issuer(&["https://login.eveonline.com"]);
+
+    let token_data = jsonwebtoken::decode::<EveJwtClaims>(
+        token,
+        &DecodingKey::from_rsa_components(&n, &e)?,
+        &validation,
+    )?;
+
+    Ok(token_data.claims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EveJwtClaims;
+
+    fn parse_claims(scp: &str) -> EveJwtClaims {
+        let claims = format!(
+            r#"{{{}"sub": "CHARACTER:EVE:180548812", "owner": "ownerhash"}}"#,
+            scp
+        );
+
+        serde_json::from_str(&claims).unwrap()
+    }
+
+    #[test]
+    fn deserialize_scopes() {
+        assert!(parse_claims("").scp.is_empty());
+
+        assert_eq!(
+            parse_claims(r#""scp": "esi-characters.read_titles.v1","#).scp,
+            vec!["esi-characters.read_titles.v1"]
+        );
+
+        assert_eq!(
+            parse_claims(
+                r#""scp": ["esi-characters.read_corporation_roles.v1", "esi-characters.read_titles.v1"],"#
+            )
+            .scp,
+            vec![
+                "esi-characters.read_corporation_roles.v1",
+                "esi-characters.read_titles.v1"
+            ]
+        );
+    }
+}
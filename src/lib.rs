@@ -2,3 +2,5 @@ pub mod auth;
 pub mod error;
 pub mod eve;
 pub mod mock;
+#[cfg(test)]
+pub mod test_utils;
@@ -5,9 +5,15 @@ mod router;
 
 #[cfg(test)]
 mod mock;
+#[cfg(test)]
+mod test_utils;
 
 use sea_orm::{Database, DatabaseConnection};
 
+use auth::jobs::{
+    spawn_affiliation_refresh_job, spawn_application_expiry_job, spawn_archived_group_purge_job,
+    spawn_membership_expiry_job, spawn_recruitment_handoff_job,
+};
 use auth::seed::create_admin;
 use axum::Extension;
 use eve_esi::initialize_eve_esi;
@@ -44,6 +50,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let _ = create_admin(&db).await;
 
+    spawn_membership_expiry_job(db.clone());
+    spawn_archived_group_purge_job(db.clone());
+    spawn_application_expiry_job(db.clone());
+    spawn_recruitment_handoff_job(db.clone());
+    spawn_affiliation_refresh_job(db.clone());
+
     let app = router::routes().layer(Extension(db)).layer(session_layer);
 
     let binding = format!("0.0.0.0:{}", application_port);
@@ -8,6 +8,8 @@ use eve_esi::model::{
     corporation::Corporation,
 };
 
+use crate::eve::esi::{CharacterRoles, CharacterTitle, RefreshedToken};
+
 pub async fn get_alliance(
     _alliance_id: i32,
 ) -> Result<eve_esi::model::alliance::Alliance, reqwest::Error> {
@@ -79,3 +81,29 @@ pub async fn get_character_affiliations(
 
     Ok(affiliations)
 }
+
+pub async fn get_character_roles(
+    _character_id: i32,
+    _access_token: &str,
+) -> Result<CharacterRoles, reqwest::Error> {
+    Ok(CharacterRoles {
+        roles: vec!["Director".to_string(), "Personnel_Manager".to_string()],
+    })
+}
+
+pub async fn get_character_titles(
+    _character_id: i32,
+    _access_token: &str,
+) -> Result<Vec<CharacterTitle>, reqwest::Error> {
+    Ok(vec![CharacterTitle {
+        title_id: 1,
+        name: "Fleet Commander".to_string(),
+    }])
+}
+
+pub async fn refresh_access_token(refresh_token: &str) -> Result<RefreshedToken, reqwest::Error> {
+    Ok(RefreshedToken {
+        access_token: "access_token".to_string(),
+        refresh_token: format!("{}_rotated", refresh_token),
+    })
+}
@@ -4,11 +4,13 @@ use utoipa_swagger_ui::SwaggerUi;
 
 use crate::auth::{model::{
     groups::{
-        GroupApplicationDto, GroupApplicationStatus, GroupApplicationType, GroupDto, GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterGroupDto, GroupFilterRuleDto, GroupFilterType, GroupFiltersDto, GroupOwnerInfo, GroupOwnerType, GroupType, NewGroupDto, NewGroupFilterGroupDto, NewGroupFilterRuleDto, UpdateGroupDto, UpdateGroupFilterGroupDto, UpdateGroupFilterRuleDto
+        ApplicationActionResultDto, ApplicationMembershipDto, ArchivedGroupDto, AvailableGroupDto, CloneGroupDto, GroupApplicationAnswerDto, GroupApplicationCommentDto, GroupApplicationDto, GroupApplicationHistoryDto, GroupApplicationQueueDto, GroupApplicationSettingsDto, GroupApplicationStatus, GroupApplicationType, GroupCategoryDto, GroupDto, GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterGroupDto, GroupFilterScope, GroupFilterRuleDto, GroupFilterType, GroupFiltersDto, GroupInvitationDto, GroupInvitationStatus, GroupMemberDto, GroupMembershipAction, GroupMembershipHistoryDto, GroupMembershipInheritance, GroupMembershipReason, GroupOwnerDto, GroupOwnerInfo, GroupOwnerType, GroupQuestionDto, GroupQuestionType, GroupTemplateDefinition, GroupTemplateDto, GroupType, InstantiateGroupTemplateDto, JoinGroupDto, JoinGroupRequest, NewGroupApplicationAnswerDto, NewGroupApplicationCommentDto, NewGroupCategoryDto, NewGroupDto, NewGroupFilterGroupDto, NewGroupFilterRuleDto, NewGroupInvitationDto, NewGroupQuestionDto, NewGroupTemplateDto, PendingGroupApplicationDto, UpdateGroupDto, UpdateGroupFilterGroupDto, UpdateGroupFilterRuleDto
     },
-    user::UserDto,
-}, route::groups::applications::{ApplicationAction, GetGroupApplicationParams}};
-use crate::auth::route::{auth, groups, user};
+    organizations::{NewOrganizationDto, OrganizationDto, OrganizationMemberDto, OrganizationMemberType},
+    recruitment::{NewRecruitmentDto, NewRecruitmentPipelineDto, RecruitmentDto, RecruitmentPipelineDto, RecruitmentStatus},
+    user::{UserDto, UserGroupMembershipDto},
+}, route::{groups::{applications::{ApplicationAction, BulkApplicationActionDto, GetGroupApplicationParams}, history::GetMembershipHistoryParams, members::MembershipDurationParams, GetGroupsParams}, recruitment::{GetRecruitmentParams, RecruitmentAction}, user::InvitationAction}};
+use crate::auth::route::{auth, groups, organizations, recruitment, user};
 use crate::eve::model::character::CharacterAffiliationDto;
 
 pub fn routes() -> Router {
@@ -17,22 +19,55 @@ pub fn routes() -> Router {
         paths(
             auth::login, auth::logout,
             user::get_user, user::get_user_main_character, user::get_user_characters,
-            user::get_user_groups,
-            groups::create_group, groups::get_groups, groups::get_group_by_id,
+            user::get_user_groups, user::get_user_group_invitations, user::respond_to_user_invitation,
+            user::get_user_recruitments,
+            groups::create_group, groups::get_groups, groups::get_available_groups, groups::get_group_by_id,
             groups::get_group_filters, groups::update_group, groups::delete_group,
+            groups::get_archived_groups, groups::restore_group, groups::clone_group,
+            groups::get_group_application_settings, groups::update_group_application_settings,
             groups::members::join_group, groups::members::leave_group,
             groups::members::get_group_members, groups::members::add_group_members, groups::members::delete_group_members,
-            groups::applications::get_group_applications, groups::applications::update_group_application, 
-            groups::applications::delete_group_application, groups::applications::accept_reject_application,
+            groups::invitations::get_group_invitations, groups::invitations::create_group_invitation, groups::invitations::revoke_group_invitation,
+            groups::applications::get_group_applications, groups::applications::get_group_application, groups::applications::update_group_application, 
+            groups::applications::accept_reject_application,
+            groups::applications::get_application_comments, groups::applications::create_application_comment,
+            groups::applications::withdraw_group_application, groups::applications::bulk_accept_reject_applications,
+            groups::applications::get_application_queues, groups::applications::claim_group_application, groups::applications::assign_group_application,
+            groups::categories::create_group_category, groups::categories::get_group_categories,
+            groups::categories::update_group_category, groups::categories::delete_group_category,
+            groups::history::get_membership_history,
+            groups::questionnaires::get_group_questions, groups::questionnaires::update_group_questions,
+            groups::templates::create_group_template, groups::templates::get_group_templates,
+            groups::templates::update_group_template, groups::templates::delete_group_template,
+            groups::templates::instantiate_group_template,
+            groups::owners::get_managed_group_owners, groups::owners::get_owner_delegates,
+            groups::owners::add_owner_delegate, groups::owners::delete_owner_delegate,
+            organizations::create_organization, organizations::get_organizations, organizations::get_organization_by_id,
+            organizations::update_organization, organizations::delete_organization,
+            recruitment::get_recruitment_pipelines, recruitment::create_recruitment_pipeline, recruitment::update_recruitment_pipeline,
+            recruitment::get_recruitments, recruitment::submit_recruitment, recruitment::withdraw_recruitment,
+            recruitment::approve_reject_recruitment,
         ),
         components(schemas(
-            UserDto, CharacterAffiliationDto, 
+            UserDto, UserGroupMembershipDto, CharacterAffiliationDto, 
             NewGroupDto, NewGroupFilterGroupDto, NewGroupFilterRuleDto,  
             GroupFiltersDto, GroupDto, GroupFilterRuleDto, GroupFilterGroupDto, 
             UpdateGroupDto, UpdateGroupFilterRuleDto, UpdateGroupFilterGroupDto,
-            GroupType, GroupFilterType, GroupFilterCriteria, GroupFilterCriteriaType,
+            GroupType, GroupFilterType, GroupFilterCriteria, GroupFilterCriteriaType, GroupFilterScope, GroupMembershipInheritance,
             GroupApplicationDto, GroupApplicationStatus, GroupApplicationType, ApplicationAction,
-            GetGroupApplicationParams, GroupOwnerType, GroupOwnerInfo)),
+            BulkApplicationActionDto, ApplicationActionResultDto, ApplicationMembershipDto,
+            GroupApplicationCommentDto, NewGroupApplicationCommentDto, GroupApplicationSettingsDto, GroupApplicationHistoryDto, GroupApplicationQueueDto,
+            GroupApplicationAnswerDto, NewGroupApplicationAnswerDto, JoinGroupDto, JoinGroupRequest,
+            GroupQuestionDto, NewGroupQuestionDto, GroupQuestionType,
+            GetGroupApplicationParams, GroupOwnerType, GroupOwnerInfo, GroupOwnerDto,
+            GroupCategoryDto, NewGroupCategoryDto, GetGroupsParams, AvailableGroupDto, PendingGroupApplicationDto,
+            GroupMemberDto, MembershipDurationParams, ArchivedGroupDto, CloneGroupDto,
+            GroupInvitationDto, NewGroupInvitationDto, GroupInvitationStatus, InvitationAction,
+            GroupTemplateDto, GroupTemplateDefinition, NewGroupTemplateDto, InstantiateGroupTemplateDto,
+            GroupMembershipHistoryDto, GroupMembershipAction, GroupMembershipReason, GetMembershipHistoryParams,
+            OrganizationDto, NewOrganizationDto, OrganizationMemberDto, OrganizationMemberType,
+            RecruitmentPipelineDto, NewRecruitmentPipelineDto, RecruitmentDto, NewRecruitmentDto, RecruitmentStatus,
+            GetRecruitmentParams, RecruitmentAction)),
         tags(
             (name = "Black Rose Auth API", description = "Black Rose Auth API endpoints")
         )
@@ -41,12 +76,16 @@ pub fn routes() -> Router {
 
     use crate::auth::route::auth::auth_routes;
     use crate::auth::route::groups::group_routes;
+    use crate::auth::route::organizations::organization_routes;
+    use crate::auth::route::recruitment::recruitment_routes;
     use crate::auth::route::user::user_routes;
 
     let routes = Router::new()
         .nest("/auth", auth_routes())
         .nest("/user", user_routes())
-        .nest("/groups", group_routes());
+        .nest("/groups", group_routes())
+        .nest("/organizations", organization_routes())
+        .nest("/recruitment", recruitment_routes());
 
     if cfg!(debug_assertions) {
         routes.merge(SwaggerUi::new("/docs").url("/openapi.json", ApiDoc::openapi()))
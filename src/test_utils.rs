@@ -0,0 +1,126 @@
+use sea_orm::{
+    sea_query::Index, ActiveModelTrait, ActiveValue::Set, ConnectionTrait, Database,
+    DatabaseConnection, DbBackend, DbErr, Schema,
+};
+
+use crate::{
+    auth::{
+        data::user::create_user,
+        model::groups::{GroupFilterType, GroupOwnerType, GroupType, NewGroupDto},
+    },
+    error::DbOrReqwestError,
+    eve::service::character::get_or_create_character,
+};
+
+// Connects to an in-memory database with a table created for every entity
+pub async fn initialize_test_db() -> Result<DatabaseConnection, DbErr> {
+    let db = Database::connect("sqlite::memory:").await?;
+    let schema = Schema::new(DbBackend::Sqlite);
+
+    let stmts = vec![
+        schema.create_table_from_entity(entity::prelude::EveAlliance),
+        schema.create_table_from_entity(entity::prelude::EveCorporation),
+        schema.create_table_from_entity(entity::prelude::EveCharacter),
+        schema.create_table_from_entity(entity::prelude::EveCharacterRole),
+        schema.create_table_from_entity(entity::prelude::EveCharacterTitle),
+        schema.create_table_from_entity(entity::prelude::EveCharacterToken),
+        schema.create_table_from_entity(entity::prelude::AuthUser),
+        schema.create_table_from_entity(entity::prelude::AuthUserCharacterOwnership),
+        schema.create_table_from_entity(entity::prelude::AuthOrganization),
+        schema.create_table_from_entity(entity::prelude::AuthOrganizationMember),
+        schema.create_table_from_entity(entity::prelude::AuthGroupCategory),
+        schema.create_table_from_entity(entity::prelude::AuthGroup),
+        schema.create_table_from_entity(entity::prelude::AuthGroupFilterGroup),
+        schema.create_table_from_entity(entity::prelude::AuthGroupFilterRule),
+        schema.create_table_from_entity(entity::prelude::AuthGroupUser),
+        schema.create_table_from_entity(entity::prelude::AuthGroupMembershipHistory),
+        schema.create_table_from_entity(entity::prelude::AuthGroupOwnerDelegate),
+        schema.create_table_from_entity(entity::prelude::AuthGroupQuestion),
+        schema.create_table_from_entity(entity::prelude::AuthGroupTemplate),
+        schema.create_table_from_entity(entity::prelude::AuthGroupApplication),
+        schema.create_table_from_entity(entity::prelude::AuthGroupApplicationAnswer),
+        schema.create_table_from_entity(entity::prelude::AuthGroupApplicationComment),
+        schema.create_table_from_entity(entity::prelude::AuthGroupApplicationHistory),
+        schema.create_table_from_entity(entity::prelude::AuthGroupInvitation),
+        schema.create_table_from_entity(entity::prelude::AuthRecruitmentPipeline),
+        schema.create_table_from_entity(entity::prelude::AuthRecruitment),
+    ];
+
+    for stmt in stmts {
+        let _ = db.execute(db.get_database_backend().build(&stmt)).await?;
+    }
+
+    // Unique indexes created by the migrations, required for on conflict clauses
+    let indexes = vec![
+        Index::create()
+            .name("idx-auth_group_user-group_id-user_id")
+            .table(entity::prelude::AuthGroupUser)
+            .col(entity::auth_group_user::Column::GroupId)
+            .col(entity::auth_group_user::Column::UserId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("idx-auth_group_owner_delegate-unique")
+            .table(entity::prelude::AuthGroupOwnerDelegate)
+            .col(entity::auth_group_owner_delegate::Column::OwnerType)
+            .col(entity::auth_group_owner_delegate::Column::OwnerId)
+            .col(entity::auth_group_owner_delegate::Column::UserId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("idx-auth_organization_member-unique")
+            .table(entity::prelude::AuthOrganizationMember)
+            .col(entity::auth_organization_member::Column::OrganizationId)
+            .col(entity::auth_organization_member::Column::MemberType)
+            .col(entity::auth_organization_member::Column::MemberId)
+            .unique()
+            .to_owned(),
+    ];
+
+    for index in indexes {
+        let _ = db.execute(db.get_database_backend().build(&index)).await?;
+    }
+
+    Ok(db)
+}
+
+// Creates a user whose main is the character, the character & its corporation are created from
+// the mocked ESI responses if they don't exist yet
+pub async fn create_user_with_main(
+    db: &DatabaseConnection,
+    character_id: i32,
+) -> Result<i32, DbOrReqwestError> {
+    let character = get_or_create_character(db, character_id).await?;
+    let user_id = create_user(db).await?;
+
+    entity::auth_user_character_ownership::ActiveModel {
+        user_id: Set(user_id),
+        character_id: Set(character.character_id),
+        ownerhash: Set(format!("ownerhash-{}", character.character_id)),
+        main: Set(true),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    Ok(user_id)
+}
+
+// An open auth owned group without filters, override fields using struct update syntax
+pub fn new_group_dto(name: &str) -> NewGroupDto {
+    NewGroupDto {
+        name: name.to_string(),
+        description: None,
+        confidential: false,
+        leave_applications: false,
+        owner_type: GroupOwnerType::Auth,
+        owner_id: None,
+        group_type: GroupType::Open,
+        parent_id: None,
+        membership_inheritance: Default::default(),
+        category_id: None,
+        filter_type: GroupFilterType::All,
+        filter_rules: vec![],
+        filter_groups: vec![],
+    }
+}
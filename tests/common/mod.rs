@@ -24,12 +24,26 @@ pub async fn create_tables(db: &DatabaseConnection) -> Result<(), sea_orm::DbErr
     stmts.push(schema.create_table_from_entity(entity::prelude::EveAlliance));
     stmts.push(schema.create_table_from_entity(entity::prelude::EveCorporation));
     stmts.push(schema.create_table_from_entity(entity::prelude::EveCharacter));
+    stmts.push(schema.create_table_from_entity(entity::prelude::EveCharacterRole));
+    stmts.push(schema.create_table_from_entity(entity::prelude::EveCharacterTitle));
     stmts.push(schema.create_table_from_entity(entity::prelude::AuthUser));
     stmts.push(schema.create_table_from_entity(entity::prelude::AuthUserCharacterOwnership));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthOrganization));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthOrganizationMember));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupCategory));
     stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroup));
     stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupFilterGroup));
     stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupFilterRule));
     stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupUser));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupMembershipHistory));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupOwnerDelegate));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupQuestion));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupTemplate));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupApplicationComment));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupApplicationHistory));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthGroupInvitation));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthRecruitmentPipeline));
+    stmts.push(schema.create_table_from_entity(entity::prelude::AuthRecruitment));
 
     for stmt in stmts {
         let _ = db.execute(db.get_database_backend().build(&stmt)).await?;